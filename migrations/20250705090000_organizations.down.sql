@@ -0,0 +1,5 @@
+-- Down migration
+DROP INDEX IF EXISTS organization_memberships_user_idx;
+DROP TABLE IF EXISTS "organization_memberships";
+DROP TABLE IF EXISTS "organizations";
+DROP TYPE IF EXISTS organization_role;
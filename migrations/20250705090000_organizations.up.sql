@@ -0,0 +1,25 @@
+-- Up migration
+-- Tạo enum type cho vai trò trong tổ chức
+CREATE TYPE organization_role AS ENUM ('owner', 'admin', 'member');
+
+-- Tạo bảng organizations
+CREATE TABLE "organizations" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Tạo bảng thành viên của tổ chức
+CREATE TABLE "organization_memberships" (
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role organization_role NOT NULL DEFAULT 'member',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, user_id)
+);
+
+-- Tạo index để tra cứu các tổ chức của một người dùng
+CREATE INDEX organization_memberships_user_idx ON organization_memberships (user_id);
@@ -0,0 +1,5 @@
+-- Down migration
+DROP INDEX IF EXISTS invitations_status_idx;
+DROP INDEX IF EXISTS invitations_email_idx;
+DROP TABLE IF EXISTS "invitations";
+DROP TYPE IF EXISTS invitation_status;
@@ -0,0 +1,23 @@
+-- Up migration
+-- Tạo enum type cho trạng thái lời mời
+CREATE TYPE invitation_status AS ENUM ('pending', 'accepted', 'revoked');
+
+-- Tạo bảng invitations
+CREATE TABLE "invitations" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    email VARCHAR(255) NOT NULL,
+    role user_role NOT NULL DEFAULT 'user',
+    organization_id UUID REFERENCES organizations(id) ON DELETE CASCADE,
+    organization_role organization_role,
+    token_nonce VARCHAR(255) NOT NULL,
+    status invitation_status NOT NULL DEFAULT 'pending',
+    invited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    accepted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Tạo index cho cột email và trạng thái
+CREATE INDEX invitations_email_idx ON invitations (email);
+CREATE INDEX invitations_status_idx ON invitations (status);
@@ -0,0 +1,4 @@
+-- Down migration
+DROP INDEX IF EXISTS user_approval_status_idx;
+ALTER TABLE "users" DROP COLUMN IF EXISTS approval_status;
+DROP TYPE IF EXISTS approval_status;
@@ -0,0 +1,8 @@
+-- Up migration
+-- Tạo enum type cho trạng thái phê duyệt tài khoản
+CREATE TYPE approval_status AS ENUM ('approved', 'pending', 'rejected');
+
+-- Thêm cột trạng thái phê duyệt cho bảng users
+ALTER TABLE "users" ADD COLUMN approval_status approval_status NOT NULL DEFAULT 'approved';
+
+CREATE INDEX user_approval_status_idx ON users (approval_status);
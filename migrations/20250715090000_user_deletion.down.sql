@@ -0,0 +1,4 @@
+-- Down migration
+DROP INDEX IF EXISTS user_deletion_scheduled_at_idx;
+ALTER TABLE "users" DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE "users" DROP COLUMN IF EXISTS deletion_scheduled_at;
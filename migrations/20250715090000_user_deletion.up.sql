@@ -0,0 +1,7 @@
+-- Up migration
+-- Thêm cột lên lịch xoá tài khoản và đánh dấu tài khoản đã ẩn danh hoá
+ALTER TABLE "users" ADD COLUMN deletion_scheduled_at TIMESTAMPTZ;
+ALTER TABLE "users" ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX user_deletion_scheduled_at_idx ON users (deletion_scheduled_at)
+    WHERE deletion_scheduled_at IS NOT NULL;
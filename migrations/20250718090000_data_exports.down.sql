@@ -0,0 +1,4 @@
+-- Down migration
+DROP INDEX IF EXISTS data_exports_user_idx;
+DROP TABLE IF EXISTS "data_exports";
+DROP TYPE IF EXISTS data_export_status;
@@ -0,0 +1,16 @@
+-- Up migration
+-- Tạo enum type cho trạng thái xuất dữ liệu
+CREATE TYPE data_export_status AS ENUM ('pending', 'ready', 'failed');
+
+-- Tạo bảng data_exports
+CREATE TABLE "data_exports" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status data_export_status NOT NULL DEFAULT 'pending',
+    payload JSONB,
+    expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX data_exports_user_idx ON data_exports (user_id);
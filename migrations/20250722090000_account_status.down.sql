@@ -0,0 +1,12 @@
+-- Down migration
+DROP INDEX IF EXISTS user_status_transitions_user_idx;
+DROP TABLE IF EXISTS "user_status_transitions";
+DROP INDEX IF EXISTS user_status_idx;
+
+ALTER TABLE "users" ADD COLUMN verified BOOLEAN NOT NULL DEFAULT FALSE;
+UPDATE users SET verified = status <> 'pending_verification';
+
+ALTER TABLE "users" DROP COLUMN suspended_until;
+ALTER TABLE "users" DROP COLUMN suspended_reason;
+ALTER TABLE "users" DROP COLUMN status;
+DROP TYPE IF EXISTS account_status;
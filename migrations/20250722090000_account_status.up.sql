@@ -0,0 +1,37 @@
+-- Up migration
+-- Tạo enum type cho trạng thái tài khoản
+CREATE TYPE account_status AS ENUM (
+    'pending_verification',
+    'active',
+    'suspended',
+    'locked',
+    'pending_deletion'
+);
+
+-- Thay cột verified bằng cột trạng thái tài khoản
+ALTER TABLE "users" ADD COLUMN status account_status NOT NULL DEFAULT 'pending_verification';
+ALTER TABLE "users" ADD COLUMN suspended_reason TEXT;
+ALTER TABLE "users" ADD COLUMN suspended_until TIMESTAMPTZ;
+
+UPDATE users SET status = CASE
+    WHEN deletion_scheduled_at IS NOT NULL THEN 'pending_deletion'::account_status
+    WHEN verified THEN 'active'::account_status
+    ELSE 'pending_verification'::account_status
+END;
+
+ALTER TABLE "users" DROP COLUMN verified;
+
+CREATE INDEX user_status_idx ON users (status);
+
+-- Tạo bảng lưu lịch sử chuyển trạng thái
+CREATE TABLE "user_status_transitions" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    from_status account_status NOT NULL,
+    to_status account_status NOT NULL,
+    reason TEXT,
+    actor_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX user_status_transitions_user_idx ON user_status_transitions (user_id, created_at);
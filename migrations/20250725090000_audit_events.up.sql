@@ -0,0 +1,33 @@
+-- Up migration
+-- Tạo bảng nhật ký bảo mật (chỉ được thêm, không sửa/xóa)
+CREATE TABLE "audit_events" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    sequence BIGSERIAL NOT NULL UNIQUE,
+    actor_id UUID,
+    target_user_id UUID,
+    action VARCHAR(100) NOT NULL,
+    ip_address VARCHAR(64),
+    user_agent TEXT,
+    metadata JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX audit_events_actor_idx ON audit_events (actor_id, sequence);
+CREATE INDEX audit_events_target_idx ON audit_events (target_user_id, sequence);
+CREATE INDEX audit_events_action_idx ON audit_events (action, sequence);
+CREATE INDEX audit_events_created_at_idx ON audit_events (created_at);
+
+-- Chặn mọi thao tác UPDATE/DELETE trên nhật ký
+CREATE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_events_no_update
+    BEFORE UPDATE OR DELETE ON audit_events
+    FOR EACH ROW EXECUTE FUNCTION audit_events_append_only();
+
+CREATE TRIGGER audit_events_no_truncate
+    BEFORE TRUNCATE ON audit_events
+    FOR EACH STATEMENT EXECUTE FUNCTION audit_events_append_only();
@@ -0,0 +1,11 @@
+-- Down migration
+DROP INDEX IF EXISTS audit_checkpoints_created_at_idx;
+DROP TABLE IF EXISTS "audit_checkpoints";
+DROP TRIGGER IF EXISTS audit_events_chain ON audit_events;
+DROP FUNCTION IF EXISTS audit_events_chain;
+ALTER TABLE "audit_events" ALTER COLUMN sequence SET DEFAULT nextval('audit_events_sequence_seq');
+DROP FUNCTION IF EXISTS audit_event_hash;
+ALTER TABLE audit_events DISABLE TRIGGER audit_events_no_update;
+ALTER TABLE "audit_events" DROP COLUMN IF EXISTS hash;
+ALTER TABLE "audit_events" DROP COLUMN IF EXISTS prev_hash;
+ALTER TABLE audit_events ENABLE TRIGGER audit_events_no_update;
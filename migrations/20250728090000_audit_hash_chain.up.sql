@@ -0,0 +1,77 @@
+-- Up migration
+-- Thêm chuỗi băm để phát hiện chỉnh sửa nhật ký
+ALTER TABLE "audit_events" ADD COLUMN prev_hash VARCHAR(64);
+ALTER TABLE "audit_events" ADD COLUMN hash VARCHAR(64);
+
+-- Băm nội dung sự kiện cùng với hash của sự kiện trước đó.
+-- Định dạng phải khớp với core::audit_chain::event_hash.
+CREATE FUNCTION audit_event_hash(prev_hash TEXT, e audit_events) RETURNS TEXT AS $$
+    SELECT encode(sha256(convert_to(
+        prev_hash || E'\n' ||
+        e.id::text || E'\n' ||
+        e.sequence::text || E'\n' ||
+        coalesce(e.actor_id::text, '') || E'\n' ||
+        coalesce(e.target_user_id::text, '') || E'\n' ||
+        e.action || E'\n' ||
+        coalesce(e.ip_address, '') || E'\n' ||
+        coalesce(e.user_agent, '') || E'\n' ||
+        e.metadata::text || E'\n' ||
+        (extract(epoch FROM e.created_at) * 1000000)::bigint::text,
+        'UTF8')), 'hex');
+$$ LANGUAGE sql IMMUTABLE;
+
+-- Tính lại chuỗi cho các sự kiện đã có
+ALTER TABLE audit_events DISABLE TRIGGER audit_events_no_update;
+
+DO $$
+DECLARE
+    e audit_events;
+    prev TEXT := repeat('0', 64);
+BEGIN
+    FOR e IN SELECT * FROM audit_events ORDER BY sequence LOOP
+        UPDATE audit_events
+        SET prev_hash = prev, hash = audit_event_hash(prev, e)
+        WHERE id = e.id;
+        prev := audit_event_hash(prev, e);
+    END LOOP;
+END;
+$$;
+
+ALTER TABLE audit_events ENABLE TRIGGER audit_events_no_update;
+
+ALTER TABLE "audit_events" ALTER COLUMN prev_hash SET NOT NULL;
+ALTER TABLE "audit_events" ALTER COLUMN hash SET NOT NULL;
+
+-- Số thứ tự được cấp sau khi giữ khóa để thứ tự chuỗi khớp với sequence
+ALTER TABLE "audit_events" ALTER COLUMN sequence DROP DEFAULT;
+
+CREATE FUNCTION audit_events_chain() RETURNS trigger AS $$
+DECLARE
+    prev TEXT;
+BEGIN
+    PERFORM pg_advisory_xact_lock(hashtext('audit_events_chain'));
+
+    SELECT hash INTO prev FROM audit_events ORDER BY sequence DESC LIMIT 1;
+
+    NEW.sequence := nextval('audit_events_sequence_seq');
+    NEW.prev_hash := coalesce(prev, repeat('0', 64));
+    NEW.hash := audit_event_hash(NEW.prev_hash, NEW);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_events_chain
+    BEFORE INSERT ON audit_events
+    FOR EACH ROW EXECUTE FUNCTION audit_events_chain();
+
+-- Tạo bảng checkpoint đã ký
+CREATE TABLE "audit_checkpoints" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    sequence BIGINT NOT NULL,
+    hash VARCHAR(64) NOT NULL,
+    event_count BIGINT NOT NULL,
+    signature VARCHAR(64) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX audit_checkpoints_created_at_idx ON audit_checkpoints (created_at);
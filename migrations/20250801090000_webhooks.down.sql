@@ -0,0 +1,6 @@
+-- Down migration
+DROP INDEX IF EXISTS webhook_deliveries_endpoint_idx;
+DROP INDEX IF EXISTS webhook_deliveries_due_idx;
+DROP TABLE IF EXISTS "webhook_deliveries";
+DROP TYPE IF EXISTS webhook_delivery_status;
+DROP TABLE IF EXISTS "webhook_endpoints";
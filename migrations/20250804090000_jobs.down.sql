@@ -0,0 +1,6 @@
+-- Down migration
+DROP INDEX IF EXISTS jobs_status_idx;
+DROP INDEX IF EXISTS jobs_running_idx;
+DROP INDEX IF EXISTS jobs_due_idx;
+DROP TABLE IF EXISTS "jobs";
+DROP TYPE IF EXISTS job_status;
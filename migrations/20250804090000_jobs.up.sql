@@ -0,0 +1,23 @@
+-- Up migration
+-- Tạo enum type cho trạng thái công việc nền
+CREATE TYPE job_status AS ENUM ('pending', 'running', 'succeeded', 'dead');
+
+-- Tạo bảng hàng đợi công việc nền (worker lấy việc bằng FOR UPDATE SKIP LOCKED)
+CREATE TABLE "jobs" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    kind VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL,
+    status job_status NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL,
+    run_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX jobs_due_idx ON jobs (run_at) WHERE status = 'pending';
+CREATE INDEX jobs_running_idx ON jobs (locked_until) WHERE status = 'running';
+CREATE INDEX jobs_status_idx ON jobs (status, created_at);
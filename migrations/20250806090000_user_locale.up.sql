@@ -0,0 +1,3 @@
+-- Up migration
+-- Thêm ngôn ngữ ưa thích của người dùng để chọn mẫu email (NULL = ngôn ngữ mặc định)
+ALTER TABLE users ADD COLUMN locale VARCHAR(35);
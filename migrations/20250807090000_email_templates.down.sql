@@ -0,0 +1,3 @@
+-- Down migration
+DROP INDEX IF EXISTS email_templates_active_idx;
+DROP TABLE IF EXISTS "email_templates";
@@ -0,0 +1,18 @@
+-- Up migration
+-- Tạo bảng mẫu email do quản trị viên chỉnh sửa (mỗi lần lưu là một phiên bản mới)
+CREATE TABLE "email_templates" (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    locale VARCHAR(35) NOT NULL,
+    version INTEGER NOT NULL,
+    subject TEXT NOT NULL,
+    html_body TEXT NOT NULL,
+    text_body TEXT NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT FALSE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (name, locale, version)
+);
+
+-- Mỗi email và ngôn ngữ chỉ có tối đa một phiên bản đang dùng
+CREATE UNIQUE INDEX email_templates_active_idx ON email_templates (name, locale) WHERE active;
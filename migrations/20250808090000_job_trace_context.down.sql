@@ -0,0 +1,3 @@
+-- Down migration
+ALTER TABLE jobs DROP COLUMN IF EXISTS traceparent;
+ALTER TABLE jobs DROP COLUMN IF EXISTS request_id;
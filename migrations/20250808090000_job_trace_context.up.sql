@@ -0,0 +1,4 @@
+-- Up migration
+-- Lưu request ID và traceparent của request đã tạo công việc để worker nối tiếp trace
+ALTER TABLE jobs ADD COLUMN request_id VARCHAR(128);
+ALTER TABLE jobs ADD COLUMN traceparent VARCHAR(55);
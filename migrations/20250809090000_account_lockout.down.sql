@@ -0,0 +1,3 @@
+-- Down migration
+-- Postgres không xoá được giá trị khỏi enum; 'deleted' được giữ lại nhưng không còn dùng
+ALTER TABLE users DROP COLUMN IF EXISTS failed_login_attempts;
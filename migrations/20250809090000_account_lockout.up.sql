@@ -0,0 +1,6 @@
+-- Up migration
+-- Thêm trạng thái cuối cho tài khoản đã bị ẩn danh hoá
+ALTER TYPE account_status ADD VALUE IF NOT EXISTS 'deleted';
+
+-- Đếm số lần đăng nhập sai liên tiếp để khoá tài khoản
+ALTER TABLE users ADD COLUMN failed_login_attempts INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,3 @@
+-- Down migration
+DELETE FROM user_status_transitions WHERE to_status = 'deleted' OR from_status = 'deleted';
+UPDATE users SET status = 'pending_deletion' WHERE status = 'deleted';
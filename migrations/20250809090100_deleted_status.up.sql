@@ -0,0 +1,8 @@
+-- Up migration
+-- Tách khỏi migration trước vì giá trị enum mới chỉ dùng được sau khi commit
+UPDATE users SET status = 'deleted' WHERE deleted_at IS NOT NULL;
+
+INSERT INTO user_status_transitions (user_id, from_status, to_status, reason, created_at)
+SELECT id, 'pending_deletion', 'deleted', 'account purged', deleted_at
+FROM users
+WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,3 @@
+-- Down migration
+-- Dữ liệu đã xóa không thể khôi phục
+SELECT 1;
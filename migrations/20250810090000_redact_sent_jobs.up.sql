@@ -0,0 +1,13 @@
+-- Up migration
+-- Xóa token và liên kết khỏi các email đã gửi; worker làm việc này từ nay
+UPDATE jobs
+SET payload = jsonb_set(
+    payload,
+    '{data}',
+    (payload -> 'data') || (
+        SELECT coalesce(jsonb_object_agg(key, '[REDACTED]'), '{}'::jsonb)
+        FROM jsonb_object_keys(payload -> 'data') AS key
+        WHERE key IN ('token', 'reset_link', 'invite_link', 'cancel_link', 'download_link')
+    )
+)
+WHERE kind = 'email.send' AND status = 'succeeded';
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use serde_json::json;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        email_template::{check_template_source, template_locale},
+    },
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        email_template::{
+            email_template_impl::PgEmailTemplateRepository,
+            email_template_trait::EmailTemplateRepository,
+        },
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    },
+    models::email_template::response::{EmailTemplateResponse, FilterEmailTemplate},
+};
+
+/// Switches the override back (or forward) to a saved version.
+pub async fn activate_email_template(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path((name, locale, version)): Path<(String, String, i32)>,
+) -> Result<impl IntoResponse, HttpError> {
+    let locale = template_locale(&app_state, &name, &locale)?;
+    let repository = PgEmailTemplateRepository::new(&app_state.db_client);
+
+    // An old version may use variables a newer build no longer provides.
+    let versions = repository
+        .get_template_versions(&name, &locale)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+    let saved = versions
+        .iter()
+        .find(|template| template.version == version)
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::EmailTemplateNotFound.to_string()))?;
+    check_template_source(&app_state, &name, &locale, saved.into(), None)?;
+
+    let template = repository
+        .activate_template_version(&name, &locale, version)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::EmailTemplateNotFound.to_string()))?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "name": template.name, "locale": template.locale, "version": template.version }),
+        ..audit_event(AuditAction::AdminEmailTemplateActivated, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok(Json(EmailTemplateResponse {
+        status: "success".to_string(),
+        template: FilterEmailTemplate::filter_email_template(&template),
+    }))
+}
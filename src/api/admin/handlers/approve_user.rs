@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        job::enqueue_email,
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        job::EmailJob,
+        user::ApprovalStatus,
+    },
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    models::user::response::{FilterUser, UserData, UserResponse},
+};
+
+pub async fn approve_user(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(admin): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let repository = &app_state.user_repository;
+
+    let user = repository
+        .get_user(Some(user_id), None, None, None)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::UserNoLongerExist.to_string()))?;
+
+    if user.approval_status == ApprovalStatus::Approved {
+        return Err(HttpError::bad_request(
+            "User is already approved".to_string(),
+        ));
+    }
+
+    let user = repository
+        .update_approval_status(user_id, ApprovalStatus::Approved)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(admin.user.id),
+        target_user_id: Some(user.id),
+        ..audit_event(AuditAction::AdminUserApproved, &client)
+    };
+    record_event(&app_state, event).await;
+
+    enqueue_email(
+        &app_state,
+        EmailJob::AccountApproved {
+            to: user.email.clone(),
+            locale: user.locale.clone(),
+            username: user.name.clone(),
+        },
+    )
+    .await;
+
+    let response = UserResponse {
+        status: "success".to_string(),
+        data: UserData {
+            user: FilterUser::filter_user(&user),
+        },
+    };
+
+    Ok(Json(response))
+}
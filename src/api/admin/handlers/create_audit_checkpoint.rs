@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+
+use crate::{
+    AppState,
+    core::audit_chain::create_checkpoint,
+    errors::http_error::HttpError,
+    infrastructure::audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+    models::audit::response::{AuditCheckpointResponse, FilterAuditCheckpoint},
+};
+
+/// Signs the current head right away instead of waiting for the worker. When
+/// the head is already covered the latest checkpoint is returned.
+pub async fn create_audit_checkpoint(
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let created = create_checkpoint(&app_state)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let (status, checkpoint) = match created {
+        Some(checkpoint) => (StatusCode::CREATED, checkpoint),
+        None => (
+            StatusCode::OK,
+            PgAuditRepository::new(&app_state.db_client)
+                .get_latest_checkpoint()
+                .await
+                .map_err(|e| HttpError::server_error(e.to_string()))?
+                .ok_or_else(|| HttpError::bad_request("The audit log is empty".to_string()))?,
+        ),
+    };
+
+    Ok((
+        status,
+        Json(AuditCheckpointResponse {
+            status: "success".to_string(),
+            checkpoint: FilterAuditCheckpoint::filter_audit_checkpoint(&checkpoint),
+        }),
+    ))
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        invitation::{deliver_invitation, invitation_expiry, new_invitation_nonce},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        invitation::NewInvitation,
+        organization::OrganizationRole,
+    },
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        invitation::{
+            invitation_impl::PgInvitationRepository, invitation_trait::InvitationRepository,
+        },
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::invitation::{
+        request::CreateInvitation,
+        response::{FilterInvitation, InvitationResponse},
+    },
+};
+
+pub async fn create_invitation(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Json(body): Json<CreateInvitation>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    if let Some(organization_id) = body.organization_id {
+        PgOrganizationRepository::new(&app_state.db_client)
+            .get_organization(organization_id)
+            .await
+            .map_err(|e| HttpError::server_error(e.to_string()))?
+            .ok_or_else(|| {
+                HttpError::bad_request(ErrorMessage::OrganizationNotFound.to_string())
+            })?;
+    }
+
+    let organization_role = body
+        .organization_id
+        .map(|_| body.organization_role.unwrap_or(OrganizationRole::Member));
+
+    let invitation = PgInvitationRepository::new(&app_state.db_client)
+        .save_invitation(NewInvitation {
+            email: body.email.to_lowercase(),
+            role: body.role,
+            organization_id: body.organization_id,
+            organization_role,
+            invited_by: user.user.id,
+            token_nonce: new_invitation_nonce(),
+            expires_at: invitation_expiry(),
+        })
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    deliver_invitation(&app_state, &invitation, &user.user.name).await?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({
+            "invitationId": invitation.id,
+            "email": invitation.email,
+            "role": invitation.role.to_str(),
+            "organizationId": invitation.organization_id,
+        }),
+        ..audit_event(AuditAction::AdminInvitationCreated, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let response = InvitationResponse {
+        status: "success".to_string(),
+        invitation: FilterInvitation::filter_invitation(&invitation),
+    };
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        webhook::new_webhook_secret,
+    },
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::http_error::HttpError,
+    infrastructure::{
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+        webhook::{webhook_impl::PgWebhookRepository, webhook_trait::WebhookRepository},
+    },
+    models::webhook::{
+        request::CreateWebhookEndpoint,
+        response::{FilterWebhookEndpoint, WebhookEndpointCreatedResponse},
+    },
+};
+
+pub async fn create_webhook_endpoint(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Json(body): Json<CreateWebhookEndpoint>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    if !(body.url.starts_with("https://") || body.url.starts_with("http://")) {
+        return Err(HttpError::bad_request(
+            "Webhook URL must use http or https".to_string(),
+        ));
+    }
+
+    let secret = body.secret.clone().unwrap_or_else(new_webhook_secret);
+
+    let mut events: Vec<String> = body
+        .events
+        .iter()
+        .map(|event| event.to_str().to_string())
+        .collect();
+    events.sort();
+    events.dedup();
+
+    let endpoint = PgWebhookRepository::new(&app_state.db_client)
+        .save_endpoint(&body.url, &secret, &events, user.user.id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "endpointId": endpoint.id, "url": endpoint.url, "events": endpoint.events }),
+        ..audit_event(AuditAction::AdminWebhookCreated, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let response = WebhookEndpointCreatedResponse {
+        status: "success".to_string(),
+        endpoint: FilterWebhookEndpoint::filter_webhook_endpoint(&endpoint),
+        secret,
+    };
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
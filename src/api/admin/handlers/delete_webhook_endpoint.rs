@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::audit::{audit_event, record_event},
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::http_error::HttpError,
+    infrastructure::{
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+        webhook::{webhook_impl::PgWebhookRepository, webhook_trait::WebhookRepository},
+    },
+    models::user::response::Response,
+};
+
+/// Removes the endpoint together with its delivery log.
+pub async fn delete_webhook_endpoint(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(endpoint_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let deleted = PgWebhookRepository::new(&app_state.db_client)
+        .delete_endpoint(endpoint_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    if !deleted {
+        return Err(HttpError::bad_request(
+            "Webhook endpoint not found".to_string(),
+        ));
+    }
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "endpointId": endpoint_id }),
+        ..audit_event(AuditAction::AdminWebhookDeleted, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok(Json(Response {
+        status: "success",
+        message: "Webhook endpoint has been deleted.".to_string(),
+    }))
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json,
+    extract::Path,
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+    models::audit::response::FilterAuditCheckpoint,
+};
+
+const DISPOSITION: HeaderValue =
+    HeaderValue::from_static("attachment; filename=\"audit-checkpoint.json\"");
+
+/// Serves a signed checkpoint as a standalone document for off-site storage.
+pub async fn export_audit_checkpoint(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Path(checkpoint_id): Path<Uuid>,
+) -> Result<Response, HttpError> {
+    let checkpoint = PgAuditRepository::new(&app_state.db_client)
+        .get_checkpoint(checkpoint_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request("Checkpoint not found".to_string()))?;
+
+    let mut response =
+        Json(FilterAuditCheckpoint::filter_audit_checkpoint(&checkpoint)).into_response();
+    response
+        .headers_mut()
+        .insert(header::CONTENT_DISPOSITION, DISPOSITION);
+    Ok(response)
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Query, response::IntoResponse};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+    models::audit::response::{AuditCheckpointListResponse, FilterAuditCheckpoint},
+};
+
+#[derive(Validate, Serialize, Deserialize)]
+pub struct RequestQuery {
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<usize>,
+}
+
+pub async fn get_audit_checkpoints(
+    Query(query_params): Query<RequestQuery>,
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    query_params
+        .validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let limit = query_params.limit.unwrap_or(20) as i64;
+
+    let checkpoints = PgAuditRepository::new(&app_state.db_client)
+        .get_checkpoints(limit)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(AuditCheckpointListResponse {
+        status: "success".to_string(),
+        checkpoints: FilterAuditCheckpoint::filter_audit_checkpoints(&checkpoints),
+    }))
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Query, response::IntoResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    domains::audit::AuditFilter,
+    errors::http_error::HttpError,
+    infrastructure::audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+    models::audit::response::{AuditEventListResponse, FilterAuditEvent},
+};
+
+#[derive(Validate, Serialize, Deserialize)]
+pub struct RequestQuery {
+    #[serde(rename = "actorId")]
+    pub actor_id: Option<Uuid>,
+    #[validate(length(min = 1, max = 100))]
+    pub action: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<usize>,
+}
+
+/// Newest events first. `nextCursor` is set while older events remain.
+pub async fn get_audit_events(
+    Query(query_params): Query<RequestQuery>,
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    query_params
+        .validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let before = query_params
+        .cursor
+        .as_deref()
+        .map(str::parse::<i64>)
+        .transpose()
+        .map_err(|_| HttpError::bad_request("Invalid cursor".to_string()))?;
+
+    let limit = query_params.limit.unwrap_or(50) as i64;
+
+    let filter = AuditFilter {
+        actor_id: query_params.actor_id,
+        action: query_params.action,
+        from: query_params.from,
+        to: query_params.to,
+        before,
+    };
+
+    // One extra row tells us whether another page exists.
+    let mut events = PgAuditRepository::new(&app_state.db_client)
+        .get_events(&filter, limit + 1)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let next_cursor = if events.len() as i64 > limit {
+        events.truncate(limit as usize);
+        events.last().map(|event| event.sequence.to_string())
+    } else {
+        None
+    };
+
+    let response = AuditEventListResponse {
+        status: "success".to_string(),
+        events: FilterAuditEvent::filter_audit_events(&events),
+        next_cursor,
+    };
+
+    Ok(Json(response))
+}
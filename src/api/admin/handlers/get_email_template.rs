@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+
+use crate::{
+    AppState,
+    core::email_template::template_locale,
+    errors::http_error::HttpError,
+    infrastructure::email_template::{
+        email_template_impl::PgEmailTemplateRepository,
+        email_template_trait::EmailTemplateRepository,
+    },
+    models::email_template::response::{EmailTemplateDetailResponse, FilterEmailTemplate},
+};
+
+/// The built-in source, for admins to start editing from, and every saved
+/// version of the override.
+pub async fn get_email_template(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Path((name, locale)): Path<(String, String)>,
+) -> Result<impl IntoResponse, HttpError> {
+    let locale = template_locale(&app_state, &name, &locale)?;
+
+    let versions = PgEmailTemplateRepository::new(&app_state.db_client)
+        .get_template_versions(&name, &locale)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(EmailTemplateDetailResponse {
+        status: "success".to_string(),
+        builtin: app_state
+            .mail_renderer()
+            .builtin_source(&name, &locale)
+            .map(Into::into),
+        active_version: versions
+            .iter()
+            .find(|template| template.active)
+            .map(|template| template.version),
+        versions: FilterEmailTemplate::filter_email_templates(&versions),
+        name,
+        locale,
+    }))
+}
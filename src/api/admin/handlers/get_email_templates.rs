@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    helpers::mail::renderer::EMAIL_TEMPLATES,
+    infrastructure::email_template::{
+        email_template_impl::PgEmailTemplateRepository,
+        email_template_trait::EmailTemplateRepository,
+    },
+    models::email_template::response::{EmailTemplateListResponse, EmailTemplateSummary},
+};
+
+/// Every email in every locale, with the override in use if there is one.
+pub async fn get_email_templates(
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let overrides = PgEmailTemplateRepository::new(&app_state.db_client)
+        .get_all_active_templates()
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let renderer = app_state.mail_renderer();
+    let templates = EMAIL_TEMPLATES
+        .iter()
+        .flat_map(|name| {
+            renderer.locales().iter().map(|locale| {
+                let active = overrides
+                    .iter()
+                    .find(|template| template.name == *name && template.locale == *locale);
+
+                EmailTemplateSummary {
+                    name: name.to_string(),
+                    locale: locale.to_owned(),
+                    builtin: renderer.builtin_source(name, locale).is_some(),
+                    active_version: active.map(|template| template.version),
+                    updated_at: active.map(|template| template.created_at),
+                }
+            })
+        })
+        .collect();
+
+    Ok(Json(EmailTemplateListResponse {
+        status: "success".to_string(),
+        templates,
+    }))
+}
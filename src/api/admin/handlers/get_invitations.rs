@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Query, response::IntoResponse};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::invitation::{
+        invitation_impl::PgInvitationRepository, invitation_trait::InvitationRepository,
+    },
+    models::invitation::response::{FilterInvitation, InvitationListResponse},
+};
+
+#[derive(Validate, Serialize, Deserialize)]
+pub struct RequestQuery {
+    #[validate(range(min = 1))]
+    pub page: Option<usize>,
+    #[validate(range(min = 1, max = 50))]
+    pub limit: Option<usize>,
+}
+
+pub async fn get_invitations(
+    Query(query_params): Query<RequestQuery>,
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    query_params
+        .validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let page = query_params.page.unwrap_or(1);
+    let limit = query_params.limit.unwrap_or(10);
+
+    let repository = PgInvitationRepository::new(&app_state.db_client);
+
+    let invitations = repository
+        .get_pending_invitations(page as u32, limit)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let invitation_count = repository
+        .get_pending_invitation_count()
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let response = InvitationListResponse {
+        status: "success".to_string(),
+        invitations: FilterInvitation::filter_invitations(&invitations),
+        results: invitation_count,
+    };
+
+    Ok(Json(response))
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Query, response::IntoResponse};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    domains::job::JobStatus,
+    errors::http_error::HttpError,
+    infrastructure::job::{job_impl::PgJobRepository, job_trait::JobRepository},
+    models::job::response::{FilterJob, JobListResponse},
+};
+
+#[derive(Validate, Serialize, Deserialize)]
+pub struct RequestQuery {
+    pub status: Option<JobStatus>,
+    #[validate(range(min = 1))]
+    pub page: Option<usize>,
+    #[validate(range(min = 1, max = 50))]
+    pub limit: Option<usize>,
+}
+
+pub async fn get_jobs(
+    Query(query_params): Query<RequestQuery>,
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    query_params
+        .validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let page = query_params.page.unwrap_or(1);
+    let limit = query_params.limit.unwrap_or(10);
+
+    let repository = PgJobRepository::new(&app_state.db_client);
+
+    let jobs = repository
+        .get_jobs(query_params.status, page as u32, limit)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let job_count = repository
+        .get_job_count(query_params.status)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(JobListResponse {
+        status: "success".to_string(),
+        jobs: FilterJob::filter_jobs(&jobs),
+        results: job_count,
+    }))
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    models::user::response::{FilterStatusTransition, StatusHistoryResponse},
+};
+
+pub async fn get_status_history(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let transitions = app_state
+        .user_repository
+        .get_status_transitions(user_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(StatusHistoryResponse {
+        status: "success".to_string(),
+        transitions: FilterStatusTransition::filter_transitions(&transitions),
+    }))
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json,
+    extract::{Path, Query},
+    response::IntoResponse,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::webhook::{
+        webhook_impl::PgWebhookRepository, webhook_trait::WebhookRepository,
+    },
+    models::webhook::response::{FilterWebhookDelivery, WebhookDeliveryListResponse},
+};
+
+#[derive(Validate, Serialize, Deserialize)]
+pub struct RequestQuery {
+    #[validate(range(min = 1))]
+    pub page: Option<usize>,
+    #[validate(range(min = 1, max = 50))]
+    pub limit: Option<usize>,
+}
+
+pub async fn get_webhook_deliveries(
+    Query(query_params): Query<RequestQuery>,
+    Path(endpoint_id): Path<Uuid>,
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    query_params
+        .validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let page = query_params.page.unwrap_or(1);
+    let limit = query_params.limit.unwrap_or(10);
+
+    let repository = PgWebhookRepository::new(&app_state.db_client);
+
+    let deliveries = repository
+        .get_deliveries(endpoint_id, page as u32, limit)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let delivery_count = repository
+        .get_delivery_count(endpoint_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(WebhookDeliveryListResponse {
+        status: "success".to_string(),
+        deliveries: FilterWebhookDelivery::filter_webhook_deliveries(&deliveries),
+        results: delivery_count,
+    }))
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::webhook::{
+        webhook_impl::PgWebhookRepository, webhook_trait::WebhookRepository,
+    },
+    models::webhook::response::{FilterWebhookEndpoint, WebhookEndpointListResponse},
+};
+
+pub async fn get_webhook_endpoints(
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let endpoints = PgWebhookRepository::new(&app_state.db_client)
+        .get_endpoints()
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(WebhookEndpointListResponse {
+        status: "success".to_string(),
+        endpoints: FilterWebhookEndpoint::filter_webhook_endpoints(&endpoints),
+    }))
+}
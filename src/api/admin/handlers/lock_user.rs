@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use serde_json::json;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::{
+        account_status::transition_status,
+        audit::{audit_event, record_event},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::{AccountStatus, StatusChange},
+    },
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    models::user::{
+        request::LockUser,
+        response::{FilterUser, UserData, UserResponse},
+    },
+};
+
+/// Locks an active account until an admin reinstates it through unsuspend.
+pub async fn lock_user(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(admin): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(user_id): Path<Uuid>,
+    Json(body): Json<LockUser>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    if admin.user.id == user_id {
+        return Err(HttpError::bad_request(
+            "You cannot lock your own account".to_string(),
+        ));
+    }
+
+    let user = app_state
+        .user_repository
+        .get_user(Some(user_id), None, None, None)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::UserNoLongerExist.to_string()))?;
+
+    let user = transition_status(
+        &app_state,
+        &user,
+        StatusChange {
+            to: AccountStatus::Locked,
+            reason: Some(body.reason.clone()),
+            suspended_until: None,
+            actor_id: Some(admin.user.id),
+        },
+    )
+    .await?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(admin.user.id),
+        target_user_id: Some(user.id),
+        metadata: json!({ "reason": body.reason }),
+        ..audit_event(AuditAction::AdminUserLocked, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let response = UserResponse {
+        status: "success".to_string(),
+        data: UserData {
+            user: FilterUser::filter_user(&user),
+        },
+    };
+
+    Ok(Json(response))
+}
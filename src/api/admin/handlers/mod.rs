@@ -0,0 +1,29 @@
+pub mod activate_email_template;
+pub mod approve_user;
+pub mod create_audit_checkpoint;
+pub mod create_invitation;
+pub mod create_webhook_endpoint;
+pub mod delete_webhook_endpoint;
+pub mod export_audit_checkpoint;
+pub mod get_audit_checkpoints;
+pub mod get_audit_events;
+pub mod get_email_template;
+pub mod get_email_templates;
+pub mod get_invitations;
+pub mod get_jobs;
+pub mod get_pending_approvals;
+pub mod get_status_history;
+pub mod get_webhook_deliveries;
+pub mod get_webhook_endpoints;
+pub mod lock_user;
+pub mod preview_email_template;
+pub mod redeliver_webhook;
+pub mod reject_user;
+pub mod resend_invitation;
+pub mod retry_job;
+pub mod revert_email_template;
+pub mod revoke_invitation;
+pub mod suspend_user;
+pub mod unsuspend_user;
+pub mod update_email_template;
+pub mod verify_audit_chain;
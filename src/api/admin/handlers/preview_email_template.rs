@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+
+use crate::{
+    AppState,
+    core::email_template::{check_template_source, preview_context, template_locale},
+    errors::http_error::HttpError,
+    helpers::mail::renderer::TemplateSource,
+    infrastructure::email_template::{
+        email_template_impl::PgEmailTemplateRepository,
+        email_template_trait::EmailTemplateRepository,
+    },
+    models::email_template::{
+        request::PreviewEmailTemplate, response::EmailTemplatePreviewResponse,
+    },
+};
+
+/// Renders a draft, or the email as it would be sent now, with sample data.
+pub async fn preview_email_template(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Path((name, locale)): Path<(String, String)>,
+    body: Option<Json<PreviewEmailTemplate>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let locale = template_locale(&app_state, &name, &locale)?;
+    let body = body.map(|Json(body)| body).unwrap_or_default();
+
+    let rendered = match (&body.subject, &body.html_body, &body.text_body) {
+        (Some(subject), Some(html_body), Some(text_body)) => {
+            let source = TemplateSource {
+                subject,
+                html_body,
+                text_body,
+            };
+            check_template_source(&app_state, &name, &locale, source, body.data.as_ref())?
+        }
+        (None, None, None) => {
+            let overrides = PgEmailTemplateRepository::new(&app_state.db_client)
+                .get_active_templates(&name)
+                .await
+                .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+            app_state
+                .mail_renderer()
+                .render_with_overrides(
+                    &name,
+                    Some(&locale),
+                    preview_context(&name, body.data.as_ref()),
+                    &overrides,
+                )
+                .map_err(|e| HttpError::bad_request(format!("Template does not render: {}", e)))?
+        }
+        _ => {
+            return Err(HttpError::bad_request(
+                "Provide subject, htmlBody and textBody together to preview a draft".to_string(),
+            ));
+        }
+    };
+
+    Ok(Json(EmailTemplatePreviewResponse {
+        status: "success".to_string(),
+        locale,
+        preview: rendered.into(),
+    }))
+}
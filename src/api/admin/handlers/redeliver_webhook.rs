@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, http::StatusCode, response::IntoResponse};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::audit::{audit_event, record_event},
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::http_error::HttpError,
+    infrastructure::{
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+        webhook::{webhook_impl::PgWebhookRepository, webhook_trait::WebhookRepository},
+    },
+    models::webhook::response::{FilterWebhookDelivery, WebhookDeliveryResponse},
+};
+
+/// Queues a fresh delivery of the same event. The original entry stays in the
+/// log untouched; receivers can dedupe on the shared event id.
+pub async fn redeliver_webhook(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(delivery_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let repository = PgWebhookRepository::new(&app_state.db_client);
+
+    let original = repository
+        .get_delivery(delivery_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request("Webhook delivery not found".to_string()))?;
+
+    let delivery = repository
+        .enqueue_delivery(
+            original.endpoint_id,
+            original.event_id,
+            &original.event_type,
+            &original.payload.0,
+        )
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "deliveryId": original.id, "redeliveryId": delivery.id }),
+        ..audit_event(AuditAction::AdminWebhookRedelivered, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(WebhookDeliveryResponse {
+            status: "success".to_string(),
+            delivery: FilterWebhookDelivery::filter_webhook_delivery(&delivery),
+        }),
+    ))
+}
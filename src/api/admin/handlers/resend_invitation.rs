@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        invitation::{deliver_invitation, invitation_expiry, new_invitation_nonce},
+    },
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        invitation::{
+            invitation_impl::PgInvitationRepository, invitation_trait::InvitationRepository,
+        },
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    },
+    models::invitation::response::{FilterInvitation, InvitationResponse},
+};
+
+pub async fn resend_invitation(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(invitation_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let invitation = PgInvitationRepository::new(&app_state.db_client)
+        .refresh_invitation(invitation_id, &new_invitation_nonce(), invitation_expiry())
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::InvitationNotFound.to_string()))?;
+
+    deliver_invitation(&app_state, &invitation, &user.user.name).await?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "invitationId": invitation.id, "email": invitation.email }),
+        ..audit_event(AuditAction::AdminInvitationResent, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let response = InvitationResponse {
+        status: "success".to_string(),
+        invitation: FilterInvitation::filter_invitation(&invitation),
+    };
+
+    Ok(Json(response))
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, http::StatusCode, response::IntoResponse};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::audit::{audit_event, record_event},
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::http_error::HttpError,
+    infrastructure::{
+        job::{job_impl::PgJobRepository, job_trait::JobRepository},
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    },
+    models::job::response::{FilterJob, JobResponse},
+};
+
+/// Moves a dead-lettered job back onto the queue. Jobs in any other state are
+/// left alone.
+pub async fn retry_job(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let job = PgJobRepository::new(&app_state.db_client)
+        .retry_job(job_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request("Dead job not found".to_string()))?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "jobId": job.id, "kind": job.kind }),
+        ..audit_event(AuditAction::AdminJobRetried, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(JobResponse {
+            status: "success".to_string(),
+            job: FilterJob::filter_job(&job),
+        }),
+    ))
+}
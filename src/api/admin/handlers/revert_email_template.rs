@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use serde_json::json;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        email_template::template_locale,
+    },
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::http_error::HttpError,
+    infrastructure::{
+        email_template::{
+            email_template_impl::PgEmailTemplateRepository,
+            email_template_trait::EmailTemplateRepository,
+        },
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    },
+    models::user::response::Response,
+};
+
+/// Goes back to the built-in template. Saved versions stay available to
+/// activate again.
+pub async fn revert_email_template(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path((name, locale)): Path<(String, String)>,
+) -> Result<impl IntoResponse, HttpError> {
+    let locale = template_locale(&app_state, &name, &locale)?;
+
+    let reverted = PgEmailTemplateRepository::new(&app_state.db_client)
+        .deactivate_template(&name, &locale)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    if !reverted {
+        return Err(HttpError::bad_request(
+            "Email template has no active override".to_string(),
+        ));
+    }
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "name": name, "locale": locale }),
+        ..audit_event(AuditAction::AdminEmailTemplateReverted, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok(Json(Response {
+        status: "success",
+        message: "Email template has been reverted to the built-in version.".to_string(),
+    }))
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::audit::{audit_event, record_event},
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        invitation::{
+            invitation_impl::PgInvitationRepository, invitation_trait::InvitationRepository,
+        },
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    },
+    models::user::response::Response,
+};
+
+pub async fn revoke_invitation(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(invitation_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let revoked = PgInvitationRepository::new(&app_state.db_client)
+        .revoke_invitation(invitation_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    if !revoked {
+        return Err(HttpError::bad_request(
+            ErrorMessage::InvitationNotFound.to_string(),
+        ));
+    }
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "invitationId": invitation_id }),
+        ..audit_event(AuditAction::AdminInvitationRevoked, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok(Json(Response {
+        status: "success",
+        message: "Invitation has been revoked.".to_string(),
+    }))
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::{
+        account_status::transition_status,
+        audit::{audit_event, record_event},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::{AccountStatus, StatusChange},
+    },
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    models::user::response::{FilterUser, UserData, UserResponse},
+};
+
+/// Reactivates a suspended or locked account.
+pub async fn unsuspend_user(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(admin): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let user = app_state
+        .user_repository
+        .get_user(Some(user_id), None, None, None)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::UserNoLongerExist.to_string()))?;
+
+    if !matches!(
+        user.status,
+        AccountStatus::Suspended | AccountStatus::Locked
+    ) {
+        return Err(HttpError::bad_request(
+            "User is not suspended or locked".to_string(),
+        ));
+    }
+
+    let user = transition_status(
+        &app_state,
+        &user,
+        StatusChange {
+            to: AccountStatus::Active,
+            reason: Some("reinstated by admin".to_string()),
+            suspended_until: None,
+            actor_id: Some(admin.user.id),
+        },
+    )
+    .await?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(admin.user.id),
+        target_user_id: Some(user.id),
+        ..audit_event(AuditAction::AdminUserUnsuspended, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let response = UserResponse {
+        status: "success".to_string(),
+        data: UserData {
+            user: FilterUser::filter_user(&user),
+        },
+    };
+
+    Ok(Json(response))
+}
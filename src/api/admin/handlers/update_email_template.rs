@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, http::StatusCode, response::IntoResponse};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        email_template::{check_template_source, template_locale},
+    },
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::http_error::HttpError,
+    helpers::mail::renderer::TemplateSource,
+    infrastructure::{
+        email_template::{
+            email_template_impl::PgEmailTemplateRepository,
+            email_template_trait::EmailTemplateRepository,
+        },
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    },
+    models::email_template::{
+        request::UpdateEmailTemplate,
+        response::{EmailTemplateResponse, FilterEmailTemplate},
+    },
+};
+
+/// Saves a new version of the override and starts sending it. Templates that
+/// fail to render with the sample data are refused.
+pub async fn update_email_template(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    Path((name, locale)): Path<(String, String)>,
+    Json(body): Json<UpdateEmailTemplate>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let locale = template_locale(&app_state, &name, &locale)?;
+
+    let source = TemplateSource {
+        subject: &body.subject,
+        html_body: &body.html_body,
+        text_body: &body.text_body,
+    };
+    check_template_source(&app_state, &name, &locale, source, None)?;
+
+    let template = PgEmailTemplateRepository::new(&app_state.db_client)
+        .save_template_version(
+            &name,
+            &locale,
+            &body.subject,
+            &body.html_body,
+            &body.text_body,
+            user.user.id,
+        )
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.user.id),
+        metadata: json!({ "name": template.name, "locale": template.locale, "version": template.version }),
+        ..audit_event(AuditAction::AdminEmailTemplateUpdated, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EmailTemplateResponse {
+            status: "success".to_string(),
+            template: FilterEmailTemplate::filter_email_template(&template),
+        }),
+    ))
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+
+use crate::{
+    AppState, core::audit_chain::verify_chain, errors::http_error::HttpError,
+    models::audit::response::AuditVerificationResponse,
+};
+
+/// Reports whether the audit chain is intact and, if not, its first broken
+/// link. Runs in the request; the chain is walked in fixed-size batches.
+pub async fn verify_audit_chain(
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let verification = verify_chain(&app_state)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(AuditVerificationResponse {
+        status: "success".to_string(),
+        verification,
+    }))
+}
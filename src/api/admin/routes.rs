@@ -0,0 +1,79 @@
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use super::handlers::{
+    activate_email_template::activate_email_template, approve_user::approve_user,
+    create_audit_checkpoint::create_audit_checkpoint, create_invitation::create_invitation,
+    create_webhook_endpoint::create_webhook_endpoint,
+    delete_webhook_endpoint::delete_webhook_endpoint,
+    export_audit_checkpoint::export_audit_checkpoint, get_audit_checkpoints::get_audit_checkpoints,
+    get_audit_events::get_audit_events, get_email_template::get_email_template,
+    get_email_templates::get_email_templates, get_invitations::get_invitations, get_jobs::get_jobs,
+    get_pending_approvals::get_pending_approvals, get_status_history::get_status_history,
+    get_webhook_deliveries::get_webhook_deliveries, get_webhook_endpoints::get_webhook_endpoints,
+    lock_user::lock_user, preview_email_template::preview_email_template,
+    redeliver_webhook::redeliver_webhook, reject_user::reject_user,
+    resend_invitation::resend_invitation, retry_job::retry_job,
+    revert_email_template::revert_email_template, revoke_invitation::revoke_invitation,
+    suspend_user::suspend_user, unsuspend_user::unsuspend_user,
+    update_email_template::update_email_template, verify_audit_chain::verify_audit_chain,
+};
+
+pub fn admin_handler() -> Router {
+    Router::new()
+        .route("/invitations", post(create_invitation).get(get_invitations))
+        .route("/invitations/{invitation_id}", delete(revoke_invitation))
+        .route(
+            "/invitations/{invitation_id}/resend",
+            post(resend_invitation),
+        )
+        .route("/approvals", get(get_pending_approvals))
+        .route("/approvals/{user_id}/approve", post(approve_user))
+        .route("/approvals/{user_id}/reject", post(reject_user))
+        .route("/users/{user_id}/suspend", post(suspend_user))
+        .route("/users/{user_id}/unsuspend", post(unsuspend_user))
+        .route("/users/{user_id}/lock", post(lock_user))
+        .route("/users/{user_id}/status-history", get(get_status_history))
+        .route("/audit", get(get_audit_events))
+        .route("/audit/verify", get(verify_audit_chain))
+        .route(
+            "/audit/checkpoints",
+            get(get_audit_checkpoints).post(create_audit_checkpoint),
+        )
+        .route(
+            "/audit/checkpoints/{checkpoint_id}",
+            get(export_audit_checkpoint),
+        )
+        .route(
+            "/webhooks",
+            post(create_webhook_endpoint).get(get_webhook_endpoints),
+        )
+        .route("/webhooks/{endpoint_id}", delete(delete_webhook_endpoint))
+        .route(
+            "/webhooks/{endpoint_id}/deliveries",
+            get(get_webhook_deliveries),
+        )
+        .route(
+            "/webhooks/deliveries/{delivery_id}/redeliver",
+            post(redeliver_webhook),
+        )
+        .route("/jobs", get(get_jobs))
+        .route("/jobs/{job_id}/retry", post(retry_job))
+        .route("/email-templates", get(get_email_templates))
+        .route(
+            "/email-templates/{name}/{locale}",
+            get(get_email_template)
+                .put(update_email_template)
+                .delete(revert_email_template),
+        )
+        .route(
+            "/email-templates/{name}/{locale}/preview",
+            post(preview_email_template),
+        )
+        .route(
+            "/email-templates/{name}/{locale}/versions/{version}/activate",
+            post(activate_email_template),
+        )
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        invitation::{complete_invitation, find_usable_invitation},
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        invitation::{Invitation, Invitee},
+        user::User,
+        webhook::WebhookEvent,
+    },
+    errors::http_error::HttpError,
+    infrastructure::middleware::client_info::ClientInfo,
+    models::{invitation::request::AcceptInvitation, user::response::Response},
+    utils::password,
+};
+
+pub async fn accept_invitation(
+    Extension(app_state): Extension<Arc<AppState>>,
+    client: ClientInfo,
+    Json(body): Json<AcceptInvitation>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let invitation = find_usable_invitation(&app_state, &body.token).await?;
+
+    let existing_user = app_state
+        .user_repository
+        .get_user(None, None, Some(&invitation.email), None)
+        .await
+        .map_err(server_error)?;
+
+    let (user, status) = match existing_user {
+        Some(user) => (
+            complete_invitation(&app_state, &invitation, Invitee::Existing(user.id)).await?,
+            StatusCode::OK,
+        ),
+        None => (
+            create_invited_user(&app_state, &invitation, &body).await?,
+            StatusCode::CREATED,
+        ),
+    };
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.id),
+        target_user_id: Some(user.id),
+        metadata: json!({
+            "invitationId": invitation.id,
+            "accountCreated": status == StatusCode::CREATED,
+        }),
+        ..audit_event(AuditAction::InvitationAccepted, &client)
+    };
+    record_event(&app_state, event).await;
+
+    if status == StatusCode::CREATED {
+        dispatch_event(
+            &app_state,
+            WebhookEvent::UserRegistered,
+            user_event_data(&user),
+        )
+        .await;
+    }
+
+    Ok((
+        status,
+        Json(Response {
+            status: "success",
+            message: "Invitation accepted. You can now log in.".to_string(),
+        }),
+    ))
+}
+
+async fn create_invited_user(
+    app_state: &AppState,
+    invitation: &Invitation,
+    body: &AcceptInvitation,
+) -> Result<User, HttpError> {
+    let (Some(name), Some(new_password)) = (&body.name, &body.password) else {
+        return Err(HttpError::bad_request(
+            "Name and password are required to create an account".to_string(),
+        ));
+    };
+
+    if body.confirm_password.as_ref() != Some(new_password) {
+        return Err(HttpError::bad_request("Password don't match".to_string()));
+    }
+
+    let hash_password = password::hash_password(new_password).map_err(server_error)?;
+
+    complete_invitation(
+        app_state,
+        invitation,
+        Invitee::New {
+            name,
+            password: &hash_password,
+            locale: None,
+        },
+    )
+    .await
+}
+
+fn server_error<E: ToString>(err: E) -> HttpError {
+    HttpError::server_error(err.to_string())
+}
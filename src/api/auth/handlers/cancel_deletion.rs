@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::{
+        account_deletion::{DELETION_CANCEL_PURPOSE, cancel_link_matches},
+        audit::{audit_event, record_event},
+    },
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::middleware::client_info::ClientInfo,
+    models::user::{request::CancelDeletion, response::Response},
+    utils::token,
+};
+
+pub async fn cancel_deletion(
+    Extension(app_state): Extension<Arc<AppState>>,
+    client: ClientInfo,
+    Json(body): Json<CancelDeletion>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let claims = token::decode_signed_link_token(
+        &body.token,
+        DELETION_CANCEL_PURPOSE,
+        app_state.config().jwt.secret.as_bytes(),
+    )?;
+
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| HttpError::bad_request(ErrorMessage::InvalidToken.to_string()))?;
+
+    let repository = &app_state.user_repository;
+
+    let user = repository
+        .get_user(Some(user_id), None, None, None)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::UserNoLongerExist.to_string()))?;
+
+    if !cancel_link_matches(&user, &claims) {
+        return Err(HttpError::bad_request(
+            ErrorMessage::InvalidToken.to_string(),
+        ));
+    }
+
+    repository
+        .cancel_deletion(user.id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.id),
+        target_user_id: Some(user.id),
+        ..audit_event(AuditAction::DeletionCancelled, &client)
+    };
+    record_event(&app_state, event).await;
+
+    Ok(Json(Response {
+        status: "success",
+        message: "Account deletion has been cancelled.".to_string(),
+    }))
+}
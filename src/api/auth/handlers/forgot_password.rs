@@ -7,9 +7,11 @@ use validator::Validate;
 
 use crate::{
     AppState,
+    core::{
+        job::enqueue_email, metrics::record_password_reset_requested, redirect::resolve_return_to,
+    },
+    domains::job::EmailJob,
     errors::http_error::HttpError,
-    helpers::mail::mails::send_forgot_password_email,
-    infrastructure::user::{user_trait::UserRepository, users_impl::PgUserRepository},
     models::user::response::Response,
 };
 use serde::{Deserialize, Serialize};
@@ -21,6 +23,9 @@ pub struct ForgotPasswordRequest {
         email(message = "Email is invalid")
     )]
     pub email: String,
+    /// Passed on to the reset page so the frontend can continue from there.
+    #[serde(rename = "returnTo")]
+    pub return_to: Option<String>,
 }
 
 pub async fn forgot_password(
@@ -30,7 +35,10 @@ pub async fn forgot_password(
     body.validate()
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
-    let result = PgUserRepository::new(&app_state.db_client)
+    let return_to = resolve_return_to(&app_state, body.return_to.as_deref())?;
+
+    let result = app_state
+        .user_repository
         .get_user(None, None, Some(&body.email), None)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
@@ -43,21 +51,29 @@ pub async fn forgot_password(
 
     let user_id = Uuid::parse_str(&user.id.to_string()).unwrap();
 
-    PgUserRepository::new(&app_state.db_client)
+    app_state
+        .user_repository
         .add_verified_token(user_id, &verification_token, expires_at)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
-    let rest_link = format!(
-        "http://localhost:5173/reset-password?token={}",
-        &verification_token
-    );
+    let reset_link = app_state
+        .config()
+        .urls
+        .reset_password_link(&verification_token, return_to.as_deref());
 
-    let email_sent = send_forgot_password_email(&user.email, &rest_link, &user.name).await;
+    enqueue_email(
+        &app_state,
+        EmailJob::ForgotPassword {
+            to: user.email,
+            locale: user.locale,
+            username: user.name,
+            reset_link,
+        },
+    )
+    .await;
 
-    if let Err(e) = email_sent {
-        eprintln!("Failed to send forgot password mail: {}", e);
-    }
+    record_password_reset_requested();
 
     Ok(Json(Response {
         message: "Password reset link has been sent to your email!".to_string(),
@@ -2,44 +2,75 @@ use std::sync::Arc;
 
 use axum::{Extension, Json, http::header, response::IntoResponse};
 use cookie::Cookie;
+use serde_json::json;
 use validator::Validate;
 
 use crate::{
     AppState,
+    core::audit::{audit_event, record_event},
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::AccountStatus,
+    },
     errors::{error_message::ErrorMessage, http_error::HttpError},
-    infrastructure::user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    infrastructure::middleware::{auth::ensure_account_usable, client_info::ClientInfo},
     models::user::{request::LoginUser, response::UserLoginResponse},
     utils::{password, token},
 };
 
 pub async fn login(
     Extension(app_state): Extension<Arc<AppState>>,
+    client: ClientInfo,
     Json(body): Json<LoginUser>,
 ) -> Result<impl IntoResponse, HttpError> {
     body.validate()
         .map_err(|e| HttpError::bad_request(e.to_string()))?;
 
-    let result = PgUserRepository::new(&app_state.db_client)
+    let result = app_state
+        .user_repository
         .get_user(None, None, Some(&body.email), None)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
-    let user = result.ok_or(HttpError::bad_request(
-        ErrorMessage::WrongCredentials.to_string(),
-    ))?;
+    let Some(user) = result else {
+        let event = NewAuditEvent {
+            metadata: json!({ "email": body.email, "reason": "unknown_email" }),
+            ..audit_event(AuditAction::LoginFailed, &client)
+        };
+        record_event(&app_state, event).await;
+        return Err(HttpError::bad_request(
+            ErrorMessage::WrongCredentials.to_string(),
+        ));
+    };
 
-    let password_matched = password::compare(&body.password, &user.password)
-        .map_err(|_| HttpError::bad_request(ErrorMessage::WrongCredentials.to_string()))?;
+    let password_matched = password::compare(&body.password, &user.password).unwrap_or(false);
 
     if password_matched {
+        if let Err(e) = ensure_account_usable(&user) {
+            let event = NewAuditEvent {
+                actor_id: Some(user.id),
+                target_user_id: Some(user.id),
+                metadata: json!({ "reason": "account_unusable", "status": user.status.to_str() }),
+                ..audit_event(AuditAction::LoginFailed, &client)
+            };
+            record_event(&app_state, event).await;
+            return Err(e);
+        }
+
+        app_state
+            .user_repository
+            .reset_failed_logins(user.id)
+            .await
+            .map_err(|e| HttpError::server_error(e.to_string()))?;
+
         let token = token::generate_token(
             &user.id.to_string(),
-            &app_state.env.jwt_secret.as_bytes(),
-            app_state.env.jwt_maxage,
+            app_state.config().jwt.secret.as_bytes(),
+            app_state.config().jwt.maxage,
         )
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
-        let cookie_duration = time::Duration::minutes(app_state.env.jwt_maxage * 60);
+        let cookie_duration = time::Duration::minutes(app_state.config().jwt.maxage * 60);
 
         let cookie = Cookie::build(("token", token.clone()))
             .path("/")
@@ -51,12 +82,45 @@ pub async fn login(
             status: "success".to_string(),
             token,
         });
+        let event = NewAuditEvent {
+            actor_id: Some(user.id),
+            target_user_id: Some(user.id),
+            ..audit_event(AuditAction::LoginSucceeded, &client)
+        };
+        record_event(&app_state, event).await;
+
         let mut response = reponse.into_response();
         response
             .headers_mut()
             .insert(header::SET_COOKIE, cookie.to_string().parse().unwrap());
         Ok(response)
     } else {
+        let event = NewAuditEvent {
+            actor_id: Some(user.id),
+            target_user_id: Some(user.id),
+            metadata: json!({ "reason": "wrong_password" }),
+            ..audit_event(AuditAction::LoginFailed, &client)
+        };
+        record_event(&app_state, event).await;
+
+        let threshold = app_state.config().account.lockout_threshold;
+        let after = app_state
+            .user_repository
+            .record_failed_login(user.id, threshold)
+            .await
+            .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+        if after.is_some_and(|after| {
+            user.status != AccountStatus::Locked && after.status == AccountStatus::Locked
+        }) {
+            let event = NewAuditEvent {
+                target_user_id: Some(user.id),
+                metadata: json!({ "threshold": threshold }),
+                ..audit_event(AuditAction::AccountLocked, &client)
+            };
+            record_event(&app_state, event).await;
+        }
+
         Err(HttpError::bad_request(
             ErrorMessage::WrongCredentials.to_string(),
         ))
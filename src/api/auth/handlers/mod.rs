@@ -1,3 +1,5 @@
+pub mod accept_invitation;
+pub mod cancel_deletion;
 pub mod forgot_password;
 pub mod login;
 pub mod register;
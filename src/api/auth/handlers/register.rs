@@ -1,15 +1,35 @@
-use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use axum::{
+    Extension, Json,
+    http::{HeaderMap, StatusCode, header::ACCEPT_LANGUAGE},
+    response::IntoResponse,
+};
 use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::Arc;
+use tracing::error;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     AppState,
+    config::registration::RegistrationMode,
+    core::{
+        audit::{audit_event, record_event},
+        invitation::{complete_invitation, find_usable_invitation},
+        job::enqueue_email,
+        redirect::resolve_return_to,
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        invitation::{Invitation, Invitee},
+        job::EmailJob,
+        user::{ApprovalStatus, User},
+        webhook::WebhookEvent,
+    },
     errors::{error_message::ErrorMessage, http_error::HttpError},
-    helpers::mail::mails::send_verification_email,
-    infrastructure::user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    infrastructure::middleware::client_info::ClientInfo,
     models::user::response::Response,
     utils::password,
 };
@@ -31,45 +51,113 @@ pub struct RegisterUser {
     )]
     #[serde(rename = "confirmPassword")]
     pub confirm_password: String,
+    #[serde(rename = "invitationToken")]
+    pub invitation_token: Option<String>,
+    /// Language for emails. Falls back to `Accept-Language` when omitted.
+    pub locale: Option<String>,
+    /// Where the verification link sends the user afterwards.
+    #[serde(rename = "returnTo")]
+    pub return_to: Option<String>,
 }
 
 pub async fn register(
     Extension(app_state): Extension<Arc<AppState>>,
+    client: ClientInfo,
+    headers: HeaderMap,
     Json(body): Json<RegisterUser>,
 ) -> Result<impl IntoResponse, HttpError> {
     body.validate()
         .map_err(|e| HttpError::bad_request(e.to_string()))?;
 
-    let verification_token = Uuid::new_v4().to_string();
-    let expires_at: chrono::DateTime<Utc> = Utc::now() + Duration::hours(24);
+    let locale = preferred_locale(&app_state, body.locale.as_deref(), &headers)?;
+    let return_to = resolve_return_to(&app_state, body.return_to.as_deref())?;
+
+    let invitation = check_registration_policy(&app_state, &body).await?;
 
     let hash_password = password::hash_password(&body.password)
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
-    let result = PgUserRepository::new(&app_state.db_client)
+    if let Some(invitation) = invitation {
+        return register_invited(
+            &app_state,
+            &client,
+            &body,
+            &invitation,
+            &hash_password,
+            locale.as_deref(),
+        )
+        .await;
+    }
+
+    let approval_status = app_state
+        .config()
+        .registration
+        .mode
+        .initial_approval_status();
+
+    let verification_token = Uuid::new_v4().to_string();
+    let expires_at: chrono::DateTime<Utc> = Utc::now() + Duration::hours(24);
+
+    let repository = &app_state.user_repository;
+    let result = repository
         .save_user(
             &body.name,
             &body.email,
             &hash_password,
             &verification_token,
             expires_at,
+            approval_status,
         )
         .await;
 
     match result {
-        Ok(_user) => {
-            let send_email_result =
-                send_verification_email(&body.email, &body.name, &verification_token).await;
-            if let Err(e) = send_email_result {
-                eprintln!("Failed to send verification email: {}", e);
+        Ok(mut user) => {
+            if locale.is_some() {
+                user = repository
+                    .update_user_locale(user.id, locale.as_deref())
+                    .await
+                    .map_err(|e| HttpError::server_error(e.to_string()))?;
             }
+
+            let event = NewAuditEvent {
+                actor_id: Some(user.id),
+                target_user_id: Some(user.id),
+                metadata: json!({ "approvalStatus": approval_status.to_str() }),
+                ..audit_event(AuditAction::Registered, &client)
+            };
+            record_event(&app_state, event).await;
+
+            dispatch_event(
+                &app_state,
+                WebhookEvent::UserRegistered,
+                user_event_data(&user),
+            )
+            .await;
+
+            enqueue_email(
+                &app_state,
+                EmailJob::Verification {
+                    to: body.email.clone(),
+                    locale: user.locale.clone(),
+                    username: body.name.clone(),
+                    token: verification_token,
+                    return_to,
+                },
+            )
+            .await;
+
+            let message = if approval_status == ApprovalStatus::Pending {
+                notify_admins_of_pending_user(&app_state, &user).await;
+                "registration successful! Please check your email to verify your account. An administrator will review your registration."
+            } else {
+                "registration successful! Please check your email to verify your account."
+            };
+
             Ok((
                 StatusCode::CREATED,
                 Json(Response {
                     status: "success",
-                    message:
-                        "registration successful! Please check your email to verify your account."
-                            .to_string(),
+                    message: message.to_string(),
                 }),
             ))
         }
@@ -85,3 +173,140 @@ pub async fn register(
         Err(e) => Err(HttpError::server_error(e.to_string())),
     }
 }
+
+/// An invitation already proves the address, so the account is created active
+/// and redeemed in the same transaction, with no verification email.
+async fn register_invited(
+    app_state: &AppState,
+    client: &ClientInfo,
+    body: &RegisterUser,
+    invitation: &Invitation,
+    hash_password: &str,
+    locale: Option<&str>,
+) -> Result<(StatusCode, Json<Response>), HttpError> {
+    let user = complete_invitation(
+        app_state,
+        invitation,
+        Invitee::New {
+            name: &body.name,
+            password: hash_password,
+            locale,
+        },
+    )
+    .await?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.id),
+        target_user_id: Some(user.id),
+        metadata: json!({
+            "approvalStatus": user.approval_status.to_str(),
+            "invitationId": invitation.id,
+        }),
+        ..audit_event(AuditAction::Registered, client)
+    };
+    record_event(app_state, event).await;
+
+    dispatch_event(
+        app_state,
+        WebhookEvent::UserRegistered,
+        user_event_data(&user),
+    )
+    .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(Response {
+            status: "success",
+            message: "registration successful! You can now log in.".to_string(),
+        }),
+    ))
+}
+
+/// Enforces the configured registration mode. In invite-only mode the
+/// invitation backing the registration is returned so it can be redeemed.
+async fn check_registration_policy(
+    app_state: &AppState,
+    body: &RegisterUser,
+) -> Result<Option<Invitation>, HttpError> {
+    match app_state.config().registration.mode {
+        RegistrationMode::InviteOnly => {
+            let token = body.invitation_token.as_deref().ok_or_else(|| {
+                HttpError::new(
+                    ErrorMessage::RegistrationInviteOnly.to_string(),
+                    StatusCode::FORBIDDEN,
+                )
+            })?;
+
+            let invitation = find_usable_invitation(app_state, token).await?;
+
+            if !invitation.email.eq_ignore_ascii_case(&body.email) {
+                return Err(HttpError::bad_request(
+                    ErrorMessage::InvitationEmailMismatch.to_string(),
+                ));
+            }
+
+            Ok(Some(invitation))
+        }
+        RegistrationMode::AllowedDomains => {
+            if !app_state
+                .config()
+                .registration
+                .is_domain_allowed(&body.email)
+            {
+                return Err(HttpError::new(
+                    ErrorMessage::EmailDomainNotAllowed.to_string(),
+                    StatusCode::FORBIDDEN,
+                ));
+            }
+            Ok(None)
+        }
+        RegistrationMode::Open | RegistrationMode::ApprovalRequired => Ok(None),
+    }
+}
+
+/// An explicit locale must be one we have templates for; a browser header is
+/// only a hint and is dropped when nothing in it matches.
+fn preferred_locale(
+    app_state: &AppState,
+    requested: Option<&str>,
+    headers: &HeaderMap,
+) -> Result<Option<String>, HttpError> {
+    let renderer = app_state.mail_renderer();
+
+    if let Some(requested) = requested {
+        return renderer
+            .supported_locale(requested)
+            .map(|locale| Some(locale.to_string()))
+            .ok_or_else(|| HttpError::bad_request(ErrorMessage::UnsupportedLocale.to_string()));
+    }
+
+    Ok(headers
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| renderer.negotiate_locale(value))
+        .map(str::to_string))
+}
+
+async fn notify_admins_of_pending_user(app_state: &AppState, user: &User) {
+    let admins = match app_state.user_repository.get_admins().await {
+        Ok(admins) => admins,
+        Err(e) => {
+            error!(error = %e, "Failed to load admins for approval notification");
+            return;
+        }
+    };
+
+    for admin in admins {
+        enqueue_email(
+            app_state,
+            EmailJob::PendingApproval {
+                to: admin.email,
+                locale: admin.locale,
+                admin_name: admin.name,
+                applicant_name: user.name.clone(),
+                applicant_email: user.email.clone(),
+            },
+        )
+        .await;
+    }
+}
@@ -8,8 +8,16 @@ use validator::Validate;
 
 use crate::{
     AppState,
+    core::{
+        audit::{audit_event, record_event},
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        webhook::WebhookEvent,
+    },
     errors::http_error::HttpError,
-    infrastructure::user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    infrastructure::middleware::client_info::ClientInfo,
     models::user::response::Response,
     utils::password,
 };
@@ -34,13 +42,15 @@ pub struct ResetPasswordRequest {
 
 pub async fn reset_password(
     Extension(app_state): Extension<Arc<AppState>>,
+    client: ClientInfo,
     Json(body): Json<ResetPasswordRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     body.validate()
         .map_err(|e| HttpError::bad_request(e.to_string()))?;
 
-    let user_controller = PgUserRepository::new(&app_state.db_client);
-    let result = PgUserRepository::new(&app_state.db_client)
+    let user_controller = &app_state.user_repository;
+    let result = app_state
+        .user_repository
         .get_user(None, None, None, Some(&body.token))
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
@@ -66,7 +76,7 @@ pub async fn reset_password(
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
     user_controller
-        .update_user_password(user_id.clone(), hash_password)
+        .update_user_password(user_id, hash_password)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
@@ -75,6 +85,20 @@ pub async fn reset_password(
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
+    let event = NewAuditEvent {
+        actor_id: Some(user_id),
+        target_user_id: Some(user_id),
+        ..audit_event(AuditAction::PasswordReset, &client)
+    };
+    record_event(&app_state, event).await;
+
+    dispatch_event(
+        &app_state,
+        WebhookEvent::UserPasswordChanged,
+        user_event_data(&user),
+    )
+    .await;
+
     let response = Response {
         status: "success",
         message: "Password has been successfully reset.".to_string(),
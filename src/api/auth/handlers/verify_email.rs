@@ -10,10 +10,20 @@ use validator::Validate;
 
 use crate::{
     AppState,
-    domains::user::User,
+    core::{
+        audit::{audit_event, record_event},
+        job::enqueue_email,
+        redirect::resolve_return_to,
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        job::EmailJob,
+        user::{AccountStatus, User},
+        webhook::WebhookEvent,
+    },
     errors::{error_message::ErrorMessage, http_error::HttpError},
-    helpers::mail::mails::send_welcome_email,
-    infrastructure::user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    infrastructure::middleware::client_info::ClientInfo,
     utils::token,
 };
 
@@ -22,31 +32,64 @@ use serde::{Deserialize, Serialize};
 pub struct VerifyEmailQuery {
     #[validate(length(min = 1, message = "Token is required"))]
     pub token: String,
+    pub return_to: Option<String>,
 }
 
 pub async fn verify_email(
     Extension(app_state): Extension<Arc<AppState>>,
+    client: ClientInfo,
     Query(query_params): Query<VerifyEmailQuery>,
 ) -> Result<impl IntoResponse, HttpError> {
     query_params
         .validate()
         .map_err(|e| HttpError::bad_request(e.to_string()))?;
 
+    let redirect_to = resolve_return_to(&app_state, query_params.return_to.as_deref())?
+        .unwrap_or_else(|| app_state.config().urls.email_verified_url());
+
     let user = get_user_by_token(&app_state, &query_params.token).await?;
 
-    if !user.verified {
+    if user.status == AccountStatus::PendingVerification {
         verify_user_token(&app_state, &query_params.token).await?;
-        try_send_welcome_email(&user.email, &user.name).await;
+
+        let event = NewAuditEvent {
+            actor_id: Some(user.id),
+            target_user_id: Some(user.id),
+            ..audit_event(AuditAction::EmailVerified, &client)
+        };
+        record_event(&app_state, event).await;
+
+        let verified = User {
+            status: AccountStatus::Active,
+            ..user.clone()
+        };
+        dispatch_event(
+            &app_state,
+            WebhookEvent::UserVerified,
+            user_event_data(&verified),
+        )
+        .await;
+
+        enqueue_email(
+            &app_state,
+            EmailJob::Welcome {
+                to: user.email.clone(),
+                locale: user.locale.clone(),
+                username: user.name.clone(),
+            },
+        )
+        .await;
     }
 
     let jwt_cookie = generate_jwt_cookie(&app_state, &user.id.to_string())?;
-    let response = redirect_with_cookie("http://localhost:5173/settings", jwt_cookie);
+    let response = redirect_with_cookie(&redirect_to, jwt_cookie);
 
     Ok(response)
 }
 
 async fn get_user_by_token(app_state: &Arc<AppState>, token: &str) -> Result<User, HttpError> {
-    PgUserRepository::new(&app_state.db_client)
+    app_state
+        .user_repository
         .get_user(None, None, None, Some(token))
         .await
         .map_err(server_error)?
@@ -54,32 +97,27 @@ async fn get_user_by_token(app_state: &Arc<AppState>, token: &str) -> Result<Use
 }
 
 async fn verify_user_token(app_state: &Arc<AppState>, token: &str) -> Result<(), HttpError> {
-    PgUserRepository::new(&app_state.db_client)
+    app_state
+        .user_repository
         .verified_token(token)
         .await
         .map_err(server_error)
 }
 
-async fn try_send_welcome_email(email: &str, name: &str) {
-    if let Err(e) = send_welcome_email(email, name).await {
-        eprintln!("Failed to send welcome email: {}", e);
-    }
-}
-
 fn generate_jwt_cookie(
     app_state: &Arc<AppState>,
     user_id: &str,
 ) -> Result<Cookie<'static>, HttpError> {
     let jwt = token::generate_token(
         user_id,
-        app_state.env.jwt_secret.as_bytes(),
-        app_state.env.jwt_maxage,
+        app_state.config().jwt.secret.as_bytes(),
+        app_state.config().jwt.maxage,
     )
     .map_err(server_error)?;
 
     Ok(Cookie::build(("token", jwt))
         .path("/")
-        .max_age(time::Duration::minutes(app_state.env.jwt_maxage * 60))
+        .max_age(time::Duration::minutes(app_state.config().jwt.maxage * 60))
         .http_only(true)
         .build())
 }
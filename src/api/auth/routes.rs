@@ -4,6 +4,7 @@ use axum::{
 };
 
 use super::handlers::{
+    accept_invitation::accept_invitation, cancel_deletion::cancel_deletion,
     forgot_password::forgot_password, login::login, register::register,
     reset_password::reset_password, verify_email::verify_email,
 };
@@ -15,4 +16,6 @@ pub fn auth_handler() -> Router {
         .route("/forgot-password", post(forgot_password))
         .route("/reset-password", post(reset_password))
         .route("/verify-email", get(verify_email))
+        .route("/accept-invitation", post(accept_invitation))
+        .route("/cancel-deletion", post(cancel_deletion))
 }
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+
+use crate::{AppState, errors::http_error::HttpError, models::user::response::Response};
+
+pub async fn clear_mailbox(
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let mailbox = app_state
+        .mailer
+        .as_mailbox()
+        .ok_or_else(|| HttpError::bad_request("The mailbox transport is not enabled"))?;
+
+    let cleared = mailbox.clear();
+
+    Ok(Json(Response {
+        status: "success",
+        message: format!("Removed {} messages from the mailbox.", cleared),
+    }))
+}
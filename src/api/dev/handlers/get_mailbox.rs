@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    models::mailbox::response::{FilterMailboxMessage, MailboxListResponse},
+};
+
+pub async fn get_mailbox(
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let mailbox = app_state
+        .mailer
+        .as_mailbox()
+        .ok_or_else(|| HttpError::bad_request("The mailbox transport is not enabled"))?;
+
+    let messages = mailbox.messages();
+
+    Ok(Json(MailboxListResponse {
+        status: "success".to_string(),
+        results: messages.len(),
+        messages: FilterMailboxMessage::filter_mailbox_messages(&messages),
+    }))
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, response::IntoResponse};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::mail::mail_trait::build_message,
+    models::mailbox::response::{
+        FilterMailboxMessage, MailHeader, MailboxMessageDetail, MailboxMessageResponse,
+    },
+};
+
+pub async fn get_mailbox_message(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Path(message_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let mailbox = app_state
+        .mailer
+        .as_mailbox()
+        .ok_or_else(|| HttpError::bad_request("The mailbox transport is not enabled"))?;
+
+    let mail = mailbox
+        .message(message_id)
+        .ok_or_else(|| HttpError::bad_request("Message not found"))?;
+
+    let message = build_message(&mail).map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    Ok(Json(MailboxMessageResponse {
+        status: "success".to_string(),
+        message: MailboxMessageDetail {
+            summary: FilterMailboxMessage::filter_mailbox_message(&mail),
+            headers: parse_headers(&message.headers().to_string()),
+            html_body: mail.html_body,
+            text_body: mail.text_body,
+        },
+    }))
+}
+
+/// Splits raw header lines, joining folded continuation lines back up.
+fn parse_headers(raw: &str) -> Vec<MailHeader> {
+    let mut headers: Vec<MailHeader> = Vec::new();
+
+    for line in raw.lines().filter(|line| !line.is_empty()) {
+        if line.starts_with([' ', '\t']) {
+            if let Some(last) = headers.last_mut() {
+                last.value.push_str(line);
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            headers.push(MailHeader {
+                name: name.to_string(),
+                value: value.trim_start().to_string(),
+            });
+        }
+    }
+
+    headers
+}
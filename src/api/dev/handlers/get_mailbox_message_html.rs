@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension,
+    extract::Path,
+    response::{Html, IntoResponse},
+};
+use uuid::Uuid;
+
+use crate::{AppState, errors::http_error::HttpError};
+
+/// Serves the HTML part on its own so it can be opened in a browser.
+pub async fn get_mailbox_message_html(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Path(message_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let mailbox = app_state
+        .mailer
+        .as_mailbox()
+        .ok_or_else(|| HttpError::bad_request("The mailbox transport is not enabled"))?;
+
+    let mail = mailbox
+        .message(message_id)
+        .ok_or_else(|| HttpError::bad_request("Message not found"))?;
+
+    Ok(Html(mail.html_body))
+}
@@ -0,0 +1,4 @@
+pub mod clear_mailbox;
+pub mod get_mailbox;
+pub mod get_mailbox_message;
+pub mod get_mailbox_message_html;
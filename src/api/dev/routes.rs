@@ -0,0 +1,13 @@
+use axum::{Router, routing::get};
+
+use super::handlers::{
+    clear_mailbox::clear_mailbox, get_mailbox::get_mailbox,
+    get_mailbox_message::get_mailbox_message, get_mailbox_message_html::get_mailbox_message_html,
+};
+
+pub fn dev_handler() -> Router {
+    Router::new()
+        .route("/mailbox", get(get_mailbox).delete(clear_mailbox))
+        .route("/mailbox/{message_id}", get(get_mailbox_message))
+        .route("/mailbox/{message_id}/html", get(get_mailbox_message_html))
+}
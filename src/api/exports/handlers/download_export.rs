@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Query, http::header, response::IntoResponse};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::data_export::DATA_EXPORT_PURPOSE,
+    domains::data_export::DataExportStatus,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::data_export::{
+        data_export_impl::PgDataExportRepository, data_export_trait::DataExportRepository,
+    },
+    utils::token,
+};
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct DownloadExportQuery {
+    #[validate(length(min = 1, message = "Token is required"))]
+    pub token: String,
+}
+
+pub async fn download_export(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Query(query_params): Query<DownloadExportQuery>,
+) -> Result<impl IntoResponse, HttpError> {
+    query_params
+        .validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let claims = token::decode_signed_link_token(
+        &query_params.token,
+        DATA_EXPORT_PURPOSE,
+        app_state.config().jwt.secret.as_bytes(),
+    )?;
+
+    let export_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| HttpError::bad_request(ErrorMessage::InvalidToken.to_string()))?;
+
+    let export = PgDataExportRepository::new(&app_state.db_client)
+        .get_export(export_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::InvalidToken.to_string()))?;
+
+    let usable = export.status == DataExportStatus::Ready
+        && export.user_id.to_string() == claims.nonce
+        && export
+            .expires_at
+            .is_some_and(|expires_at| expires_at > Utc::now());
+
+    let payload = match export.payload {
+        Some(payload) if usable => payload.0,
+        _ => {
+            return Err(HttpError::bad_request(
+                ErrorMessage::InvalidToken.to_string(),
+            ));
+        }
+    };
+
+    let disposition = format!("attachment; filename=\"user-export-{}.json\"", export.id);
+
+    Ok(([(header::CONTENT_DISPOSITION, disposition)], Json(payload)))
+}
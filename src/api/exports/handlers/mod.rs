@@ -0,0 +1 @@
+pub mod download_export;
@@ -0,0 +1,7 @@
+use axum::{Router, routing::get};
+
+use super::handlers::download_export::download_export;
+
+pub fn exports_handler() -> Router {
+    Router::new().route("/download", get(download_export))
+}
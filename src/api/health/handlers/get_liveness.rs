@@ -0,0 +1,11 @@
+use axum::{Json, response::IntoResponse};
+
+use crate::models::health::response::LivenessResponse;
+
+/// Answers as long as the process can serve requests at all; it checks no
+/// dependencies so a database outage does not get the process restarted.
+pub async fn get_liveness() -> impl IntoResponse {
+    Json(LivenessResponse {
+        status: "alive".to_string(),
+    })
+}
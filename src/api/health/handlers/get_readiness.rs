@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+
+use crate::{
+    AppState,
+    core::health::check_readiness,
+    models::health::response::{CheckStatus, ReadinessResponse},
+};
+
+pub async fn get_readiness(Extension(app_state): Extension<Arc<AppState>>) -> impl IntoResponse {
+    let checks = check_readiness(&app_state).await;
+    let ready = checks.iter().all(|check| check.status != CheckStatus::Fail);
+
+    let (status_code, status) = if ready {
+        (StatusCode::OK, "ready")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "not_ready")
+    };
+
+    (
+        status_code,
+        Json(ReadinessResponse {
+            status: status.to_string(),
+            checks,
+        }),
+    )
+}
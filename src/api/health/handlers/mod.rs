@@ -0,0 +1,2 @@
+pub mod get_liveness;
+pub mod get_readiness;
@@ -0,0 +1,9 @@
+use axum::{Router, routing::get};
+
+use super::handlers::{get_liveness::get_liveness, get_readiness::get_readiness};
+
+pub fn health_handler() -> Router {
+    Router::new()
+        .route("/live", get(get_liveness))
+        .route("/ready", get(get_readiness))
+}
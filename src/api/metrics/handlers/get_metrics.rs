@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use axum::{Extension, http::header, response::IntoResponse};
+
+use crate::{AppState, core::metrics::render_metrics, errors::http_error::HttpError};
+
+pub async fn get_metrics(
+    Extension(app_state): Extension<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let metrics = render_metrics(&app_state)
+        .ok_or_else(|| HttpError::server_error("Metrics are not enabled"))?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics,
+    ))
+}
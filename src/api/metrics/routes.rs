@@ -0,0 +1,7 @@
+use axum::{Router, routing::get};
+
+use super::handlers::get_metrics::get_metrics;
+
+pub fn metrics_handler() -> Router {
+    Router::new().route("/metrics", get(get_metrics))
+}
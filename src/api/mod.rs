@@ -1,3 +1,10 @@
+pub mod admin;
 pub mod auth;
+#[cfg(debug_assertions)]
+pub mod dev;
+pub mod exports;
+pub mod health;
+pub mod metrics;
+pub mod organizations;
 pub mod router;
 pub mod users;
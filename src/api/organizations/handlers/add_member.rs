@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        middleware::organization::OrganizationContext,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::{organization::request::AddMember, user::response::Response},
+};
+
+pub async fn add_member(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(organization): Extension<OrganizationContext>,
+    Json(body): Json<AddMember>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    if !organization.role.can_assign(body.role) {
+        return Err(HttpError::new(
+            ErrorMessage::PermissionDenied.to_string(),
+            StatusCode::FORBIDDEN,
+        ));
+    }
+
+    let user = app_state
+        .user_repository
+        .get_user(None, None, Some(&body.email), None)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request("User not found!".to_string()))?;
+
+    let result = PgOrganizationRepository::new(&app_state.db_client)
+        .add_member(organization.organization_id, user.id, body.role)
+        .await;
+
+    match result {
+        Ok(_membership) => Ok((
+            StatusCode::CREATED,
+            Json(Response {
+                status: "success",
+                message: "Member added to the organization.".to_string(),
+            }),
+        )),
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => Err(
+            HttpError::unique_constraint_violation(ErrorMessage::MemberAlreadyExists.to_string()),
+        ),
+        Err(e) => Err(HttpError::server_error(e.to_string())),
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::{
+        middleware::auth::JWTAuthMiddleware,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::organization::{
+        request::CreateOrganization,
+        response::{FilterOrganization, OrganizationResponse},
+    },
+};
+
+pub async fn create_organization(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    Json(body): Json<CreateOrganization>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let organization = PgOrganizationRepository::new(&app_state.db_client)
+        .create_organization(&body.name, user.user.id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let response = OrganizationResponse {
+        status: "success".to_string(),
+        organization: FilterOrganization::from_created(&organization),
+    };
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
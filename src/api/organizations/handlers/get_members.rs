@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::{
+        middleware::organization::OrganizationContext,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::organization::response::{FilterMember, MemberListResponse},
+};
+
+pub async fn get_members(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(organization): Extension<OrganizationContext>,
+) -> Result<impl IntoResponse, HttpError> {
+    let members = PgOrganizationRepository::new(&app_state.db_client)
+        .get_members(organization.organization_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let response = MemberListResponse {
+        status: "success".to_string(),
+        results: members.len(),
+        members: FilterMember::filter_members(&members),
+    };
+
+    Ok(Json(response))
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+
+use crate::{
+    AppState,
+    errors::http_error::HttpError,
+    infrastructure::{
+        middleware::auth::JWTAuthMiddleware,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::organization::response::{FilterOrganization, OrganizationListResponse},
+};
+
+pub async fn get_organizations(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+) -> Result<impl IntoResponse, HttpError> {
+    let organizations = PgOrganizationRepository::new(&app_state.db_client)
+        .get_user_organizations(user.user.id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let response = OrganizationListResponse {
+        status: "success".to_string(),
+        results: organizations.len(),
+        organizations: organizations
+            .iter()
+            .map(FilterOrganization::from_user_organization)
+            .collect(),
+    };
+
+    Ok(Json(response))
+}
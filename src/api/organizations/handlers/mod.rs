@@ -0,0 +1,7 @@
+pub mod add_member;
+pub mod create_organization;
+pub mod get_members;
+pub mod get_organizations;
+pub mod remove_member;
+pub mod switch_organization;
+pub mod update_member_role;
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, http::StatusCode, response::IntoResponse};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    domains::organization::MembershipChange,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        middleware::organization::OrganizationContext,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::user::response::Response,
+};
+
+pub async fn remove_member(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(organization): Extension<OrganizationContext>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let repository = PgOrganizationRepository::new(&app_state.db_client);
+
+    let change = repository
+        .remove_member(organization.organization_id, user_id, organization.role)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    match change {
+        MembershipChange::Applied(_) => {}
+        MembershipChange::NotFound => {
+            return Err(HttpError::bad_request(
+                ErrorMessage::MemberNotFound.to_string(),
+            ));
+        }
+        MembershipChange::Forbidden => {
+            return Err(HttpError::new(
+                ErrorMessage::PermissionDenied.to_string(),
+                StatusCode::FORBIDDEN,
+            ));
+        }
+        MembershipChange::LastOwner => {
+            return Err(HttpError::bad_request(
+                ErrorMessage::LastOrganizationOwner.to_string(),
+            ));
+        }
+    }
+
+    Ok(Json(Response {
+        status: "success",
+        message: "Member removed from the organization.".to_string(),
+    }))
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, http::header, response::IntoResponse};
+use cookie::Cookie;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        middleware::auth::JWTAuthMiddleware,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::{organization::request::SwitchOrganization, user::response::UserLoginResponse},
+    utils::token,
+};
+
+pub async fn switch_organization(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    Json(body): Json<SwitchOrganization>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    PgOrganizationRepository::new(&app_state.db_client)
+        .get_membership(body.organization_id, user.user.id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| {
+            HttpError::new(
+                ErrorMessage::NotOrganizationMember.to_string(),
+                StatusCode::FORBIDDEN,
+            )
+        })?;
+
+    let token = token::generate_token_with_organization(
+        &user.user.id.to_string(),
+        Some(&body.organization_id.to_string()),
+        app_state.config().jwt.secret.as_bytes(),
+        app_state.config().jwt.maxage,
+    )
+    .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let cookie_duration = time::Duration::minutes(app_state.config().jwt.maxage * 60);
+
+    let cookie = Cookie::build(("token", token.clone()))
+        .path("/")
+        .max_age(cookie_duration)
+        .http_only(true)
+        .build();
+
+    let mut response = Json(UserLoginResponse {
+        status: "success".to_string(),
+        token,
+    })
+    .into_response();
+    response
+        .headers_mut()
+        .insert(header::SET_COOKIE, cookie.to_string().parse().unwrap());
+
+    Ok(response)
+}
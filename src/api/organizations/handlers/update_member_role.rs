@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, extract::Path, http::StatusCode, response::IntoResponse};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    domains::organization::MembershipChange,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        middleware::organization::OrganizationContext,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::{organization::request::MemberRoleUpdate, user::response::Response},
+};
+
+pub async fn update_member_role(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(organization): Extension<OrganizationContext>,
+    Path(user_id): Path<Uuid>,
+    Json(body): Json<MemberRoleUpdate>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let repository = PgOrganizationRepository::new(&app_state.db_client);
+
+    let change = repository
+        .update_member_role(
+            organization.organization_id,
+            user_id,
+            body.role,
+            organization.role,
+        )
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    match change {
+        MembershipChange::Applied(_) => {}
+        MembershipChange::NotFound => {
+            return Err(HttpError::bad_request(
+                ErrorMessage::MemberNotFound.to_string(),
+            ));
+        }
+        MembershipChange::Forbidden => {
+            return Err(HttpError::new(
+                ErrorMessage::PermissionDenied.to_string(),
+                StatusCode::FORBIDDEN,
+            ));
+        }
+        MembershipChange::LastOwner => {
+            return Err(HttpError::bad_request(
+                ErrorMessage::LastOrganizationOwner.to_string(),
+            ));
+        }
+    }
+
+    Ok(Json(Response {
+        status: "success",
+        message: "Member role updated.".to_string(),
+    }))
+}
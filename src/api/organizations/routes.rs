@@ -0,0 +1,48 @@
+use axum::{
+    Router, middleware,
+    routing::{get, post, put},
+};
+
+use crate::{
+    domains::organization::OrganizationRole,
+    infrastructure::middleware::organization::{organization_context, organization_role_check},
+};
+
+use super::handlers::{
+    add_member::add_member, create_organization::create_organization, get_members::get_members,
+    get_organizations::get_organizations, remove_member::remove_member,
+    switch_organization::switch_organization, update_member_role::update_member_role,
+};
+
+pub fn organizations_handler() -> Router {
+    let members_route = Router::new()
+        .route(
+            "/",
+            post(add_member).layer(middleware::from_fn(|req, next| {
+                organization_role_check(
+                    req,
+                    next,
+                    vec![OrganizationRole::Owner, OrganizationRole::Admin],
+                )
+            })),
+        )
+        .route(
+            "/{user_id}",
+            put(update_member_role)
+                .delete(remove_member)
+                .layer(middleware::from_fn(|req, next| {
+                    organization_role_check(
+                        req,
+                        next,
+                        vec![OrganizationRole::Owner, OrganizationRole::Admin],
+                    )
+                })),
+        )
+        .route("/", get(get_members))
+        .layer(middleware::from_fn(organization_context));
+
+    Router::new()
+        .route("/", post(create_organization).get(get_organizations))
+        .route("/switch", post(switch_organization))
+        .nest("/members", members_route)
+}
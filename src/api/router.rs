@@ -1,18 +1,58 @@
 use std::sync::Arc;
 
 use axum::{Extension, Router, middleware};
-use tower_http::trace::TraceLayer;
 
-use crate::{AppState, infrastructure::middleware::auth::auth};
+use crate::{
+    AppState,
+    domains::user::UserRole,
+    infrastructure::middleware::auth::{auth, role_check},
+};
 
-use super::{auth::routes::auth_handler, users::routes::users_handler};
+use super::{
+    admin::routes::admin_handler, auth::routes::auth_handler, exports::routes::exports_handler,
+    health::routes::health_handler, metrics::routes::metrics_handler,
+    organizations::routes::organizations_handler, users::routes::users_handler,
+};
 
 pub fn create_router(app_state: Arc<AppState>) -> Router {
     let api_route = Router::new()
         .nest("/auth", auth_handler())
+        .nest("/exports", exports_handler())
         .nest("/users", users_handler().layer(middleware::from_fn(auth)))
-        .layer(TraceLayer::new_for_http())
-        .layer(Extension(app_state));
+        .nest(
+            "/organizations",
+            organizations_handler().layer(middleware::from_fn(auth)),
+        )
+        .nest(
+            "/admin",
+            admin_handler()
+                .layer(middleware::from_fn(|state, req, next| {
+                    role_check(state, req, next, vec![UserRole::Admin])
+                }))
+                .layer(middleware::from_fn(auth)),
+        );
+
+    // Only mounted when the mailbox transport is in use, which release
+    // builds cannot select.
+    #[cfg(debug_assertions)]
+    let api_route = if app_state.mailer.as_mailbox().is_some() {
+        api_route.nest("/dev", super::dev::routes::dev_handler())
+    } else {
+        api_route
+    };
+
+    let api_route = api_route.layer(Extension(app_state));
 
     Router::new().nest("/api", api_route)
 }
+
+/// Probes for the orchestrator. Kept apart from [`create_router`] so they sit
+/// outside the request logging and need no authentication.
+pub fn create_health_router() -> Router {
+    Router::new().nest("/health", health_handler())
+}
+
+/// `/metrics` for Prometheus, on the API port or on its own listener.
+pub fn create_metrics_router() -> Router {
+    metrics_handler()
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use chrono::Utc;
+use serde_json::json;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    core::{
+        account_deletion::{cancel_deletion_token, deletion_due_at, is_recent_auth},
+        account_status::invalid_transition,
+        audit::{audit_event, record_event},
+        job::enqueue_email,
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        job::EmailJob,
+    },
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    models::user::{request::DeleteAccount, response::Response},
+    utils::password,
+};
+
+pub async fn delete_me(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+    body: Option<Json<DeleteAccount>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let body = body.map(|Json(body)| body).unwrap_or_default();
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    match &body.password {
+        Some(current_password) => {
+            let password_matched = password::compare(current_password, &user.user.password)
+                .map_err(|_| HttpError::bad_request(ErrorMessage::WrongCredentials.to_string()))?;
+            if !password_matched {
+                return Err(HttpError::bad_request(
+                    ErrorMessage::WrongCredentials.to_string(),
+                ));
+            }
+        }
+        None => {
+            if !is_recent_auth(user.authenticated_at, Utc::now()) {
+                return Err(HttpError::unauthorized(
+                    ErrorMessage::ReauthenticationRequired.to_string(),
+                ));
+            }
+        }
+    }
+
+    let deletion_scheduled_at =
+        deletion_due_at(Utc::now(), app_state.config().account.deletion_grace_days);
+
+    let user = app_state
+        .user_repository
+        .schedule_deletion(user.user.id, deletion_scheduled_at)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(invalid_transition)?;
+
+    let event = NewAuditEvent {
+        actor_id: Some(user.id),
+        target_user_id: Some(user.id),
+        metadata: json!({ "deletionScheduledAt": deletion_scheduled_at }),
+        ..audit_event(AuditAction::DeletionRequested, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let cancel_token = cancel_deletion_token(
+        &user,
+        deletion_scheduled_at,
+        app_state.config().jwt.secret.as_bytes(),
+    )?;
+
+    let cancel_link = app_state.config().urls.cancel_deletion_link(&cancel_token);
+
+    enqueue_email(
+        &app_state,
+        EmailJob::AccountDeletion {
+            to: user.email.clone(),
+            locale: user.locale.clone(),
+            username: user.name.clone(),
+            cancel_link,
+            deletion_date: deletion_scheduled_at.format("%Y-%m-%d").to_string(),
+        },
+    )
+    .await;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(Response {
+            status: "success",
+            message: format!(
+                "Your account will be deleted in {} days. Check your email to cancel.",
+                app_state.config().account.deletion_grace_days
+            ),
+        }),
+    ))
+}
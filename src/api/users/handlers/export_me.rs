@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+
+use crate::{
+    AppState,
+    core::{
+        audit::{audit_event, record_event},
+        data_export::spawn_export,
+    },
+    domains::audit::{AuditAction, NewAuditEvent},
+    errors::http_error::HttpError,
+    infrastructure::{
+        data_export::{
+            data_export_impl::PgDataExportRepository, data_export_trait::DataExportRepository,
+        },
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+    },
+    models::user::response::Response,
+};
+
+pub async fn export_me(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
+) -> Result<impl IntoResponse, HttpError> {
+    let repository = PgDataExportRepository::new(&app_state.db_client);
+
+    let pending = repository
+        .get_pending_export(user.user.id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    if pending.is_none() {
+        let export = repository
+            .save_export(user.user.id)
+            .await
+            .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+        let event = NewAuditEvent {
+            actor_id: Some(user.user.id),
+            target_user_id: Some(user.user.id),
+            ..audit_event(AuditAction::DataExportRequested, &client)
+        };
+        record_event(&app_state, event).await;
+
+        spawn_export(app_state.clone(), export, user.user.clone());
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(Response {
+            status: "success",
+            message:
+                "Your data export is being prepared. A download link will be sent to your email."
+                    .to_string(),
+        }),
+    ))
+}
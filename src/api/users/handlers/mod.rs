@@ -1,5 +1,8 @@
+pub mod delete_me;
+pub mod export_me;
 pub mod get_me;
 pub mod get_users;
+pub mod update_user_locale;
 pub mod update_user_password;
 pub mod update_user_role;
 pub mod update_username;
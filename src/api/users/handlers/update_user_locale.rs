@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json, response::IntoResponse};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::middleware::auth::JWTAuthMiddleware,
+    models::user::{
+        response::{FilterUser, UserData, UserResponse},
+        update::LocaleUpdate,
+    },
+};
+
+pub async fn update_user_locale(
+    Extension(app_state): Extension<Arc<AppState>>,
+    Extension(user): Extension<JWTAuthMiddleware>,
+    Json(body): Json<LocaleUpdate>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.validate()
+        .map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+    let renderer = app_state.mail_renderer();
+    let locale =
+        match body.locale.as_deref() {
+            Some(requested) => Some(renderer.supported_locale(requested).ok_or_else(|| {
+                HttpError::bad_request(ErrorMessage::UnsupportedLocale.to_string())
+            })?),
+            None => None,
+        };
+
+    let result = app_state
+        .user_repository
+        .update_user_locale(user.user.id, locale)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let response = UserResponse {
+        data: UserData {
+            user: FilterUser::filter_user(&result),
+        },
+        status: "success".to_string(),
+    };
+
+    Ok(Json(response))
+}
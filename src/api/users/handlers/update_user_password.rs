@@ -5,11 +5,16 @@ use validator::Validate;
 
 use crate::{
     AppState,
-    errors::{error_message::ErrorMessage, http_error::HttpError},
-    infrastructure::{
-        middleware::auth::JWTAuthMiddleware,
-        user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    core::{
+        audit::{audit_event, record_event},
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        webhook::WebhookEvent,
     },
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
     models::user::{response::Response, update::UserPasswordUpdate},
     utils::password,
 };
@@ -17,6 +22,7 @@ use crate::{
 pub async fn update_user_password(
     Extension(app_state): Extension<Arc<AppState>>,
     Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
     Json(body): Json<UserPasswordUpdate>,
 ) -> Result<impl IntoResponse, HttpError> {
     body.validate()
@@ -26,8 +32,9 @@ pub async fn update_user_password(
 
     let user_id = uuid::Uuid::parse_str(&user.id.to_string()).unwrap();
 
-    let result = PgUserRepository::new(&app_state.db_client)
-        .get_user(Some(user_id.clone()), None, None, None)
+    let result = app_state
+        .user_repository
+        .get_user(Some(user_id), None, None, None)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
@@ -47,11 +54,26 @@ pub async fn update_user_password(
     let hash_password = password::hash_password(&body.new_password)
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
-    PgUserRepository::new(&app_state.db_client)
-        .update_user_password(user_id.clone(), hash_password)
+    app_state
+        .user_repository
+        .update_user_password(user_id, hash_password)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
+    let event = NewAuditEvent {
+        actor_id: Some(user_id),
+        target_user_id: Some(user_id),
+        ..audit_event(AuditAction::PasswordChanged, &client)
+    };
+    record_event(&app_state, event).await;
+
+    dispatch_event(
+        &app_state,
+        WebhookEvent::UserPasswordChanged,
+        user_event_data(&user),
+    )
+    .await;
+
     let response = Response {
         message: "Password updated Successfully".to_string(),
         status: "success",
@@ -1,15 +1,21 @@
 use std::sync::Arc;
 
 use axum::{Extension, Json, response::IntoResponse};
+use serde_json::json;
 use validator::Validate;
 
 use crate::{
     AppState,
-    errors::http_error::HttpError,
-    infrastructure::{
-        middleware::auth::JWTAuthMiddleware,
-        user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    core::{
+        audit::{audit_event, record_event},
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        webhook::WebhookEvent,
     },
+    errors::http_error::HttpError,
+    infrastructure::middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
     models::user::{
         response::{FilterUser, UserData, UserResponse},
         update::RoleUpdate,
@@ -18,6 +24,7 @@ use crate::{
 pub async fn update_user_role(
     Extension(app_state): Extension<Arc<AppState>>,
     Extension(user): Extension<JWTAuthMiddleware>,
+    client: ClientInfo,
     Json(body): Json<RoleUpdate>,
 ) -> Result<impl IntoResponse, HttpError> {
     body.validate()
@@ -27,11 +34,24 @@ pub async fn update_user_role(
 
     let user_id = uuid::Uuid::parse_str(&user.id.to_string()).unwrap();
 
-    let result = PgUserRepository::new(&app_state.db_client)
-        .update_user_role(user_id.clone(), body.role)
+    let result = app_state
+        .user_repository
+        .update_user_role(user_id, body.role)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
+    let event = NewAuditEvent {
+        actor_id: Some(user_id),
+        target_user_id: Some(user_id),
+        metadata: json!({ "from": user.role.to_str(), "to": result.role.to_str() }),
+        ..audit_event(AuditAction::RoleChanged, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let mut data = user_event_data(&result);
+    data["previousRole"] = json!(user.role.to_str());
+    dispatch_event(&app_state, WebhookEvent::UserRoleChanged, data).await;
+
     let filtered_user = FilterUser::filter_user(&result);
 
     let response = UserResponse {
@@ -6,10 +6,7 @@ use validator::Validate;
 use crate::{
     AppState,
     errors::http_error::HttpError,
-    infrastructure::{
-        middleware::auth::JWTAuthMiddleware,
-        user::{user_trait::UserRepository, users_impl::PgUserRepository},
-    },
+    infrastructure::middleware::auth::JWTAuthMiddleware,
     models::user::{
         response::{FilterUser, UserData, UserResponse},
         update::NameUpdate,
@@ -27,8 +24,9 @@ pub async fn update_username(
 
     let user_id = uuid::Uuid::parse_str(&user.id.to_string()).unwrap();
 
-    let result = PgUserRepository::new(&app_state.db_client)
-        .update_username(user_id.clone(), &body.name)
+    let result = app_state
+        .user_repository
+        .update_username(user_id, &body.name)
         .await
         .map_err(|e| HttpError::server_error(e.to_string()))?;
 
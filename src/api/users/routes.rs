@@ -6,13 +6,15 @@ use axum::{
 use crate::{domains::user::UserRole, infrastructure::middleware::auth::role_check};
 
 use super::handlers::{
-    get_me::get_me, get_users::get_users, update_user_password::update_user_password,
+    delete_me::delete_me, export_me::export_me, get_me::get_me, get_users::get_users,
+    update_user_locale::update_user_locale, update_user_password::update_user_password,
     update_user_role::update_user_role, update_username::update_username,
 };
 
 pub fn users_handler() -> Router {
     Router::new()
-        .route("/me", get(get_me))
+        .route("/me", get(get_me).delete(delete_me))
+        .route("/me/export", get(export_me))
         .route(
             "/users",
             get(get_users).layer(middleware::from_fn(|state, req, next| {
@@ -21,5 +23,6 @@ pub fn users_handler() -> Router {
         )
         .route("/name", put(update_username))
         .route("/role", put(update_user_role))
+        .route("/locale", put(update_user_locale))
         .route("/password", put(update_user_password))
 }
@@ -0,0 +1,4 @@
+#[tokio::main]
+async fn main() {
+    std::process::exit(axum_auth_backend::cli::run().await);
+}
@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::domains::user::{AccountStatus, ApprovalStatus, UserRole};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "auth-admin",
+    about = "Operations tasks against the auth database, with the server's configuration"
+)]
+pub struct Cli {
+    /// TOML file to read instead of config.toml, as with the server.
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Human)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Human,
+    Json,
+}
+
+/// Users are given by id or by email address.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Create a verified administrator account.
+    CreateAdmin {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        email: String,
+        /// Read the password from the first line of stdin. Without it a
+        /// link to choose one is printed instead.
+        #[arg(long)]
+        password_stdin: bool,
+    },
+    /// Change a user's role.
+    SetRole {
+        user: String,
+        #[arg(value_parser = parse_role)]
+        role: UserRole,
+    },
+    /// Print a password reset link for a user without emailing it.
+    ResetPassword { user: String },
+    /// Mark a user's email address as verified.
+    VerifyUser { user: String },
+    /// List users, newest first.
+    ListUsers {
+        #[arg(long, value_parser = parse_role)]
+        role: Option<UserRole>,
+        #[arg(long, value_parser = parse_status)]
+        status: Option<AccountStatus>,
+        #[arg(long, value_parser = parse_approval_status)]
+        approval: Option<ApprovalStatus>,
+        /// Part of a name or email address.
+        #[arg(long)]
+        search: Option<String>,
+        #[arg(long, default_value_t = 1)]
+        page: u32,
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Apply pending migrations.
+    Migrate {
+        /// Only report the schema; fails when migrations are pending.
+        #[arg(long)]
+        check: bool,
+    },
+    /// Drop verification and password reset tokens that have expired.
+    PurgeExpiredTokens,
+    /// Send a test email through the configured transport.
+    SendTestEmail { to: String },
+}
+
+fn parse_role(value: &str) -> Result<UserRole, String> {
+    [UserRole::Admin, UserRole::User]
+        .into_iter()
+        .find(|role| role.to_str() == value)
+        .ok_or_else(|| "must be admin or user".to_string())
+}
+
+fn parse_status(value: &str) -> Result<AccountStatus, String> {
+    [
+        AccountStatus::PendingVerification,
+        AccountStatus::Active,
+        AccountStatus::Suspended,
+        AccountStatus::Locked,
+        AccountStatus::PendingDeletion,
+        AccountStatus::Deleted,
+    ]
+    .into_iter()
+    .find(|status| status.to_str() == value)
+    .ok_or_else(|| {
+        "must be pending_verification, active, suspended, locked, pending_deletion or deleted"
+            .to_string()
+    })
+}
+
+fn parse_approval_status(value: &str) -> Result<ApprovalStatus, String> {
+    [
+        ApprovalStatus::Approved,
+        ApprovalStatus::Pending,
+        ApprovalStatus::Rejected,
+    ]
+    .into_iter()
+    .find(|status| status.to_str() == value)
+    .ok_or_else(|| "must be approved, pending or rejected".to_string())
+}
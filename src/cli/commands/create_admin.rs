@@ -0,0 +1,101 @@
+use std::io::BufRead;
+
+use serde_json::json;
+use uuid::Uuid;
+use validator::ValidateEmail;
+
+use super::{database_error, issue_reset_link};
+use crate::{
+    AppState,
+    cli::output::CommandOutput,
+    core::audit::{audit_event, record_event},
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::UserRole,
+    },
+    infrastructure::middleware::client_info::ClientInfo,
+    models::user::response::FilterUser,
+    utils::password,
+};
+
+/// Same minimum as the registration endpoint.
+const MIN_PASSWORD_LENGTH: usize = 6;
+
+/// Without `password_stdin` the account gets a random password nobody
+/// knows, and the reset link printed is how the admin chooses theirs.
+pub async fn create_admin(
+    app_state: &AppState,
+    name: &str,
+    email: &str,
+    password_stdin: bool,
+) -> Result<CommandOutput, String> {
+    if name.trim().is_empty() {
+        return Err("Name is required".to_string());
+    }
+    if !email.validate_email() {
+        return Err(format!("{} is not a valid email address", email));
+    }
+
+    let repository = &app_state.user_repository;
+    let existing = repository
+        .get_user(None, None, Some(email), None)
+        .await
+        .map_err(database_error)?;
+    if existing.is_some() {
+        return Err(format!(
+            "{} already has an account; use set-role to make it an admin",
+            email
+        ));
+    }
+
+    let plain_password = match password_stdin {
+        true => read_password()?,
+        false => Uuid::new_v4().to_string(),
+    };
+    let hashed_password = password::hash_password(&plain_password).map_err(|e| e.to_string())?;
+
+    let user = repository
+        .save_invited_user(name.trim(), email, &hashed_password, UserRole::Admin)
+        .await
+        .map_err(database_error)?;
+
+    let event = NewAuditEvent {
+        target_user_id: Some(user.id),
+        metadata: json!({ "source": "cli", "role": user.role.to_str() }),
+        ..audit_event(AuditAction::Registered, &ClientInfo::default())
+    };
+    record_event(app_state, event).await;
+
+    let reset_link = match password_stdin {
+        true => None,
+        false => Some(issue_reset_link(app_state, &user).await?),
+    };
+
+    let mut message = format!("Created admin {} ({})", user.email, user.id);
+    if let Some(link) = &reset_link {
+        message.push_str(&format!("\nChoose a password at {}", link));
+    }
+
+    Ok(CommandOutput::new(
+        message,
+        json!({ "user": FilterUser::filter_user(&user), "resetLink": reset_link }),
+    ))
+}
+
+fn read_password() -> Result<String, String> {
+    let mut line = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|e| format!("Could not read the password from stdin: {}", e))?;
+
+    let password = line.trim_end_matches(['\r', '\n']).to_string();
+    if password.len() < MIN_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        ));
+    }
+
+    Ok(password)
+}
@@ -0,0 +1,80 @@
+use serde_json::json;
+
+use super::database_error;
+use crate::{
+    AppState,
+    cli::output::CommandOutput,
+    domains::user::{User, UserFilter},
+    models::user::response::FilterUser,
+};
+
+pub async fn list_users(
+    app_state: &AppState,
+    filter: &UserFilter,
+    page: u32,
+    limit: usize,
+) -> Result<CommandOutput, String> {
+    let page = page.max(1);
+    let users = app_state
+        .user_repository
+        .find_users(filter, page, limit)
+        .await
+        .map_err(database_error)?;
+
+    let filtered: Vec<FilterUser> = users.iter().map(FilterUser::filter_user).collect();
+
+    let output = CommandOutput::new(
+        format!("{} users on page {}", users.len(), page),
+        json!({ "users": filtered, "page": page, "limit": limit }),
+    );
+
+    Ok(match users.is_empty() {
+        true => output,
+        false => output.with_detail(table(&users)),
+    })
+}
+
+fn table(users: &[User]) -> String {
+    let header = [
+        "ID", "EMAIL", "NAME", "ROLE", "STATUS", "APPROVAL", "CREATED",
+    ];
+    let rows: Vec<[String; 7]> = users
+        .iter()
+        .map(|user| {
+            [
+                user.id.to_string(),
+                user.email.clone(),
+                user.name.clone(),
+                user.role.to_str().to_string(),
+                user.status.to_str().to_string(),
+                user.approval_status.to_str().to_string(),
+                user.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![line(header.to_vec())];
+    lines.extend(
+        rows.iter()
+            .map(|row| line(row.iter().map(String::as_str).collect())),
+    );
+    lines.join("\n")
+}
@@ -0,0 +1,42 @@
+use serde_json::{Value, json};
+
+use crate::{
+    AppState,
+    cli::output::CommandOutput,
+    core::migration::{MigrationStatus, apply_migrations, migration_status},
+};
+
+/// Applies pending migrations under the same lock as the server's
+/// `database.migrations = "apply"`. With `check`, only reports, and fails
+/// when the schema is not current so deploy scripts can gate on it.
+pub async fn migrate(app_state: &AppState, check: bool) -> Result<CommandOutput, String> {
+    if check {
+        let status = migration_status(app_state)
+            .await
+            .map_err(|e| format!("Could not read the migration history: {}", e))?;
+
+        if !status.is_current() {
+            return Err(format!("Schema is not current: {}", status.summary()));
+        }
+        return Ok(CommandOutput::new(
+            format!("Schema is current: {}", status.summary()),
+            status_data(&status),
+        ));
+    }
+
+    let status = apply_migrations(app_state).await?;
+    Ok(CommandOutput::new(
+        format!("Schema is current: {}", status.summary()),
+        status_data(&status),
+    ))
+}
+
+fn status_data(status: &MigrationStatus) -> Value {
+    json!({
+        "applied": status.applied,
+        "pending": status.pending,
+        "failed": status.failed,
+        "modified": status.modified,
+        "unknown": status.unknown,
+    })
+}
@@ -0,0 +1,47 @@
+pub mod create_admin;
+pub mod list_users;
+pub mod migrate;
+pub mod purge_expired_tokens;
+pub mod reset_password;
+pub mod send_test_email;
+pub mod set_role;
+pub mod verify_user;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{AppState, domains::user::User};
+
+/// Same lifetime as links sent by forgot-password.
+const RESET_LINK_LIFETIME_HOURS: i64 = 24;
+
+/// Looks a user up by id, or by email address when `user` is not a UUID.
+pub(super) async fn find_user(app_state: &AppState, user: &str) -> Result<User, String> {
+    let repository = &app_state.user_repository;
+    let found = match Uuid::parse_str(user) {
+        Ok(user_id) => repository.get_user(Some(user_id), None, None, None).await,
+        Err(_) => repository.get_user(None, None, Some(user), None).await,
+    };
+
+    found
+        .map_err(database_error)?
+        .ok_or_else(|| format!("No user matches {}", user))
+}
+
+/// Stores a fresh reset token for the user and returns the link to use it.
+pub(super) async fn issue_reset_link(app_state: &AppState, user: &User) -> Result<String, String> {
+    let token = Uuid::new_v4().to_string();
+    let expires_at = Utc::now() + Duration::hours(RESET_LINK_LIFETIME_HOURS);
+
+    app_state
+        .user_repository
+        .add_verified_token(user.id, &token, expires_at)
+        .await
+        .map_err(database_error)?;
+
+    Ok(app_state.config().urls.reset_password_link(&token, None))
+}
+
+pub(super) fn database_error(e: sqlx::Error) -> String {
+    format!("Database error: {}", e)
+}
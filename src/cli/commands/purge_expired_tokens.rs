@@ -0,0 +1,18 @@
+use chrono::Utc;
+use serde_json::json;
+
+use super::database_error;
+use crate::{AppState, cli::output::CommandOutput};
+
+pub async fn purge_expired_tokens(app_state: &AppState) -> Result<CommandOutput, String> {
+    let cleared = app_state
+        .user_repository
+        .clear_expired_tokens(Utc::now())
+        .await
+        .map_err(database_error)?;
+
+    Ok(CommandOutput::new(
+        format!("Cleared expired tokens from {} users", cleared),
+        json!({ "cleared": cleared }),
+    ))
+}
@@ -0,0 +1,16 @@
+use serde_json::json;
+
+use super::{find_user, issue_reset_link};
+use crate::{AppState, cli::output::CommandOutput};
+
+/// Issues the same kind of link as forgot-password, for handing to the user
+/// out of band when email is not getting through.
+pub async fn reset_password(app_state: &AppState, user: &str) -> Result<CommandOutput, String> {
+    let user = find_user(app_state, user).await?;
+    let reset_link = issue_reset_link(app_state, &user).await?;
+
+    Ok(CommandOutput::new(
+        format!("Reset link for {}: {}", user.email, reset_link),
+        json!({ "userId": user.id, "email": user.email, "resetLink": reset_link }),
+    ))
+}
@@ -0,0 +1,44 @@
+use chrono::Utc;
+use serde_json::json;
+use uuid::Uuid;
+use validator::ValidateEmail;
+
+use crate::{AppState, cli::output::CommandOutput, domains::mail::OutgoingMail};
+
+/// Sends a fixed message straight through the transport, skipping templates
+/// and the job queue, to check delivery settings in isolation.
+pub async fn send_test_email(app_state: &AppState, to: &str) -> Result<CommandOutput, String> {
+    if !to.validate_email() {
+        return Err(format!("{} is not a valid email address", to));
+    }
+
+    let config = app_state.config();
+    let transport = config.mail.transport.to_str();
+    let text_body = format!(
+        "This is a test message sent through the {} transport.",
+        transport
+    );
+    let mail = OutgoingMail {
+        id: Uuid::new_v4(),
+        from: config.mail.from_address.clone(),
+        to: to.to_string(),
+        subject: "Test email".to_string(),
+        html_body: format!("<p>{}</p>", text_body),
+        text_body,
+        created_at: Utc::now(),
+    };
+
+    app_state
+        .mailer
+        .send(&mail)
+        .await
+        .map_err(|e| format!("Sending to {} failed: {}", to, e))?;
+
+    Ok(CommandOutput::new(
+        format!(
+            "Sent a test email to {} through the {} transport",
+            to, transport
+        ),
+        json!({ "id": mail.id, "to": to, "transport": transport }),
+    ))
+}
@@ -0,0 +1,64 @@
+use serde_json::json;
+
+use super::{database_error, find_user};
+use crate::{
+    AppState,
+    cli::output::CommandOutput,
+    core::{
+        audit::{audit_event, record_event},
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::UserRole,
+        webhook::WebhookEvent,
+    },
+    infrastructure::middleware::client_info::ClientInfo,
+    models::user::response::FilterUser,
+};
+
+pub async fn set_role(
+    app_state: &AppState,
+    user: &str,
+    role: UserRole,
+) -> Result<CommandOutput, String> {
+    let user = find_user(app_state, user).await?;
+
+    if user.role == role {
+        return Ok(CommandOutput::new(
+            format!("{} is already {}", user.email, role.to_str()),
+            json!({ "user": FilterUser::filter_user(&user) }),
+        ));
+    }
+
+    let updated = app_state
+        .user_repository
+        .update_user_role(user.id, role)
+        .await
+        .map_err(database_error)?;
+
+    let event = NewAuditEvent {
+        target_user_id: Some(updated.id),
+        metadata: json!({
+            "from": user.role.to_str(),
+            "to": updated.role.to_str(),
+            "source": "cli",
+        }),
+        ..audit_event(AuditAction::RoleChanged, &ClientInfo::default())
+    };
+    record_event(app_state, event).await;
+
+    let mut data = user_event_data(&updated);
+    data["previousRole"] = json!(user.role.to_str());
+    dispatch_event(app_state, WebhookEvent::UserRoleChanged, data).await;
+
+    Ok(CommandOutput::new(
+        format!(
+            "{} is now {} (was {})",
+            updated.email,
+            updated.role.to_str(),
+            user.role.to_str()
+        ),
+        json!({ "user": FilterUser::filter_user(&updated) }),
+    ))
+}
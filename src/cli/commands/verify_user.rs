@@ -0,0 +1,71 @@
+use serde_json::json;
+
+use super::{database_error, find_user};
+use crate::{
+    AppState,
+    cli::output::CommandOutput,
+    core::{
+        audit::{audit_event, record_event},
+        webhook::{dispatch_event, user_event_data},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::{AccountStatus, StatusChange},
+        webhook::WebhookEvent,
+    },
+    infrastructure::middleware::client_info::ClientInfo,
+    models::user::response::FilterUser,
+};
+
+pub async fn verify_user(app_state: &AppState, user: &str) -> Result<CommandOutput, String> {
+    let user = find_user(app_state, user).await?;
+
+    let change = StatusChange {
+        to: AccountStatus::Active,
+        reason: Some("verified from the admin CLI".to_string()),
+        suspended_until: None,
+        actor_id: None,
+    };
+    let updated = app_state
+        .user_repository
+        .update_status(user.id, AccountStatus::PendingVerification, change)
+        .await
+        .map_err(database_error)?;
+
+    let Some(updated) = updated else {
+        return Ok(CommandOutput::new(
+            format!(
+                "{} is {}, nothing to verify",
+                user.email,
+                user.status.to_str()
+            ),
+            json!({ "user": FilterUser::filter_user(&user) }),
+        ));
+    };
+
+    // The emailed link would otherwise still sign the user in.
+    app_state
+        .user_repository
+        .clear_verification_token(updated.id)
+        .await
+        .map_err(database_error)?;
+
+    let event = NewAuditEvent {
+        target_user_id: Some(updated.id),
+        metadata: json!({ "source": "cli" }),
+        ..audit_event(AuditAction::EmailVerified, &ClientInfo::default())
+    };
+    record_event(app_state, event).await;
+
+    dispatch_event(
+        app_state,
+        WebhookEvent::UserVerified,
+        user_event_data(&updated),
+    )
+    .await;
+
+    Ok(CommandOutput::new(
+        format!("Verified {}", updated.email),
+        json!({ "user": FilterUser::filter_user(&updated) }),
+    ))
+}
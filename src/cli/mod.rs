@@ -0,0 +1,114 @@
+//! `auth-admin`: operations tasks run against the same database and
+//! configuration as the server, for when the API is not the right tool
+//! (bootstrapping the first admin, support cases, deploy scripts).
+
+pub mod args;
+pub mod commands;
+pub mod output;
+
+use std::sync::Arc;
+
+use clap::Parser;
+use sqlx::postgres::PgPoolOptions;
+
+use crate::{
+    AppState,
+    config::source::ConfigSource,
+    domains::user::UserFilter,
+    helpers::mail::renderer::MailRenderer,
+    infrastructure::{
+        database::database::DBClient, mail::mail_trait::create_transport,
+        user::users_impl::PgUserRepository,
+    },
+    read_config,
+};
+use args::{Cli, Command};
+use commands::{
+    create_admin::create_admin, list_users::list_users, migrate::migrate,
+    purge_expired_tokens::purge_expired_tokens, reset_password::reset_password,
+    send_test_email::send_test_email, set_role::set_role, verify_user::verify_user,
+};
+use output::{CommandOutput, print_result};
+
+/// Parses the command line, runs the command and returns the exit code.
+pub async fn run() -> i32 {
+    let cli = Cli::parse();
+
+    let result = match connect(&cli).await {
+        Ok(app_state) => {
+            let result = execute(&app_state, cli.command).await;
+            app_state.db_client.pool.close().await;
+            result
+        }
+        Err(e) => Err(e),
+    };
+
+    print_result(cli.output, &result);
+    match result {
+        Ok(_) => 0,
+        Err(_) => 1,
+    }
+}
+
+/// Builds the server's state from its configuration; stdout stays free for
+/// the command's own output. Configuration problems are returned like any
+/// other failure so `--output json` still reports them.
+async fn connect(cli: &Cli) -> Result<Arc<AppState>, String> {
+    let mut source = ConfigSource::from_environment(cli.config.as_deref())?;
+    let config = read_config(&mut source)
+        .map_err(|errors| format!("Invalid configuration: {}", errors.join("; ")))?;
+
+    let pool = PgPoolOptions::new()
+        .max_connections(2)
+        .connect(&config.database.url)
+        .await
+        .map_err(|e| format!("Failed to connect to the database: {}", e))?;
+
+    let mailer = create_transport(&config.mail);
+    let mail_renderer = MailRenderer::try_new(&config.mail)?;
+
+    let db_client = DBClient::new(pool);
+    let user_repository = Arc::new(PgUserRepository::new(&db_client));
+
+    Ok(Arc::new(AppState::new(
+        config,
+        db_client,
+        user_repository,
+        mailer,
+        mail_renderer,
+        None,
+    )))
+}
+
+/// Runs one parsed command against `app_state`.
+pub async fn execute(app_state: &AppState, command: Command) -> Result<CommandOutput, String> {
+    match command {
+        Command::CreateAdmin {
+            name,
+            email,
+            password_stdin,
+        } => create_admin(app_state, &name, &email, password_stdin).await,
+        Command::SetRole { user, role } => set_role(app_state, &user, role).await,
+        Command::ResetPassword { user } => reset_password(app_state, &user).await,
+        Command::VerifyUser { user } => verify_user(app_state, &user).await,
+        Command::ListUsers {
+            role,
+            status,
+            approval,
+            search,
+            page,
+            limit,
+        } => {
+            let filter = UserFilter {
+                role,
+                status,
+                approval_status: approval,
+                search,
+            };
+            list_users(app_state, &filter, page, limit).await
+        }
+        Command::Migrate { check } => migrate(app_state, check).await,
+        Command::PurgeExpiredTokens => purge_expired_tokens(app_state).await,
+        Command::SendTestEmail { to } => send_test_email(app_state, &to).await,
+    }
+}
@@ -0,0 +1,51 @@
+use serde_json::{Value, json};
+
+use super::args::OutputFormat;
+
+/// What a command reports: a line for people and the same facts as data for
+/// scripts.
+#[derive(Debug)]
+pub struct CommandOutput {
+    pub message: String,
+    pub data: Value,
+    /// Printed after the message in human output only, e.g. a table whose
+    /// contents are already in `data`.
+    pub detail: Option<String>,
+}
+
+impl CommandOutput {
+    pub fn new(message: impl Into<String>, data: Value) -> Self {
+        Self {
+            message: message.into(),
+            data,
+            detail: None,
+        }
+    }
+
+    pub fn with_detail(self, detail: String) -> Self {
+        Self {
+            detail: Some(detail),
+            ..self
+        }
+    }
+}
+
+/// JSON output follows the API's envelope, so the same tooling reads both.
+pub fn print_result(format: OutputFormat, result: &Result<CommandOutput, String>) {
+    match (format, result) {
+        (OutputFormat::Human, Ok(output)) => {
+            println!("{}", output.message);
+            if let Some(detail) = &output.detail {
+                println!("{}", detail);
+            }
+        }
+        (OutputFormat::Human, Err(message)) => eprintln!("{}", message),
+        (OutputFormat::Json, Ok(output)) => println!(
+            "{}",
+            json!({ "status": "success", "message": output.message, "data": output.data })
+        ),
+        (OutputFormat::Json, Err(message)) => {
+            println!("{}", json!({ "status": "fail", "message": message }))
+        }
+    }
+}
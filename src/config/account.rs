@@ -0,0 +1,47 @@
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeletionStrategy {
+    /// Remove the row entirely; dependent rows follow their foreign keys.
+    HardDelete,
+    /// Keep the row for referential history but scrub every personal field.
+    Anonymize,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountConfig {
+    pub deletion_grace_days: i64,
+    pub deletion_strategy: DeletionStrategy,
+    /// Failed logins in a row that lock an active account; 0 never locks.
+    pub lockout_threshold: u32,
+}
+
+impl AccountConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let deletion_grace_days = source.parse::<i64>("account.deletion_grace_days", 30);
+
+        let deletion_strategy = match source
+            .string("account.deletion_strategy", "anonymize")
+            .to_lowercase()
+            .as_str()
+        {
+            "delete" => DeletionStrategy::HardDelete,
+            "anonymize" => DeletionStrategy::Anonymize,
+            _ => {
+                source.error(
+                    "account.deletion_strategy",
+                    "must be either delete or anonymize",
+                );
+                DeletionStrategy::Anonymize
+            }
+        };
+
+        let lockout_threshold = source.parse::<u32>("account.lockout_threshold", 5);
+
+        Self {
+            deletion_grace_days,
+            deletion_strategy,
+            lockout_threshold,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use super::{
+    account::AccountConfig, audit::AuditConfig, client::ClientConfig, database::DatabaseConfig,
+    email::MailConfig, environment::AppEnvironment, health::HealthConfig, job::JobConfig,
+    jwt::JwtConfig, logging::LoggingConfig, metrics::MetricsConfig, proxy::ProxyConfig,
+    registration::RegistrationConfig, shutdown::ShutdownConfig, source::ConfigSource,
+    tracing::TracingConfig, urls::UrlConfig,
+};
+
+/// Settings, or whole sections when ending in a dot, that a running server
+/// picks up on reload. Everything else is read once at startup, mostly
+/// because it sized a pool or spawned workers, and needs a restart.
+pub const RELOADABLE_SETTINGS: &[&str] = &[
+    "cors.",
+    "proxy.",
+    "urls.",
+    "registration.",
+    "health.",
+    "mail.from_address",
+    "mail.template_dir",
+    "mail.default_locale",
+    "logging.capture_bodies",
+    "logging.body_sample_rate",
+    "logging.max_body_bytes",
+    "logging.redact_fields",
+];
+
+/// Every setting the server runs with, checked as a whole at startup.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub environment: AppEnvironment,
+    pub port: u16,
+    pub database: DatabaseConfig,
+    pub jwt: JwtConfig,
+    pub cors: ClientConfig,
+    pub proxy: ProxyConfig,
+    pub urls: UrlConfig,
+    pub mail: MailConfig,
+    pub registration: RegistrationConfig,
+    pub account: AccountConfig,
+    pub audit: AuditConfig,
+    pub jobs: JobConfig,
+    pub shutdown: ShutdownConfig,
+    pub health: HealthConfig,
+    pub metrics: MetricsConfig,
+    pub tracing: TracingConfig,
+    pub logging: LoggingConfig,
+}
+
+impl AppConfig {
+    /// Reads every section, then fails with all the problems found rather
+    /// than only the first.
+    pub fn load(source: &mut ConfigSource) -> Result<Self, Vec<String>> {
+        let environment = AppEnvironment::load(source);
+        let port = source.parse::<u16>("port", 8000);
+
+        let config = Self {
+            environment,
+            port,
+            database: DatabaseConfig::load(source),
+            jwt: JwtConfig::load(source),
+            cors: ClientConfig::load(source),
+            proxy: ProxyConfig::load(source),
+            urls: UrlConfig::load(source, port),
+            mail: MailConfig::load(source, environment),
+            registration: RegistrationConfig::load(source),
+            account: AccountConfig::load(source),
+            audit: AuditConfig::load(source),
+            jobs: JobConfig::load(source),
+            shutdown: ShutdownConfig::load(source),
+            health: HealthConfig::load(source),
+            metrics: MetricsConfig::load(source),
+            tracing: TracingConfig::load(source),
+            logging: LoggingConfig::load(source, environment),
+        };
+
+        source.finish().map(|()| config)
+    }
+
+    pub fn is_reloadable(key: &str) -> bool {
+        RELOADABLE_SETTINGS
+            .iter()
+            .any(|setting| match setting.strip_suffix('.') {
+                Some(section) => key
+                    .split_once('.')
+                    .is_some_and(|(prefix, _)| prefix == section),
+                None => key == *setting,
+            })
+    }
+
+    /// This config with the reloadable settings taken from `next`.
+    pub fn reloaded(&self, next: AppConfig) -> AppConfig {
+        AppConfig {
+            cors: next.cors,
+            proxy: next.proxy,
+            urls: next.urls,
+            registration: next.registration,
+            health: next.health,
+            mail: MailConfig {
+                from_address: next.mail.from_address,
+                template_dir: next.mail.template_dir,
+                default_locale: next.mail.default_locale,
+                ..self.mail.clone()
+            },
+            logging: LoggingConfig {
+                format: self.logging.format,
+                level: self.logging.level.clone(),
+                ..next.logging
+            },
+            ..self.clone()
+        }
+    }
+}
+
+/// Command-line flags of the server binary.
+#[derive(Debug, Clone, Default)]
+pub struct StartupOptions {
+    /// `--config <path>`: the TOML file to read instead of the default one.
+    pub config_file: Option<PathBuf>,
+    /// `--print-config`: show the resolved settings, secrets redacted, and
+    /// exit without starting the server.
+    pub print_config: bool,
+}
+
+impl StartupOptions {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--print-config" => options.print_config = true,
+                "--config" => match args.next() {
+                    Some(path) => options.config_file = Some(path.into()),
+                    None => return Err("--config needs a file path".to_string()),
+                },
+                _ => match arg.strip_prefix("--config=") {
+                    Some(path) => options.config_file = Some(path.into()),
+                    None => return Err(format!("Unknown argument: {}", arg)),
+                },
+            }
+        }
+
+        Ok(options)
+    }
+}
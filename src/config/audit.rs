@@ -0,0 +1,27 @@
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone)]
+pub struct AuditConfig {
+    pub signing_key: String,
+    pub checkpoint_interval_minutes: u64,
+}
+
+impl AuditConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let signing_key = source.required("audit.signing_key");
+
+        let checkpoint_interval_minutes =
+            source.parse::<u64>("audit.checkpoint_interval_minutes", 60);
+        if checkpoint_interval_minutes == 0 {
+            source.error(
+                "audit.checkpoint_interval_minutes",
+                "must be greater than zero",
+            );
+        }
+
+        Self {
+            signing_key,
+            checkpoint_interval_minutes,
+        }
+    }
+}
@@ -1,36 +1,51 @@
-use dotenv::dotenv;
-use std::env;
+use super::source::ConfigSource;
 
-#[derive(Debug, Clone)]
-pub struct Config {
-    pub database_url: String,
-    pub jwt_secret: String,
-    pub jwt_maxage: i64,
-    pub port: u16,
+/// What the server does about pending migrations when it starts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MigrationMode {
+    /// Start regardless; `/health/ready` still reports the schema.
+    Off,
+    /// Refuse to start unless every built-in migration has been applied.
+    Verify,
+    /// Apply pending migrations first. Replicas starting together take turns
+    /// on a Postgres advisory lock, so each migration runs once.
+    Apply,
 }
 
-impl Config {
-    pub fn init() -> Self {
-        dotenv().ok();
-        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set!");
+impl MigrationMode {
+    pub fn to_str(&self) -> &str {
+        match self {
+            MigrationMode::Off => "off",
+            MigrationMode::Verify => "verify",
+            MigrationMode::Apply => "apply",
+        }
+    }
+}
 
-        let jwt_secret = env::var("JWT_SECRET_KEY").expect("JWT_SECRET_KEY must be set!");
+#[derive(Debug, Clone)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub migrations: MigrationMode,
+}
 
-        let jwt_maxage = env::var("JWT_MAXAGE")
-            .expect("JWT_MAXAGE must be set!")
-            .parse::<i64>()
-            .expect("JWT_MAXAGE must be a valid i64 number");
+impl DatabaseConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let url = source.required("database.url");
 
-        let port = env::var("PORT")
-            .ok()
-            .and_then(|p| p.parse::<u16>().ok())
-            .unwrap_or(8000);
+        let migrations = match source
+            .string("database.migrations", "off")
+            .to_lowercase()
+            .as_str()
+        {
+            "off" => MigrationMode::Off,
+            "verify" => MigrationMode::Verify,
+            "apply" => MigrationMode::Apply,
+            _ => {
+                source.error("database.migrations", "must be off, verify or apply");
+                MigrationMode::Off
+            }
+        };
 
-        Self {
-            database_url,
-            jwt_secret,
-            jwt_maxage,
-            port,
-        }
+        Self { url, migrations }
     }
 }
@@ -1,6 +1,74 @@
-use std::env;
+use super::{environment::AppEnvironment, source::ConfigSource};
 
-use dotenv::dotenv;
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MailTransportKind {
+    /// Pooled async SMTP, for production.
+    Smtp,
+    /// Writes each message as an `.eml` file into `mail.file_dir`.
+    File,
+    /// Keeps messages in memory so tests can assert on them. Only allowed
+    /// when `env` is development, since nothing is ever delivered.
+    Memory,
+    /// Logs that a message would have been sent, without its body, and sends
+    /// nothing. The fallback in development when SMTP is not configured.
+    Log,
+    /// Captures messages for `/api/dev/mailbox`. Only exists in debug builds
+    /// and only when `env` is development.
+    #[cfg(debug_assertions)]
+    Mailbox,
+}
+
+impl MailTransportKind {
+    /// Every transport this build can select.
+    const ALL: &[MailTransportKind] = &[
+        MailTransportKind::Smtp,
+        MailTransportKind::File,
+        MailTransportKind::Memory,
+        MailTransportKind::Log,
+        #[cfg(debug_assertions)]
+        MailTransportKind::Mailbox,
+    ];
+
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_lowercase();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.to_str() == value)
+    }
+
+    /// `smtp, file, memory or log`, plus whatever this build adds.
+    fn choices() -> String {
+        let names: Vec<&str> = Self::ALL.iter().map(|kind| kind.to_str()).collect();
+        match names.split_last() {
+            Some((last, [])) => last.to_string(),
+            Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+            None => String::new(),
+        }
+    }
+
+    /// Transports that never deliver anything and so are refused outside
+    /// development.
+    fn development_only(&self) -> bool {
+        match self {
+            MailTransportKind::Memory => true,
+            #[cfg(debug_assertions)]
+            MailTransportKind::Mailbox => true,
+            _ => false,
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            MailTransportKind::Smtp => "smtp",
+            MailTransportKind::File => "file",
+            MailTransportKind::Memory => "memory",
+            MailTransportKind::Log => "log",
+            #[cfg(debug_assertions)]
+            MailTransportKind::Mailbox => "mailbox",
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct MySMTP {
@@ -8,20 +76,107 @@ pub struct MySMTP {
     pub password: String,
     pub server: String,
     pub port: u16,
+    pub pool_size: u32,
 }
 
 impl MySMTP {
-    pub fn init() -> Self {
-        dotenv().ok();
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let pool_size = source.parse::<u32>("smtp.pool_size", 4);
+        if pool_size == 0 {
+            source.error("smtp.pool_size", "must be greater than zero");
+        }
+
+        Self {
+            username: source.required("smtp.username"),
+            password: source.required("smtp.password"),
+            server: source.required("smtp.server"),
+            port: source.parse_required("smtp.port").unwrap_or_default(),
+            pool_size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MailConfig {
+    pub transport: MailTransportKind,
+    pub from_address: String,
+    pub file_dir: String,
+    /// Loads templates from this directory instead of the ones built into the
+    /// binary.
+    pub template_dir: Option<String>,
+    pub default_locale: String,
+    /// Only read when the SMTP transport is selected, so the other transports
+    /// run without any SMTP settings.
+    pub smtp: Option<MySMTP>,
+}
+
+impl MailConfig {
+    pub fn load(source: &mut ConfigSource, environment: AppEnvironment) -> Self {
+        // Without an explicit choice, use SMTP when it has been configured.
+        // Otherwise only development falls back to logging; anywhere else a
+        // server that silently sends no mail is a misconfiguration.
+        let fallback = match source.optional("smtp.server") {
+            Some(_) => Some(MailTransportKind::Smtp),
+            None => (environment == AppEnvironment::Development).then_some(MailTransportKind::Log),
+        };
+        let transport = match (source.optional("mail.transport"), fallback) {
+            (Some(value), _) => MailTransportKind::parse(&value).unwrap_or_else(|| {
+                source.error(
+                    "mail.transport",
+                    format!("must be one of {}", MailTransportKind::choices()),
+                );
+                MailTransportKind::Log
+            }),
+            (None, Some(fallback)) => {
+                // Recorded so --print-config shows the transport in use.
+                source.string("mail.transport", fallback.to_str());
+                fallback
+            }
+            (None, None) => {
+                source.error(
+                    "mail.transport",
+                    "must be set when smtp.server is not and env is not development",
+                );
+                MailTransportKind::Log
+            }
+        };
+
+        if transport.development_only() && environment != AppEnvironment::Development {
+            source.error(
+                "mail.transport",
+                format!(
+                    "{} is only allowed when env is development",
+                    transport.to_str()
+                ),
+            );
+        }
+
+        let smtp = (transport == MailTransportKind::Smtp).then(|| MySMTP::load(source));
+
+        let from_address = match source.optional("mail.from_address") {
+            Some(address) => address,
+            None => {
+                let fallback = smtp
+                    .as_ref()
+                    .map(|smtp| smtp.username.clone())
+                    .unwrap_or_else(|| "no-reply@localhost".to_string());
+                source.string("mail.from_address", &fallback)
+            }
+        };
+
+        let file_dir = source.string("mail.file_dir", "mail");
+
+        let template_dir = source.optional("mail.template_dir");
+
+        let default_locale = source.string("mail.default_locale", "en");
 
         Self {
-            username: env::var("SMTP_USERNAME").expect("SMTP_USERNAME must be set"),
-            password: env::var("SMTP_PASSWORD").expect("SMTP_PASSWORD must be set"),
-            server: env::var("SMTP_SERVER").expect("SMTP_SERVER must be set"),
-            port: env::var("SMTP_PORT")
-                .expect("SMTP_PORT must be set")
-                .parse()
-                .expect("SMTP_PORT must be a valid number"),
+            transport,
+            from_address,
+            file_dir,
+            template_dir,
+            default_locale,
+            smtp,
         }
     }
 }
@@ -0,0 +1,29 @@
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AppEnvironment {
+    Development,
+    Production,
+}
+
+impl AppEnvironment {
+    /// Reads `env`. Unset means production, so developer conveniences have
+    /// to be switched on deliberately.
+    pub fn load(source: &mut ConfigSource) -> Self {
+        match source.string("env", "production").to_lowercase().as_str() {
+            "development" => AppEnvironment::Development,
+            "production" => AppEnvironment::Production,
+            _ => {
+                source.error("env", "must be development or production");
+                AppEnvironment::Production
+            }
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            AppEnvironment::Development => "development",
+            AppEnvironment::Production => "production",
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone)]
+pub struct HealthConfig {
+    /// How long each readiness check may take before it counts as failed.
+    pub timeout: Duration,
+    /// Also probe the mail transport. Off by default so an SMTP outage does
+    /// not take the whole API out of rotation.
+    pub check_mail: bool,
+}
+
+impl HealthConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let timeout_ms = source.parse::<u64>("health.timeout_ms", 2000);
+        if timeout_ms == 0 {
+            source.error("health.timeout_ms", "must be greater than zero");
+        }
+
+        Self {
+            timeout: Duration::from_millis(timeout_ms),
+            check_mail: source.parse::<bool>("health.check_mail", false),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone)]
+pub struct JobConfig {
+    pub workers: usize,
+    pub max_attempts: i32,
+}
+
+impl JobConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let workers = source.parse::<usize>("jobs.workers", 4);
+        if workers == 0 {
+            source.error("jobs.workers", "must be greater than zero");
+        }
+
+        let max_attempts = source.parse::<i32>("jobs.max_attempts", 8);
+        if max_attempts < 1 {
+            source.error("jobs.max_attempts", "must be at least 1");
+        }
+
+        Self {
+            workers,
+            max_attempts,
+        }
+    }
+}
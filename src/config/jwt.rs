@@ -0,0 +1,21 @@
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone)]
+pub struct JwtConfig {
+    pub secret: String,
+    /// Lifetime of a token, in minutes.
+    pub maxage: i64,
+}
+
+impl JwtConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let secret = source.required("jwt.secret_key");
+
+        let maxage = source.parse_required::<i64>("jwt.maxage").unwrap_or(0);
+        if maxage < 0 {
+            source.error("jwt.maxage", "must not be negative");
+        }
+
+        Self { secret, maxage }
+    }
+}
@@ -0,0 +1,93 @@
+use tracing_subscriber::EnvFilter;
+
+use super::{environment::AppEnvironment, source::ConfigSource};
+
+/// Field names always redacted in logged bodies; `logging.redact_fields` adds
+/// to these. A field matches when its name contains one of these.
+const DEFAULT_REDACT_FIELDS: &[&str] = &["password", "token", "secret", "otp"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogFormat {
+    /// One JSON object per line, for log shippers.
+    Json,
+    /// Human-readable lines for a terminal.
+    Text,
+}
+
+impl LogFormat {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "json" => Some(LogFormat::Json),
+            "text" => Some(LogFormat::Text),
+            _ => None,
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            LogFormat::Json => "json",
+            LogFormat::Text => "text",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    pub format: LogFormat,
+    /// A filter in `RUST_LOG` syntax, e.g. `info,sqlx=warn`.
+    pub level: String,
+    /// Log request and response bodies. Off by default in production.
+    pub capture_bodies: bool,
+    /// Share of requests, from 0 to 1, whose bodies are captured.
+    pub body_sample_rate: f64,
+    /// Bodies larger than this, or of unknown length, are not captured.
+    pub max_body_bytes: usize,
+    /// JSON fields whose values never reach the logs, matched by name
+    /// ignoring case, `_` and `-`. Always includes the built-in defaults.
+    pub redact_fields: Vec<String>,
+}
+
+impl LoggingConfig {
+    pub fn load(source: &mut ConfigSource, environment: AppEnvironment) -> Self {
+        let development = environment == AppEnvironment::Development;
+
+        let fallback = match development {
+            true => LogFormat::Text,
+            false => LogFormat::Json,
+        };
+        let format = LogFormat::parse(&source.string("logging.format", fallback.to_str()))
+            .unwrap_or_else(|| {
+                source.error("logging.format", "must be json or text");
+                fallback
+            });
+
+        let level = source.string("logging.level", "info");
+        if let Err(e) = EnvFilter::try_new(&level) {
+            source.error("logging.level", format!("{} is not valid: {}", level, e));
+        }
+
+        let body_sample_rate = source.parse::<f64>("logging.body_sample_rate", 1.0);
+        if !(0.0..=1.0).contains(&body_sample_rate) {
+            source.error("logging.body_sample_rate", "must be between 0 and 1");
+        }
+
+        let mut redact_fields: Vec<String> = DEFAULT_REDACT_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect();
+        for field in source.list("logging.redact_fields") {
+            if !redact_fields.contains(&field) {
+                redact_fields.push(field);
+            }
+        }
+
+        Self {
+            format,
+            level,
+            capture_bodies: source.parse::<bool>("logging.capture_bodies", development),
+            body_sample_rate,
+            max_body_bytes: source.parse::<usize>("logging.max_body_bytes", 4096),
+            redact_fields,
+        }
+    }
+}
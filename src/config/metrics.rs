@@ -0,0 +1,38 @@
+use std::net::IpAddr;
+
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    /// Serves `/metrics` on its own listener instead of the API port, so it
+    /// can stay off the public network. Without it the endpoint is public,
+    /// unauthenticated, and has to be blocked at the proxy or load balancer.
+    pub port: Option<u16>,
+    /// Where that listener binds; loopback unless a scraper elsewhere needs it.
+    pub bind_address: IpAddr,
+}
+
+impl MetricsConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let enabled = source.parse::<bool>("metrics.enabled", true);
+
+        let port = source
+            .optional("metrics.port")
+            .and_then(|port| match port.parse::<u16>() {
+                Ok(port) => Some(port),
+                Err(e) => {
+                    source.error("metrics.port", format!("{} is not valid: {}", port, e));
+                    None
+                }
+            });
+
+        let bind_address = source.parse::<IpAddr>("metrics.bind_address", [127, 0, 0, 1].into());
+
+        Self {
+            enabled,
+            port,
+            bind_address,
+        }
+    }
+}
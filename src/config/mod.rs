@@ -1,3 +1,18 @@
+pub mod account;
+pub mod app;
+pub mod audit;
 pub mod client;
 pub mod database;
 pub mod email;
+pub mod environment;
+pub mod health;
+pub mod job;
+pub mod jwt;
+pub mod logging;
+pub mod metrics;
+pub mod proxy;
+pub mod registration;
+pub mod shutdown;
+pub mod source;
+pub mod tracing;
+pub mod urls;
@@ -0,0 +1,98 @@
+use std::{fmt, net::IpAddr, str::FromStr};
+
+use super::source::ConfigSource;
+
+/// An address, or a CIDR range such as `10.0.0.0/8`, a proxy may connect from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrustedNetwork {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl TrustedNetwork {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.address, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix_len as u32);
+                let mask = mask.unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix_len as u32);
+                let mask = mask.unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for TrustedNetwork {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_len) = match value.split_once('/') {
+            Some((address, prefix_len)) => (address, Some(prefix_len)),
+            None => (value, None),
+        };
+
+        let address = address
+            .parse::<IpAddr>()
+            .map_err(|e| e.to_string())?
+            .to_canonical();
+        let max_len = if address.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix_len| *prefix_len <= max_len)
+                .ok_or_else(|| format!("prefix length must be at most {}", max_len))?,
+            None => max_len,
+        };
+
+        Ok(Self {
+            address,
+            prefix_len,
+        })
+    }
+}
+
+impl fmt::Display for TrustedNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProxyConfig {
+    /// Peers whose `X-Forwarded-For` header is believed. Requests from
+    /// anywhere else are attributed to the socket address.
+    pub trusted_addresses: Vec<TrustedNetwork>,
+}
+
+impl ProxyConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let trusted_addresses = source
+            .list("proxy.trusted_addresses")
+            .iter()
+            .filter_map(|address| match address.parse::<TrustedNetwork>() {
+                Ok(network) => Some(network),
+                Err(e) => {
+                    source.error(
+                        "proxy.trusted_addresses",
+                        format!("{} is not an address or CIDR range: {}", address, e),
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        Self { trusted_addresses }
+    }
+
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.trusted_addresses
+            .iter()
+            .any(|network| network.contains(ip))
+    }
+}
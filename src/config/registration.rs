@@ -0,0 +1,79 @@
+use crate::domains::user::ApprovalStatus;
+
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegistrationMode {
+    Open,
+    InviteOnly,
+    AllowedDomains,
+    ApprovalRequired,
+}
+
+impl RegistrationMode {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "open" => Some(RegistrationMode::Open),
+            "invite_only" => Some(RegistrationMode::InviteOnly),
+            "allowed_domains" => Some(RegistrationMode::AllowedDomains),
+            "approval_required" => Some(RegistrationMode::ApprovalRequired),
+            _ => None,
+        }
+    }
+
+    /// Where a new account starts. Only approval-required holds it for review.
+    pub fn initial_approval_status(&self) -> ApprovalStatus {
+        match self {
+            RegistrationMode::ApprovalRequired => ApprovalStatus::Pending,
+            _ => ApprovalStatus::Approved,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RegistrationConfig {
+    pub mode: RegistrationMode,
+    pub allowed_email_domains: Vec<String>,
+}
+
+impl RegistrationConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let mode = RegistrationMode::parse(&source.string("registration.mode", "open"))
+            .unwrap_or_else(|| {
+                source.error(
+                    "registration.mode",
+                    "must be one of open, invite_only, allowed_domains, approval_required",
+                );
+                RegistrationMode::Open
+            });
+
+        let allowed_email_domains: Vec<String> = source
+            .list("registration.allowed_email_domains")
+            .iter()
+            .map(|s| s.trim_start_matches('@').to_lowercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        if mode == RegistrationMode::AllowedDomains && allowed_email_domains.is_empty() {
+            source.error(
+                "registration.allowed_email_domains",
+                "must be set when registration.mode is allowed_domains",
+            );
+        }
+
+        Self {
+            mode,
+            allowed_email_domains,
+        }
+    }
+
+    pub fn is_domain_allowed(&self, email: &str) -> bool {
+        email
+            .rsplit_once('@')
+            .map(|(_, domain)| {
+                let domain = domain.to_lowercase();
+                self.allowed_email_domains.contains(&domain)
+            })
+            .unwrap_or(false)
+    }
+}
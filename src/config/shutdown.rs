@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone)]
+pub struct ShutdownConfig {
+    /// How long in-flight requests and background work get to finish after
+    /// SIGTERM or SIGINT before the process exits anyway.
+    pub drain_timeout: Duration,
+}
+
+impl ShutdownConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let seconds = source.parse::<u64>("shutdown.drain_timeout_seconds", 30);
+
+        Self {
+            drain_timeout: Duration::from_secs(seconds),
+        }
+    }
+}
@@ -0,0 +1,461 @@
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use dotenv::dotenv;
+use url::Url;
+
+/// Environment variables override the config file when they are named
+/// `APP_` followed by the setting's key in upper case, with dots replaced by
+/// underscores: `smtp.pool_size` is `APP_SMTP_POOL_SIZE`.
+pub const ENV_PREFIX: &str = "APP_";
+
+/// Read when `--config` is not given and `APP_CONFIG_FILE` is unset. It is
+/// fine for it not to exist.
+pub const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// Every setting the application reads, with the variable it was read from
+/// before settings were prefixed. Those old names still work, below the
+/// prefixed ones, so existing `.env` files keep working.
+pub const SETTINGS: &[(&str, Option<&str>)] = &[
+    ("env", None),
+    ("port", Some("PORT")),
+    ("database.url", Some("DATABASE_URL")),
+    ("database.migrations", None),
+    ("jwt.secret_key", Some("JWT_SECRET_KEY")),
+    ("jwt.maxage", Some("JWT_MAXAGE")),
+    ("cors.allowed_origins", Some("ALLOWED_ORIGINS")),
+    ("proxy.trusted_addresses", None),
+    ("urls.api_base_url", Some("API_BASE_URL")),
+    ("urls.frontend_url", Some("FRONTEND_URL")),
+    ("urls.verify_email_path", Some("URL_VERIFY_EMAIL_PATH")),
+    ("urls.email_verified_path", Some("URL_EMAIL_VERIFIED_PATH")),
+    ("urls.reset_password_path", Some("URL_RESET_PASSWORD_PATH")),
+    (
+        "urls.accept_invitation_path",
+        Some("URL_ACCEPT_INVITATION_PATH"),
+    ),
+    (
+        "urls.cancel_deletion_path",
+        Some("URL_CANCEL_DELETION_PATH"),
+    ),
+    ("urls.data_export_path", Some("URL_DATA_EXPORT_PATH")),
+    ("urls.login_path", Some("URL_LOGIN_PATH")),
+    ("urls.approvals_path", Some("URL_APPROVALS_PATH")),
+    (
+        "urls.return_to_allowed_origins",
+        Some("RETURN_TO_ALLOWED_ORIGINS"),
+    ),
+    ("mail.transport", Some("MAIL_TRANSPORT")),
+    ("mail.from_address", Some("MAIL_FROM_ADDRESS")),
+    ("mail.file_dir", Some("MAIL_FILE_DIR")),
+    ("mail.template_dir", Some("MAIL_TEMPLATE_DIR")),
+    ("mail.default_locale", Some("MAIL_DEFAULT_LOCALE")),
+    ("smtp.server", Some("SMTP_SERVER")),
+    ("smtp.port", Some("SMTP_PORT")),
+    ("smtp.username", Some("SMTP_USERNAME")),
+    ("smtp.password", Some("SMTP_PASSWORD")),
+    ("smtp.pool_size", Some("SMTP_POOL_SIZE")),
+    ("registration.mode", Some("REGISTRATION_MODE")),
+    (
+        "registration.allowed_email_domains",
+        Some("ALLOWED_EMAIL_DOMAINS"),
+    ),
+    (
+        "account.deletion_grace_days",
+        Some("ACCOUNT_DELETION_GRACE_DAYS"),
+    ),
+    (
+        "account.deletion_strategy",
+        Some("ACCOUNT_DELETION_STRATEGY"),
+    ),
+    ("account.lockout_threshold", None),
+    ("audit.signing_key", Some("AUDIT_SIGNING_KEY")),
+    (
+        "audit.checkpoint_interval_minutes",
+        Some("AUDIT_CHECKPOINT_INTERVAL_MINUTES"),
+    ),
+    ("jobs.workers", Some("JOB_WORKERS")),
+    ("jobs.max_attempts", Some("JOB_MAX_ATTEMPTS")),
+    ("shutdown.drain_timeout_seconds", None),
+    ("health.timeout_ms", None),
+    ("health.check_mail", None),
+    ("metrics.enabled", None),
+    ("metrics.port", None),
+    ("metrics.bind_address", None),
+    ("tracing.otlp_endpoint", None),
+    ("tracing.service_name", None),
+    ("tracing.sample_ratio", None),
+    ("logging.format", None),
+    ("logging.level", None),
+    ("logging.capture_bodies", None),
+    ("logging.body_sample_rate", None),
+    ("logging.max_body_bytes", None),
+    ("logging.redact_fields", None),
+];
+
+/// Settings whose values never appear in `--print-config` output.
+const SECRET_SETTINGS: &[&str] = &["jwt.secret_key", "smtp.password", "audit.signing_key"];
+
+const REDACTED: &str = "********";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueOrigin {
+    Default,
+    File,
+    LegacyEnv,
+    Env,
+}
+
+#[derive(Debug, Clone)]
+struct ResolvedValue {
+    key: &'static str,
+    value: Option<String>,
+    origin: ValueOrigin,
+}
+
+/// The config file and environment merged into one lookup. Settings are read
+/// through the typed getters, which collect every problem instead of
+/// stopping at the first, and remember where each value came from.
+#[derive(Debug, Default)]
+pub struct ConfigSource {
+    path: Option<PathBuf>,
+    file: HashMap<String, String>,
+    vars: HashMap<String, String>,
+    resolved: Vec<ResolvedValue>,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl ConfigSource {
+    /// Loads `.env`, the process environment and the config file: `path` if
+    /// given, else `APP_CONFIG_FILE`, else `config.toml` when it exists.
+    pub fn from_environment(path: Option<&Path>) -> Result<Self, String> {
+        dotenv().ok();
+
+        let vars: HashMap<String, String> = env::vars().collect();
+
+        let explicit = path
+            .map(|path| path.to_path_buf())
+            .or_else(|| vars.get("APP_CONFIG_FILE").map(Into::into));
+        let path = explicit
+            .clone()
+            .unwrap_or_else(|| DEFAULT_CONFIG_FILE.into());
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => Some(contents),
+            Err(_) if explicit.is_none() => None,
+            Err(e) => return Err(format!("Cannot read {}: {}", path.display(), e)),
+        };
+
+        let mut source = Self::from_parts(contents.as_deref(), vars)
+            .map_err(|e| format!("Invalid config file {}: {}", path.display(), e))?;
+        source.path = Some(path);
+        Ok(source)
+    }
+
+    /// Builds a source from TOML text and a set of variables without looking
+    /// at the real environment.
+    pub fn from_parts(toml: Option<&str>, vars: HashMap<String, String>) -> Result<Self, String> {
+        let mut file = HashMap::new();
+        if let Some(toml) = toml {
+            let table = toml.parse::<toml::Table>().map_err(|e| e.to_string())?;
+            flatten_table("", &table, &mut file);
+        }
+
+        Ok(Self {
+            file,
+            vars,
+            ..Self::default()
+        })
+    }
+
+    fn lookup(&self, key: &str) -> (Option<String>, ValueOrigin) {
+        let present = |value: Option<&String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        if let Some(value) = present(self.vars.get(&env_name(key))) {
+            return (Some(value), ValueOrigin::Env);
+        }
+        if let Some(value) = legacy_name(key).and_then(|name| present(self.vars.get(name))) {
+            return (Some(value), ValueOrigin::LegacyEnv);
+        }
+        if let Some(value) = present(self.file.get(key)) {
+            return (Some(value), ValueOrigin::File);
+        }
+        (None, ValueOrigin::Default)
+    }
+
+    fn record(&mut self, key: &str, value: Option<String>, origin: ValueOrigin) {
+        let key = SETTINGS
+            .iter()
+            .map(|(known, _)| *known)
+            .find(|known| *known == key)
+            .unwrap_or_else(|| panic!("Setting {} is missing from SETTINGS", key));
+
+        match self
+            .resolved
+            .iter_mut()
+            .find(|resolved| resolved.key == key)
+        {
+            Some(resolved) => {
+                resolved.value = value;
+                resolved.origin = origin;
+            }
+            None => self.resolved.push(ResolvedValue { key, value, origin }),
+        }
+    }
+
+    pub fn optional(&mut self, key: &str) -> Option<String> {
+        let (value, origin) = self.lookup(key);
+        self.record(key, value.clone(), origin);
+        value
+    }
+
+    pub fn string(&mut self, key: &str, default: &str) -> String {
+        match self.optional(key) {
+            Some(value) => value,
+            None => {
+                self.record(key, Some(default.to_string()), ValueOrigin::Default);
+                default.to_string()
+            }
+        }
+    }
+
+    /// Reports a missing value and returns an empty string, so loading can
+    /// carry on and find the remaining problems.
+    pub fn required(&mut self, key: &str) -> String {
+        self.optional(key).unwrap_or_else(|| {
+            self.error(key, "must be set");
+            String::new()
+        })
+    }
+
+    pub fn parse<T>(&mut self, key: &str, default: T) -> T
+    where
+        T: FromStr + Display,
+        T::Err: Display,
+    {
+        match self.optional(key) {
+            Some(value) => value.parse().unwrap_or_else(|e| {
+                self.error(key, format!("{} is not valid: {}", value, e));
+                default
+            }),
+            None => {
+                self.record(key, Some(default.to_string()), ValueOrigin::Default);
+                default
+            }
+        }
+    }
+
+    pub fn parse_required<T>(&mut self, key: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self.required(key);
+        if value.is_empty() {
+            return None;
+        }
+
+        match value.parse() {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                self.error(key, format!("{} is not valid: {}", value, e));
+                None
+            }
+        }
+    }
+
+    /// A comma-separated list; in the config file an array works too.
+    pub fn list(&mut self, key: &str) -> Vec<String> {
+        self.optional(key)
+            .unwrap_or_default()
+            .split(',')
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect()
+    }
+
+    pub fn error(&mut self, key: &str, message: impl Display) {
+        self.errors
+            .push(format!("{} ({}): {}", key, env_name(key), message));
+    }
+
+    /// The config file this source looked for, whether or not it exists.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Settings whose value differs between this source and `other`.
+    pub fn changed_keys(&self, other: &ConfigSource) -> Vec<&'static str> {
+        SETTINGS
+            .iter()
+            .map(|(key, _)| *key)
+            .filter(|key| self.resolved_value(key) != other.resolved_value(key))
+            .collect()
+    }
+
+    fn resolved_value(&self, key: &str) -> Option<&str> {
+        self.resolved
+            .iter()
+            .find(|resolved| resolved.key == key)
+            .and_then(|resolved| resolved.value.as_deref())
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Ends loading: reports unknown keys in the config file alongside any
+    /// invalid values, and warns about variables that still use old names.
+    pub fn finish(&mut self) -> Result<(), Vec<String>> {
+        let mut unknown: Vec<&String> = self
+            .file
+            .keys()
+            .filter(|key| !SETTINGS.iter().any(|(known, _)| known == key))
+            .collect();
+        unknown.sort();
+        for key in unknown {
+            self.errors
+                .push(format!("{}: unknown setting in the config file", key));
+        }
+
+        self.warnings = self
+            .resolved
+            .iter()
+            .filter(|resolved| resolved.origin == ValueOrigin::LegacyEnv)
+            .map(|resolved| {
+                format!(
+                    "{} is deprecated, use {} instead",
+                    legacy_name(resolved.key).unwrap_or_default(),
+                    env_name(resolved.key)
+                )
+            })
+            .collect();
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors.clone())
+        }
+    }
+
+    /// The settings that were read, as TOML, with where each came from and
+    /// secrets redacted.
+    pub fn render(&self) -> String {
+        let mut resolved: Vec<&ResolvedValue> = self.resolved.iter().collect();
+        resolved.sort_by_key(|resolved| SETTINGS.iter().position(|(key, _)| *key == resolved.key));
+
+        let mut sections: Vec<(&str, Vec<&ResolvedValue>)> = Vec::new();
+        for resolved in resolved {
+            let section = resolved
+                .key
+                .split_once('.')
+                .map_or("", |(section, _)| section);
+            match sections.iter_mut().find(|(name, _)| *name == section) {
+                Some((_, values)) => values.push(resolved),
+                None => sections.push((section, vec![resolved])),
+            }
+        }
+
+        let mut output = String::new();
+        for (section, values) in sections {
+            if !section.is_empty() {
+                output.push_str(&format!("\n[{}]\n", section));
+            }
+
+            for resolved in values {
+                let name = resolved.key.rsplit('.').next().unwrap_or(resolved.key);
+                let origin = match resolved.origin {
+                    ValueOrigin::Default => "default".to_string(),
+                    ValueOrigin::File => "config file".to_string(),
+                    ValueOrigin::LegacyEnv => {
+                        legacy_name(resolved.key).unwrap_or_default().to_string()
+                    }
+                    ValueOrigin::Env => env_name(resolved.key),
+                };
+
+                match redact(resolved.key, resolved.value.as_deref()) {
+                    Some(value) => {
+                        let value = match value.parse::<i64>() {
+                            Ok(number) => toml::Value::Integer(number),
+                            Err(_) => toml::Value::String(value),
+                        };
+                        output.push_str(&format!("{} = {}  # {}\n", name, value, origin));
+                    }
+                    None => output.push_str(&format!("# {} is not set\n", name)),
+                }
+            }
+        }
+
+        output
+    }
+}
+
+pub fn env_name(key: &str) -> String {
+    format!("{}{}", ENV_PREFIX, key.replace('.', "_").to_uppercase())
+}
+
+fn legacy_name(key: &str) -> Option<&'static str> {
+    SETTINGS
+        .iter()
+        .find(|(known, _)| *known == key)
+        .and_then(|(_, legacy)| *legacy)
+}
+
+fn flatten_table(prefix: &str, table: &toml::Table, out: &mut HashMap<String, String>) {
+    for (name, value) in table {
+        let key = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+
+        match value {
+            toml::Value::Table(table) => flatten_table(&key, table, out),
+            toml::Value::String(value) => {
+                out.insert(key, value.to_string());
+            }
+            toml::Value::Array(items) => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| match item {
+                        toml::Value::String(value) => value.to_string(),
+                        other => other.to_string(),
+                    })
+                    .collect();
+                out.insert(key, items.join(","));
+            }
+            other => {
+                out.insert(key, other.to_string());
+            }
+        }
+    }
+}
+
+fn redact(key: &str, value: Option<&str>) -> Option<String> {
+    let value = value?;
+
+    if SECRET_SETTINGS.contains(&key) {
+        return Some(REDACTED.to_string());
+    }
+
+    // Connection strings carry their password inline.
+    if let Ok(mut url) = Url::parse(value)
+        && url.password().is_some()
+        && url.set_password(Some(REDACTED)).is_ok()
+    {
+        return Some(url.to_string());
+    }
+
+    Some(value.to_string())
+}
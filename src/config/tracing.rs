@@ -0,0 +1,37 @@
+use url::Url;
+
+use super::source::ConfigSource;
+
+#[derive(Debug, Clone)]
+pub struct TracingConfig {
+    /// Base URL of an OTLP/HTTP collector, e.g. `http://localhost:4318`.
+    /// Spans are only exported when this is set.
+    pub otlp_endpoint: Option<String>,
+    pub service_name: String,
+    /// Share of new traces to keep, from 0 to 1. Requests that arrive with a
+    /// sampled `traceparent` are always kept.
+    pub sample_ratio: f64,
+}
+
+impl TracingConfig {
+    pub fn load(source: &mut ConfigSource) -> Self {
+        let otlp_endpoint = source.optional("tracing.otlp_endpoint");
+        if let Some(endpoint) = &otlp_endpoint {
+            match Url::parse(endpoint) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+                _ => source.error("tracing.otlp_endpoint", "must be an http(s) URL"),
+            }
+        }
+
+        let sample_ratio = source.parse::<f64>("tracing.sample_ratio", 1.0);
+        if !(0.0..=1.0).contains(&sample_ratio) {
+            source.error("tracing.sample_ratio", "must be between 0 and 1");
+        }
+
+        Self {
+            otlp_endpoint,
+            service_name: source.string("tracing.service_name", "axum-auth-backend"),
+            sample_ratio,
+        }
+    }
+}
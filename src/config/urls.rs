@@ -0,0 +1,228 @@
+use url::{Url, form_urlencoded};
+
+use super::source::ConfigSource;
+
+/// Where links in emails and redirects point. Each flow has a path template
+/// that is appended to the API or frontend base URL; `{token}` in it is
+/// replaced with the flow's token.
+#[derive(Debug, Clone)]
+pub struct UrlConfig {
+    pub api_base_url: String,
+    pub frontend_url: String,
+    pub verify_email_path: String,
+    pub email_verified_path: String,
+    pub reset_password_path: String,
+    pub accept_invitation_path: String,
+    pub cancel_deletion_path: String,
+    pub data_export_path: String,
+    pub login_path: String,
+    pub approvals_path: String,
+    /// Origins a `return_to` URL may point at, as `scheme://host[:port]`. The
+    /// frontend is always one of them.
+    pub return_to_origins: Vec<String>,
+}
+
+impl UrlConfig {
+    pub fn load(source: &mut ConfigSource, port: u16) -> Self {
+        let api_base_url = base_url(
+            source,
+            "urls.api_base_url",
+            &format!("http://localhost:{}", port),
+        );
+        let frontend_url = base_url(source, "urls.frontend_url", "http://localhost:5173");
+
+        let mut return_to_origins: Vec<String> = origin_of(&frontend_url).into_iter().collect();
+        for origin in source.list("urls.return_to_allowed_origins") {
+            match origin_of(&origin) {
+                Some(origin) if !return_to_origins.contains(&origin) => {
+                    return_to_origins.push(origin)
+                }
+                Some(_) => {}
+                None => source.error(
+                    "urls.return_to_allowed_origins",
+                    format!("{} is not a valid origin", origin),
+                ),
+            }
+        }
+
+        Self {
+            api_base_url,
+            frontend_url,
+            verify_email_path: path_template(
+                source,
+                "urls.verify_email_path",
+                "/api/auth/verify-email?token={token}",
+                true,
+            ),
+            email_verified_path: path_template(
+                source,
+                "urls.email_verified_path",
+                "/settings",
+                false,
+            ),
+            reset_password_path: path_template(
+                source,
+                "urls.reset_password_path",
+                "/reset-password?token={token}",
+                true,
+            ),
+            accept_invitation_path: path_template(
+                source,
+                "urls.accept_invitation_path",
+                "/accept-invitation?token={token}",
+                true,
+            ),
+            cancel_deletion_path: path_template(
+                source,
+                "urls.cancel_deletion_path",
+                "/cancel-deletion?token={token}",
+                true,
+            ),
+            data_export_path: path_template(
+                source,
+                "urls.data_export_path",
+                "/api/exports/download?token={token}",
+                true,
+            ),
+            login_path: path_template(source, "urls.login_path", "/login", false),
+            approvals_path: path_template(source, "urls.approvals_path", "/admin/approvals", false),
+            return_to_origins,
+        }
+    }
+
+    pub fn verification_link(&self, token: &str, return_to: Option<&str>) -> String {
+        link(
+            &self.api_base_url,
+            &self.verify_email_path,
+            Some(token),
+            return_to,
+        )
+    }
+
+    pub fn email_verified_url(&self) -> String {
+        link(&self.frontend_url, &self.email_verified_path, None, None)
+    }
+
+    pub fn reset_password_link(&self, token: &str, return_to: Option<&str>) -> String {
+        link(
+            &self.frontend_url,
+            &self.reset_password_path,
+            Some(token),
+            return_to,
+        )
+    }
+
+    pub fn invitation_link(&self, token: &str) -> String {
+        link(
+            &self.frontend_url,
+            &self.accept_invitation_path,
+            Some(token),
+            None,
+        )
+    }
+
+    pub fn cancel_deletion_link(&self, token: &str) -> String {
+        link(
+            &self.frontend_url,
+            &self.cancel_deletion_path,
+            Some(token),
+            None,
+        )
+    }
+
+    pub fn data_export_link(&self, token: &str) -> String {
+        link(
+            &self.api_base_url,
+            &self.data_export_path,
+            Some(token),
+            None,
+        )
+    }
+
+    pub fn login_link(&self) -> String {
+        link(&self.frontend_url, &self.login_path, None, None)
+    }
+
+    pub fn approvals_link(&self) -> String {
+        link(&self.frontend_url, &self.approvals_path, None, None)
+    }
+
+    /// Resolves a caller-supplied `return_to` to an absolute URL, or `None`
+    /// when it points anywhere outside the allowed origins. Paths starting
+    /// with `/` are taken relative to the frontend.
+    pub fn allowed_return_to(&self, return_to: &str) -> Option<String> {
+        let return_to = return_to.trim();
+
+        // Browsers treat these as pointing at another host, so refuse them
+        // even though they would resolve onto the frontend here.
+        if return_to.starts_with("//") || return_to.contains('\\') {
+            return None;
+        }
+
+        let url = if return_to.starts_with('/') {
+            Url::parse(&format!("{}{}", self.frontend_url, return_to)).ok()?
+        } else {
+            Url::parse(return_to).ok()?
+        };
+
+        if !matches!(url.scheme(), "http" | "https") {
+            return None;
+        }
+
+        self.return_to_origins
+            .contains(&url.origin().ascii_serialization())
+            .then(|| url.to_string())
+    }
+}
+
+fn base_url(source: &mut ConfigSource, key: &str, default: &str) -> String {
+    let value = source
+        .string(key, default)
+        .trim_end_matches('/')
+        .to_string();
+
+    if origin_of(&value).is_none() {
+        source.error(key, "must be an absolute http or https URL");
+    }
+
+    value
+}
+
+fn path_template(source: &mut ConfigSource, key: &str, default: &str, needs_token: bool) -> String {
+    let value = source.string(key, default);
+
+    if !value.starts_with('/') {
+        source.error(key, "must start with /");
+    }
+    if needs_token && !value.contains("{token}") {
+        source.error(key, "must contain the {token} placeholder");
+    }
+
+    value
+}
+
+/// `scheme://host[:port]` of an http(s) URL.
+fn origin_of(value: &str) -> Option<String> {
+    let url = Url::parse(value).ok()?;
+    if !matches!(url.scheme(), "http" | "https") || url.host().is_none() {
+        return None;
+    }
+    Some(url.origin().ascii_serialization())
+}
+
+fn link(base: &str, template: &str, token: Option<&str>, return_to: Option<&str>) -> String {
+    let mut link = format!("{}{}", base, template);
+
+    if let Some(token) = token {
+        let token: String = form_urlencoded::byte_serialize(token.as_bytes()).collect();
+        link = link.replace("{token}", &token);
+    }
+
+    match return_to.map(|return_to| (Url::parse(&link), return_to)) {
+        Some((Ok(mut url), return_to)) => {
+            url.query_pairs_mut().append_pair("return_to", return_to);
+            url.to_string()
+        }
+        _ => link,
+    }
+}
@@ -0,0 +1,149 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use tracing::error;
+
+use crate::{
+    AppState,
+    config::account::DeletionStrategy,
+    core::{
+        account_status::lift_expired_suspensions, data_export::clear_expired_exports,
+        webhook::dispatch_event,
+    },
+    domains::{user::User, webhook::WebhookEvent},
+    errors::http_error::HttpError,
+    utils::token::{self, SignedLinkClaims},
+};
+
+pub const DELETION_CANCEL_PURPOSE: &str = "cancel_deletion";
+
+/// How recently the session must have been issued for `DELETE /users/me` to be
+/// accepted without re-entering the password.
+pub const RECENT_AUTH_WINDOW_MINUTES: i64 = 5;
+
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Held by the replica purging accounts. The others skip that round rather
+/// than delete the same accounts and announce each deletion twice.
+const PURGE_LOCK: &str = "account_purge";
+
+/// The nonce of a cancel link is the scheduled deletion time, so rescheduling
+/// invalidates links from earlier requests.
+pub fn deletion_nonce(deletion_scheduled_at: DateTime<Utc>) -> String {
+    deletion_scheduled_at.timestamp().to_string()
+}
+
+/// When an account whose deletion is requested at `now` gets purged.
+pub fn deletion_due_at(now: DateTime<Utc>, grace_days: i64) -> DateTime<Utc> {
+    now + chrono::Duration::days(grace_days)
+}
+
+/// Whether a session issued at `authenticated_at` may delete the account
+/// without the password being entered again.
+pub fn is_recent_auth(authenticated_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    authenticated_at >= now - chrono::Duration::minutes(RECENT_AUTH_WINDOW_MINUTES)
+}
+
+/// Signs the link that cancels a deletion scheduled for `deletion_scheduled_at`.
+/// It stops working once the account is purged.
+pub fn cancel_deletion_token(
+    user: &User,
+    deletion_scheduled_at: DateTime<Utc>,
+    secret: &[u8],
+) -> Result<String, HttpError> {
+    token::generate_signed_link_token(
+        &user.id.to_string(),
+        DELETION_CANCEL_PURPOSE,
+        &deletion_nonce(deletion_scheduled_at),
+        secret,
+        deletion_scheduled_at,
+    )
+    .map_err(|e| HttpError::server_error(e.to_string()))
+}
+
+/// Whether a cancel link still applies to `user`: the account is not gone
+/// and the deletion it was issued for is the one still scheduled.
+pub fn cancel_link_matches(user: &User, claims: &SignedLinkClaims) -> bool {
+    user.id.to_string() == claims.sub
+        && user.deleted_at.is_none()
+        && user.deletion_scheduled_at.map(deletion_nonce).as_deref() == Some(claims.nonce.as_str())
+}
+
+/// Periodically purges accounts past their grace period, lifts timed
+/// suspensions and clears expired data exports.
+pub fn spawn_account_worker(app_state: Arc<AppState>) {
+    app_state.shutdown.clone().spawn(async move {
+        let mut interval = tokio::time::interval(MAINTENANCE_INTERVAL);
+        while app_state.shutdown.tick(&mut interval).await {
+            purge_due_accounts(&app_state).await;
+            lift_expired_suspensions(&app_state).await;
+            clear_expired_exports(&app_state).await;
+        }
+    });
+}
+
+/// Hard-deletes or anonymizes every account whose grace period has ended.
+/// Returns how many were purged; none when another replica holds the purge.
+pub async fn purge_due_accounts(app_state: &AppState) -> usize {
+    // The transaction only carries the lock, which goes with it however
+    // this function returns.
+    let mut lock = match app_state.db_client.pool.begin().await {
+        Ok(lock) => lock,
+        Err(e) => {
+            error!(error = %e, "Failed to start the account purge");
+            return 0;
+        }
+    };
+    let locked: Result<(bool,), _> =
+        sqlx::query_as("SELECT pg_try_advisory_xact_lock(hashtext($1))")
+            .bind(PURGE_LOCK)
+            .fetch_one(&mut *lock)
+            .await;
+    match locked {
+        Ok((true,)) => {}
+        Ok((false,)) => return 0,
+        Err(e) => {
+            error!(error = %e, "Failed to take the account purge lock");
+            return 0;
+        }
+    }
+
+    let repository = &app_state.user_repository;
+
+    let users = match repository.get_users_due_for_deletion(Utc::now()).await {
+        Ok(users) => users,
+        Err(e) => {
+            error!(error = %e, "Failed to load accounts due for deletion");
+            return 0;
+        }
+    };
+
+    let mut purged = 0;
+    for user in users {
+        let result = match app_state.config().account.deletion_strategy {
+            DeletionStrategy::HardDelete => repository.delete_user(user.id).await.map(|_| true),
+            DeletionStrategy::Anonymize => repository.anonymize_user(user.id).await,
+        };
+
+        match result {
+            Ok(true) => {}
+            // Cancelled since it was loaded.
+            Ok(false) => continue,
+            Err(e) => {
+                error!(user.id = %user.id, error = %e, "Failed to delete account");
+                continue;
+            }
+        }
+        purged += 1;
+
+        // Only the id is sent; the personal data is gone by now.
+        let data = json!({ "user": { "id": user.id } });
+        dispatch_event(app_state, WebhookEvent::UserDeleted, data).await;
+    }
+
+    if let Err(e) = lock.commit().await {
+        error!(error = %e, "Failed to release the account purge lock");
+    }
+    purged
+}
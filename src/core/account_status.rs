@@ -0,0 +1,68 @@
+use chrono::Utc;
+use tracing::error;
+
+use crate::{
+    AppState,
+    domains::user::{AccountStatus, StatusChange, User},
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+};
+use axum::http::StatusCode;
+
+/// Applies `change` to `user` if the lifecycle allows it. The update is
+/// conditional on the status we validated against, so a concurrent change
+/// surfaces as a conflict instead of skipping an edge.
+pub async fn transition_status(
+    app_state: &AppState,
+    user: &User,
+    change: StatusChange,
+) -> Result<User, HttpError> {
+    if !user.status.can_transition_to(change.to) {
+        return Err(invalid_transition());
+    }
+
+    app_state
+        .user_repository
+        .update_status(user.id, user.status, change)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(invalid_transition)
+}
+
+/// Reactivates every account whose timed suspension has ended.
+pub async fn lift_expired_suspensions(app_state: &AppState) {
+    let repository = &app_state.user_repository;
+
+    let users = match repository
+        .get_users_with_expired_suspension(Utc::now())
+        .await
+    {
+        Ok(users) => users,
+        Err(e) => {
+            error!(error = %e, "Failed to load expired suspensions");
+            return;
+        }
+    };
+
+    for user in users {
+        let change = StatusChange {
+            to: AccountStatus::Active,
+            reason: Some("suspension expired".to_string()),
+            suspended_until: None,
+            actor_id: None,
+        };
+
+        if let Err(e) = repository
+            .update_status(user.id, AccountStatus::Suspended, change)
+            .await
+        {
+            error!(user.id = %user.id, error = %e, "Failed to lift suspension");
+        }
+    }
+}
+
+pub fn invalid_transition() -> HttpError {
+    HttpError::new(
+        ErrorMessage::InvalidStatusTransition.to_string(),
+        StatusCode::CONFLICT,
+    )
+}
@@ -0,0 +1,39 @@
+use serde_json::json;
+use tracing::error;
+
+use crate::{
+    AppState,
+    core::metrics::record_auth_event,
+    domains::audit::{AuditAction, NewAuditEvent},
+    infrastructure::{
+        audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+        middleware::client_info::ClientInfo,
+    },
+};
+
+/// An event for `action` carrying the caller's IP and user agent. Callers fill
+/// in the actor, target and metadata with struct update syntax.
+pub fn audit_event(action: AuditAction, client: &ClientInfo) -> NewAuditEvent {
+    NewAuditEvent {
+        action,
+        actor_id: None,
+        target_user_id: None,
+        ip_address: client.ip_address.clone(),
+        user_agent: client.user_agent.clone(),
+        metadata: json!({}),
+    }
+}
+
+/// Appends an event to the audit log. A failed write is logged rather than
+/// failing the request that triggered it.
+pub async fn record_event(app_state: &AppState, event: NewAuditEvent) {
+    record_auth_event(&event);
+
+    let action = event.action;
+    if let Err(e) = PgAuditRepository::new(&app_state.db_client)
+        .append_event(event)
+        .await
+    {
+        error!(audit.action = action.to_str(), error = %e, "Failed to record audit event");
+    }
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    domains::audit::{AuditChainLink, AuditCheckpoint},
+    infrastructure::audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+};
+
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+pub const CHECKPOINT_ALGORITHM: &str = "HMAC-SHA256";
+
+const VERIFY_BATCH_SIZE: i64 = 1000;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BrokenLink {
+    pub sequence: i64,
+    #[serde(rename = "eventId")]
+    pub event_id: Option<Uuid>,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChainVerification {
+    pub valid: bool,
+    #[serde(rename = "eventsChecked")]
+    pub events_checked: i64,
+    #[serde(rename = "checkpointsChecked")]
+    pub checkpoints_checked: usize,
+    #[serde(rename = "headHash")]
+    pub head_hash: Option<String>,
+    #[serde(rename = "brokenLink")]
+    pub broken_link: Option<BrokenLink>,
+}
+
+/// Recomputes the hash the insert trigger stored for `link`. The layout must
+/// stay in sync with the `audit_event_hash` SQL function.
+pub fn event_hash(prev_hash: &str, link: &AuditChainLink) -> String {
+    let content = [
+        prev_hash.to_string(),
+        link.id.to_string(),
+        link.sequence.to_string(),
+        link.actor_id.map(|id| id.to_string()).unwrap_or_default(),
+        link.target_user_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        link.action.clone(),
+        link.ip_address.clone().unwrap_or_default(),
+        link.user_agent.clone().unwrap_or_default(),
+        link.metadata_text.clone(),
+        link.created_at.timestamp_micros().to_string(),
+    ]
+    .join("\n");
+
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+pub fn sign_checkpoint(
+    signing_key: &str,
+    sequence: i64,
+    hash: &str,
+    event_count: i64,
+    created_at: DateTime<Utc>,
+) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_key.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(
+        format!(
+            "{}\n{}\n{}\n{}",
+            sequence,
+            hash,
+            event_count,
+            created_at.timestamp_micros()
+        )
+        .as_bytes(),
+    );
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn checkpoint_signature_valid(signing_key: &str, checkpoint: &AuditCheckpoint) -> bool {
+    let expected = sign_checkpoint(
+        signing_key,
+        checkpoint.sequence,
+        &checkpoint.hash,
+        checkpoint.event_count,
+        checkpoint.created_at,
+    );
+    expected == checkpoint.signature
+}
+
+/// Walks the whole chain in sequence order and stops at the first event or
+/// checkpoint that does not line up.
+pub async fn verify_chain(app_state: &AppState) -> Result<ChainVerification, sqlx::Error> {
+    let repository = PgAuditRepository::new(&app_state.db_client);
+
+    let checkpoints = repository.get_all_checkpoints().await?;
+    let mut pending_checkpoints: HashMap<i64, Vec<&AuditCheckpoint>> = HashMap::new();
+    for checkpoint in &checkpoints {
+        pending_checkpoints
+            .entry(checkpoint.sequence)
+            .or_default()
+            .push(checkpoint);
+    }
+
+    let mut verification = ChainVerification {
+        valid: true,
+        events_checked: 0,
+        checkpoints_checked: 0,
+        head_hash: None,
+        broken_link: None,
+    };
+
+    let mut prev_hash = GENESIS_HASH.to_string();
+    let mut after = 0;
+
+    'walk: loop {
+        let links = repository.get_chain_links(after, VERIFY_BATCH_SIZE).await?;
+        if links.is_empty() {
+            break;
+        }
+
+        for link in &links {
+            let reason = if link.prev_hash != prev_hash {
+                Some("previous hash does not match the preceding event")
+            } else if event_hash(&prev_hash, link) != link.hash {
+                Some("event content does not match its hash")
+            } else {
+                None
+            };
+
+            if let Some(reason) = reason {
+                verification.broken_link = Some(BrokenLink {
+                    sequence: link.sequence,
+                    event_id: Some(link.id),
+                    reason: reason.to_string(),
+                });
+                break 'walk;
+            }
+
+            verification.events_checked += 1;
+
+            for checkpoint in pending_checkpoints
+                .remove(&link.sequence)
+                .unwrap_or_default()
+            {
+                verification.checkpoints_checked += 1;
+
+                let reason = if !checkpoint_signature_valid(
+                    &app_state.config().audit.signing_key,
+                    checkpoint,
+                ) {
+                    Some("checkpoint signature is invalid")
+                } else if checkpoint.hash != link.hash {
+                    Some("event hash differs from the signed checkpoint")
+                } else if checkpoint.event_count != verification.events_checked {
+                    Some("event count differs from the signed checkpoint")
+                } else {
+                    None
+                };
+
+                if let Some(reason) = reason {
+                    verification.broken_link = Some(BrokenLink {
+                        sequence: link.sequence,
+                        event_id: Some(link.id),
+                        reason: reason.to_string(),
+                    });
+                    break 'walk;
+                }
+            }
+
+            prev_hash = link.hash.clone();
+            after = link.sequence;
+        }
+    }
+
+    // A checkpoint whose event never showed up means the tail was removed.
+    if verification.broken_link.is_none()
+        && let Some(sequence) = pending_checkpoints.keys().min()
+    {
+        verification.broken_link = Some(BrokenLink {
+            sequence: *sequence,
+            event_id: None,
+            reason: "event covered by a signed checkpoint is missing".to_string(),
+        });
+    }
+
+    verification.valid = verification.broken_link.is_none();
+    if verification.valid && verification.events_checked > 0 {
+        verification.head_hash = Some(prev_hash);
+    }
+
+    Ok(verification)
+}
+
+/// Signs the current head of the chain. Returns `None` when nothing was
+/// appended since the latest checkpoint.
+pub async fn create_checkpoint(
+    app_state: &AppState,
+) -> Result<Option<AuditCheckpoint>, sqlx::Error> {
+    let repository = PgAuditRepository::new(&app_state.db_client);
+
+    let Some((sequence, hash, event_count)) = repository.get_chain_head().await? else {
+        return Ok(None);
+    };
+
+    let latest = repository.get_latest_checkpoint().await?;
+    if latest.is_some_and(|checkpoint| checkpoint.sequence == sequence) {
+        return Ok(None);
+    }
+
+    // Postgres keeps microseconds, so sign exactly what will be stored.
+    let created_at = DateTime::from_timestamp_micros(Utc::now().timestamp_micros())
+        .expect("current time is representable");
+
+    let checkpoint = AuditCheckpoint {
+        id: Uuid::new_v4(),
+        sequence,
+        signature: sign_checkpoint(
+            &app_state.config().audit.signing_key,
+            sequence,
+            &hash,
+            event_count,
+            created_at,
+        ),
+        hash,
+        event_count,
+        created_at,
+    };
+
+    repository.save_checkpoint(&checkpoint).await.map(Some)
+}
+
+pub fn spawn_checkpoint_worker(app_state: Arc<AppState>) {
+    app_state.shutdown.clone().spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            app_state.config().audit.checkpoint_interval_minutes * 60,
+        ));
+        while app_state.shutdown.tick(&mut interval).await {
+            if let Err(e) = create_checkpoint(&app_state).await {
+                error!(error = %e, "Failed to create audit checkpoint");
+            }
+        }
+    });
+}
@@ -0,0 +1,100 @@
+use std::{fs, path::Path, sync::Arc, time::Duration, time::SystemTime};
+
+use tokio::signal::unix::{SignalKind, signal};
+use tracing::{error, info, warn};
+
+use crate::{
+    AppState,
+    config::{
+        app::{AppConfig, StartupOptions},
+        source::ConfigSource,
+    },
+    helpers::mail::renderer::MailRenderer,
+};
+
+const FILE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Reloads the configuration on SIGHUP and whenever the config file changes.
+/// `source` is what the running config was loaded from.
+pub fn spawn_config_reloader(
+    app_state: Arc<AppState>,
+    options: StartupOptions,
+    mut source: ConfigSource,
+) {
+    app_state.shutdown.clone().spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                warn!(error = %e, "Failed to listen for SIGHUP, config reloads are off");
+                return;
+            }
+        };
+
+        let mut interval = tokio::time::interval(FILE_POLL_INTERVAL);
+        let mut modified = source.path().and_then(modified_at);
+
+        loop {
+            tokio::select! {
+                _ = app_state.shutdown.draining() => return,
+                _ = hangup.recv() => {
+                    modified = source.path().and_then(modified_at);
+                    info!("SIGHUP received, reloading the configuration");
+                }
+                _ = interval.tick() => {
+                    let current = source.path().and_then(modified_at);
+                    if current == modified {
+                        continue;
+                    }
+                    modified = current;
+                    info!("Config file changed, reloading the configuration");
+                }
+            }
+
+            match reload_config(&app_state, &options, &source) {
+                Ok(reloaded) => source = reloaded,
+                Err(errors) => {
+                    error!(
+                        errors = ?errors,
+                        "Config reload rejected, keeping the current configuration"
+                    );
+                }
+            }
+        }
+    });
+}
+
+/// Reads the configuration again and swaps in its reloadable settings. The
+/// new config has to be valid as a whole, templates included, before any of
+/// it is used. Returns the source it was read from.
+pub fn reload_config(
+    app_state: &AppState,
+    options: &StartupOptions,
+    previous: &ConfigSource,
+) -> Result<ConfigSource, Vec<String>> {
+    let mut source =
+        ConfigSource::from_environment(options.config_file.as_deref()).map_err(|e| vec![e])?;
+    let next = AppConfig::load(&mut source)?;
+
+    let config = app_state.config().reloaded(next);
+    let mail_renderer = MailRenderer::try_new(&config.mail).map_err(|e| vec![e])?;
+
+    for key in previous.changed_keys(&source) {
+        if AppConfig::is_reloadable(key) {
+            info!(setting = key, "Reloaded setting");
+        } else {
+            warn!(
+                setting = key,
+                "Setting changed but only takes effect after a restart"
+            );
+        }
+    }
+
+    app_state.mail_renderer.store(Arc::new(mail_renderer));
+    app_state.config.store(Arc::new(config));
+
+    Ok(source)
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
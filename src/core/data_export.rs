@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{Value, json};
+use tracing::{error, info};
+
+use crate::{
+    AppState,
+    core::job::enqueue_email,
+    domains::{audit::AuditAction, data_export::DataExport, job::EmailJob, user::User},
+    infrastructure::{
+        audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+        data_export::{
+            data_export_impl::PgDataExportRepository, data_export_trait::DataExportRepository,
+        },
+        invitation::{
+            invitation_impl::PgInvitationRepository, invitation_trait::InvitationRepository,
+        },
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    models::{
+        audit::response::FilterAuditEvent, invitation::response::FilterInvitation,
+        organization::response::FilterOrganization, user::response::FilterStatusTransition,
+    },
+    utils::token,
+};
+
+pub const DATA_EXPORT_PURPOSE: &str = "data_export";
+
+pub const EXPORT_FORMAT: &str = "axum-auth-backend/user-export/v2";
+
+/// Download links are deliberately short-lived; a new export can always be
+/// requested.
+pub fn export_link_expiry() -> DateTime<Utc> {
+    Utc::now() + Duration::hours(1)
+}
+
+/// Builds the export in the background and mails a signed download link once
+/// it is ready, so large accounts never hold up the request.
+pub fn spawn_export(app_state: Arc<AppState>, export: DataExport, user: User) {
+    app_state.shutdown.clone().spawn(async move {
+        let repository = PgDataExportRepository::new(&app_state.db_client);
+
+        let payload = match build_export(&app_state, &user).await {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!(export.id = %export.id, error = %e, "Failed to build data export");
+                if let Err(e) = repository.fail_export(export.id).await {
+                    error!(export.id = %export.id, error = %e, "Failed to mark data export as failed");
+                }
+                return;
+            }
+        };
+
+        let expires_at = export_link_expiry();
+
+        if let Err(e) = repository
+            .complete_export(export.id, payload, expires_at)
+            .await
+        {
+            error!(export.id = %export.id, error = %e, "Failed to store data export");
+            return;
+        }
+
+        let download_token = match token::generate_signed_link_token(
+            &export.id.to_string(),
+            DATA_EXPORT_PURPOSE,
+            &user.id.to_string(),
+            app_state.config().jwt.secret.as_bytes(),
+            expires_at,
+        ) {
+            Ok(download_token) => download_token,
+            Err(e) => {
+                error!(export.id = %export.id, error = %e, "Failed to sign data export link");
+                return;
+            }
+        };
+
+        let download_link = app_state.config().urls.data_export_link(&download_token);
+
+        enqueue_email(
+            &app_state,
+            EmailJob::DataExport {
+                to: user.email.clone(),
+                locale: user.locale.clone(),
+                username: user.name.clone(),
+                download_link,
+            },
+        )
+        .await;
+    });
+}
+
+/// Everything stored about `user`. Sessions are stateless JWTs and no consents
+/// are recorded, so neither has anything to export; login history comes from
+/// the audit trail.
+pub async fn build_export(app_state: &AppState, user: &User) -> Result<Value, sqlx::Error> {
+    let organizations = PgOrganizationRepository::new(&app_state.db_client)
+        .get_user_organizations(user.id)
+        .await?;
+
+    let invitations = PgInvitationRepository::new(&app_state.db_client)
+        .get_invitations_for_email(&user.email)
+        .await?;
+
+    let audit_events = PgAuditRepository::new(&app_state.db_client)
+        .get_events_for_user(user.id)
+        .await?;
+
+    let status_history = app_state
+        .user_repository
+        .get_status_transitions(user.id)
+        .await?;
+
+    let login_actions = [
+        AuditAction::LoginSucceeded.to_str(),
+        AuditAction::LoginFailed.to_str(),
+    ];
+    let login_history = audit_events
+        .iter()
+        .filter(|event| login_actions.contains(&event.action.as_str()))
+        .map(|event| {
+            json!({
+                "succeeded": event.action == AuditAction::LoginSucceeded.to_str(),
+                "ipAddress": event.ip_address,
+                "userAgent": event.user_agent,
+                "createdAt": event.created_at,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(json!({
+        "format": EXPORT_FORMAT,
+        "exportedAt": Utc::now(),
+        "profile": {
+            "id": user.id,
+            "name": user.name,
+            "email": user.email,
+            "role": user.role.to_str(),
+            "status": user.status.to_str(),
+            "suspendedReason": user.suspended_reason,
+            "suspendedUntil": user.suspended_until,
+            "approvalStatus": user.approval_status.to_str(),
+            "locale": user.locale,
+            "deletionScheduledAt": user.deletion_scheduled_at,
+            "createdAt": user.created_at,
+            "updatedAt": user.updated_at,
+        },
+        "organizations": organizations
+            .iter()
+            .map(FilterOrganization::from_user_organization)
+            .collect::<Vec<_>>(),
+        "invitations": FilterInvitation::filter_invitations(&invitations),
+        "statusHistory": FilterStatusTransition::filter_transitions(&status_history),
+        "loginHistory": login_history,
+        "auditEvents": FilterAuditEvent::filter_audit_events(&audit_events),
+    }))
+}
+
+/// Drops the payload of every export whose download link has expired, so
+/// personal data does not outlive the link that was sent for it.
+pub async fn clear_expired_exports(app_state: &AppState) {
+    match PgDataExportRepository::new(&app_state.db_client)
+        .clear_expired_exports(Utc::now())
+        .await
+    {
+        Ok(0) => {}
+        Ok(cleared) => info!(cleared, "Cleared expired data exports"),
+        Err(e) => error!(error = %e, "Failed to clear expired data exports"),
+    }
+}
@@ -0,0 +1,53 @@
+use minijinja::{Value, context};
+
+use crate::{
+    AppState,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    helpers::mail::renderer::{EMAIL_TEMPLATES, RenderedMail, TemplateSource, sample_context},
+};
+
+/// Checks that `name` is an email the application sends and maps `locale` to
+/// the spelling its templates use.
+pub fn template_locale(
+    app_state: &AppState,
+    name: &str,
+    locale: &str,
+) -> Result<String, HttpError> {
+    if !EMAIL_TEMPLATES.contains(&name) {
+        return Err(HttpError::bad_request(
+            ErrorMessage::EmailTemplateNotFound.to_string(),
+        ));
+    }
+
+    app_state
+        .mail_renderer()
+        .supported_locale(locale)
+        .map(str::to_string)
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::UnsupportedLocale.to_string()))
+}
+
+/// Sample data for `name`, with any values the caller supplied taking
+/// precedence.
+pub fn preview_context(name: &str, data: Option<&serde_json::Value>) -> Value {
+    let sample = sample_context(name).unwrap_or_default();
+
+    match data {
+        Some(data) => context! { ..Value::from_serialize(data), ..sample },
+        None => sample,
+    }
+}
+
+/// Renders `source` with sample data, so a broken edit is rejected when it is
+/// saved rather than when the next email goes out.
+pub fn check_template_source(
+    app_state: &AppState,
+    name: &str,
+    locale: &str,
+    source: TemplateSource<'_>,
+    data: Option<&serde_json::Value>,
+) -> Result<RenderedMail, HttpError> {
+    app_state
+        .mail_renderer()
+        .render_source(name, locale, source, &preview_context(name, data))
+        .map_err(|e| HttpError::bad_request(format!("Template does not render: {}", e)))
+}
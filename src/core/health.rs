@@ -0,0 +1,109 @@
+use std::{future::Future, time::Instant};
+
+use tracing::{debug, warn};
+
+use crate::{AppState, models::health::response::HealthCheck};
+
+use super::migration::migration_status;
+
+/// Runs every readiness check concurrently. The server is ready when none of
+/// them failed.
+pub async fn check_readiness(app_state: &AppState) -> Vec<HealthCheck> {
+    let (database, migrations, mail) = tokio::join!(
+        check_database(app_state),
+        check_migrations(app_state),
+        check_mail(app_state),
+    );
+
+    vec![check_shutdown(app_state), database, migrations, mail]
+}
+
+fn check_shutdown(app_state: &AppState) -> HealthCheck {
+    if app_state.shutdown.is_draining() {
+        HealthCheck::fail("shutdown", 0)
+    } else {
+        HealthCheck::pass("shutdown", 0)
+    }
+}
+
+async fn check_database(app_state: &AppState) -> HealthCheck {
+    timed(app_state, "database", async {
+        sqlx::query("SELECT 1")
+            .execute(&app_state.db_client.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let pool = &app_state.db_client.pool;
+        Ok(Some(format!(
+            "{} connections, {} idle",
+            pool.size(),
+            pool.num_idle()
+        )))
+    })
+    .await
+}
+
+async fn check_migrations(app_state: &AppState) -> HealthCheck {
+    timed(app_state, "migrations", async {
+        let status = migration_status(app_state)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if status.is_current() {
+            Ok(Some(status.summary()))
+        } else {
+            Err(status.summary())
+        }
+    })
+    .await
+}
+
+async fn check_mail(app_state: &AppState) -> HealthCheck {
+    if !app_state.config().health.check_mail {
+        return HealthCheck::skip("mail");
+    }
+
+    timed(app_state, "mail", async {
+        app_state
+            .mailer
+            .check()
+            .await
+            .map(|()| None)
+            .map_err(|e| e.to_string())
+    })
+    .await
+}
+
+/// Runs `check` under the configured timeout. `Ok` carries an optional note
+/// and `Err` the reason the check failed; both go to the log, not the
+/// response.
+async fn timed<F>(app_state: &AppState, name: &str, check: F) -> HealthCheck
+where
+    F: Future<Output = Result<Option<String>, String>>,
+{
+    let timeout = app_state.config().health.timeout;
+    let started = Instant::now();
+
+    let result = tokio::time::timeout(timeout, check).await;
+    let duration_ms = started.elapsed().as_millis();
+
+    match result {
+        Ok(Ok(note)) => {
+            debug!(check = name, duration_ms, note, "Readiness check passed");
+            HealthCheck::pass(name, duration_ms)
+        }
+        Ok(Err(error)) => {
+            warn!(check = name, duration_ms, error, "Readiness check failed");
+            HealthCheck::fail(name, duration_ms)
+        }
+        Err(_) => {
+            warn!(
+                check = name,
+                duration_ms,
+                timeout_ms = timeout.as_millis(),
+                "Readiness check timed out"
+            );
+            HealthCheck::fail(name, duration_ms)
+        }
+    }
+}
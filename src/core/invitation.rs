@@ -0,0 +1,123 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    core::job::enqueue_email,
+    domains::{
+        invitation::{Invitation, Invitee},
+        job::EmailJob,
+        user::User,
+    },
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::{
+        invitation::{
+            invitation_impl::PgInvitationRepository, invitation_trait::InvitationRepository,
+        },
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+    utils::token,
+};
+
+pub const INVITATION_PURPOSE: &str = "invitation";
+
+pub fn invitation_expiry() -> DateTime<Utc> {
+    Utc::now() + Duration::days(7)
+}
+
+pub fn new_invitation_nonce() -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// Signs a link for `invitation` and mails it to the invitee.
+pub async fn deliver_invitation(
+    app_state: &AppState,
+    invitation: &Invitation,
+    inviter: &str,
+) -> Result<(), HttpError> {
+    let token = token::generate_signed_link_token(
+        &invitation.id.to_string(),
+        INVITATION_PURPOSE,
+        &invitation.token_nonce,
+        app_state.config().jwt.secret.as_bytes(),
+        invitation.expires_at,
+    )
+    .map_err(|e| HttpError::server_error(e.to_string()))?;
+
+    let target = match invitation.organization_id {
+        Some(organization_id) => PgOrganizationRepository::new(&app_state.db_client)
+            .get_organization(organization_id)
+            .await
+            .map_err(|e| HttpError::server_error(e.to_string()))?
+            .map(|organization| organization.name)
+            .unwrap_or_else(|| "the organization".to_string()),
+        None => "the application".to_string(),
+    };
+
+    let invite_link = app_state.config().urls.invitation_link(&token);
+
+    enqueue_email(
+        app_state,
+        EmailJob::Invitation {
+            to: invitation.email.clone(),
+            locale: None,
+            invite_link,
+            inviter: inviter.to_string(),
+            target,
+        },
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Resolves a signed invitation link to a pending, unexpired invitation whose
+/// nonce still matches the one in the link.
+pub async fn find_usable_invitation(
+    app_state: &AppState,
+    token: &str,
+) -> Result<Invitation, HttpError> {
+    let claims = token::decode_signed_link_token(
+        token,
+        INVITATION_PURPOSE,
+        app_state.config().jwt.secret.as_bytes(),
+    )?;
+
+    let invitation_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| HttpError::bad_request(ErrorMessage::InvalidToken.to_string()))?;
+
+    let invitation = PgInvitationRepository::new(&app_state.db_client)
+        .get_invitation(invitation_id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::InvitationNotFound.to_string()))?;
+
+    if invitation.token_nonce != claims.nonce || !invitation.is_usable() {
+        return Err(HttpError::bad_request(
+            ErrorMessage::InvitationExpired.to_string(),
+        ));
+    }
+
+    Ok(invitation)
+}
+
+/// Redeems `invitation` for `invitee`, creating the account first when the
+/// invitee has none. Fails when someone else redeemed it in the meantime.
+pub async fn complete_invitation(
+    app_state: &AppState,
+    invitation: &Invitation,
+    invitee: Invitee<'_>,
+) -> Result<User, HttpError> {
+    PgInvitationRepository::new(&app_state.db_client)
+        .redeem_invitation(invitation, invitee)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                HttpError::unique_constraint_violation(ErrorMessage::EmailExist.to_string())
+            }
+            e => HttpError::server_error(e.to_string()),
+        })?
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::InvitationExpired.to_string()))
+}
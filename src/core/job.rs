@@ -0,0 +1,271 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use tracing::{error, field::Empty, info_span, warn};
+
+use crate::{
+    AppState,
+    core::telemetry::TraceContext,
+    domains::job::{EmailJob, Job, JobPayload},
+    helpers::mail::mails::{
+        send_account_approved_email, send_account_deletion_email, send_account_rejected_email,
+        send_data_export_email, send_forgot_password_email, send_invitation_email,
+        send_pending_approval_email, send_verification_email, send_welcome_email,
+    },
+    infrastructure::{
+        job::{job_impl::PgJobRepository, job_trait::JobRepository},
+        mail::mail_trait::MailResult,
+    },
+    utils::backoff::Backoff,
+};
+
+/// 15s, 30s, 1m, ... capped at an hour.
+pub const RETRY_BACKOFF: Backoff = Backoff::new(15, 60 * 60);
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const CLAIM_LEASE_SECONDS: i64 = 300;
+
+pub async fn enqueue_job(app_state: &AppState, payload: JobPayload) -> Result<Job, sqlx::Error> {
+    schedule_job(app_state, payload, Utc::now()).await
+}
+
+/// Queues `payload` to run no earlier than `run_at`. The worker that runs it
+/// continues the trace of the request queuing it.
+pub async fn schedule_job(
+    app_state: &AppState,
+    payload: JobPayload,
+    run_at: DateTime<Utc>,
+) -> Result<Job, sqlx::Error> {
+    let value = serde_json::to_value(&payload).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+    let trace = TraceContext::current();
+
+    PgJobRepository::new(&app_state.db_client)
+        .enqueue_job(
+            payload.kind(),
+            &value,
+            run_at,
+            app_state.config().jobs.max_attempts,
+            trace.request_id.as_deref(),
+            trace.traceparent.as_deref(),
+        )
+        .await
+}
+
+/// Queues an email. Only a database failure can lose it here; SMTP trouble is
+/// retried by the workers.
+pub async fn enqueue_email(app_state: &AppState, email: EmailJob) {
+    if let Err(e) = enqueue_job(app_state, JobPayload::SendEmail(email)).await {
+        error!(error = %e, "Failed to queue email");
+    }
+}
+
+/// Each worker finishes the job it is running when shutdown starts, then
+/// stops; unclaimed jobs stay queued for the next process.
+pub fn spawn_job_workers(app_state: Arc<AppState>) {
+    for _ in 0..app_state.config().jobs.workers {
+        let app_state = app_state.clone();
+        app_state.shutdown.clone().spawn(async move {
+            while !app_state.shutdown.is_draining() {
+                if !run_next_job(&app_state).await && !app_state.shutdown.sleep(POLL_INTERVAL).await
+                {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+/// Claims and runs a single job. Returns `false` when the queue had nothing
+/// due, so the caller can back off.
+pub async fn run_next_job(app_state: &Arc<AppState>) -> bool {
+    let repository = PgJobRepository::new(&app_state.db_client);
+
+    let now = Utc::now();
+    let job = match repository
+        .claim_due_jobs(now, now + chrono::Duration::seconds(CLAIM_LEASE_SECONDS), 1)
+        .await
+    {
+        Ok(mut jobs) => match jobs.pop() {
+            Some(job) => job,
+            None => return false,
+        },
+        Err(e) => {
+            error!(error = %e, "Failed to claim jobs");
+            return false;
+        }
+    };
+
+    let recorded = match serde_json::from_value::<JobPayload>(job.payload.0.clone()) {
+        // A payload this build cannot read will never succeed, so skip the
+        // retries and dead-letter it straight away.
+        Err(e) => {
+            repository
+                .mark_job_failed(job.id, &format!("Unreadable payload: {}", e), None)
+                .await
+        }
+        Ok(payload) => {
+            // What stays in the table once the job succeeds: the links and
+            // tokens it carried are no use to anyone after they were sent.
+            let redacted = serde_json::to_value(payload.redacted()).unwrap_or_default();
+
+            // Run in its own task so a panicking job is recorded as a failed
+            // attempt instead of taking the worker down with it.
+            let trace = TraceContext {
+                request_id: job.request_id.clone(),
+                traceparent: job.traceparent.clone(),
+            };
+            let span = info_span!(
+                "job",
+                otel.name = %job.kind,
+                otel.kind = "consumer",
+                job.id = %job.id,
+                job.attempt = job.attempts,
+                request_id = Empty,
+            );
+            let task = trace.run(span, run_job(app_state.clone(), payload));
+
+            let result = match tokio::spawn(task).await {
+                Ok(result) => result,
+                Err(e) => Err(format!("Job panicked: {}", e)),
+            };
+
+            match result {
+                Ok(()) => repository.mark_job_succeeded(job.id, &redacted).await,
+                Err(error) => {
+                    let retry_at = (job.attempts < job.max_attempts)
+                        .then(|| Utc::now() + RETRY_BACKOFF.delay(job.attempts));
+                    if retry_at.is_none() {
+                        warn!(
+                            job.id = %job.id,
+                            job.kind = %job.kind,
+                            job.attempts = job.attempts,
+                            error = %error,
+                            "Job is dead after its last attempt"
+                        );
+                    }
+                    repository.mark_job_failed(job.id, &error, retry_at).await
+                }
+            }
+        }
+    };
+
+    if let Err(e) = recorded {
+        error!(job.id = %job.id, error = %e, "Failed to record job");
+    }
+
+    true
+}
+
+async fn run_job(app_state: Arc<AppState>, payload: JobPayload) -> Result<(), String> {
+    match payload {
+        JobPayload::SendEmail(email) => send_email_job(&app_state, email)
+            .await
+            .map_err(|e| e.to_string()),
+    }
+}
+
+async fn send_email_job(app_state: &AppState, email: EmailJob) -> MailResult {
+    match email {
+        EmailJob::Verification {
+            to,
+            locale,
+            username,
+            token,
+            return_to,
+        } => {
+            send_verification_email(
+                app_state,
+                &to,
+                locale.as_deref(),
+                &username,
+                &token,
+                return_to.as_deref(),
+            )
+            .await
+        }
+        EmailJob::Welcome {
+            to,
+            locale,
+            username,
+        } => send_welcome_email(app_state, &to, locale.as_deref(), &username).await,
+        EmailJob::ForgotPassword {
+            to,
+            locale,
+            username,
+            reset_link,
+        } => {
+            send_forgot_password_email(app_state, &to, locale.as_deref(), &reset_link, &username)
+                .await
+        }
+        EmailJob::Invitation {
+            to,
+            locale,
+            invite_link,
+            inviter,
+            target,
+        } => {
+            send_invitation_email(
+                app_state,
+                &to,
+                locale.as_deref(),
+                &invite_link,
+                &inviter,
+                &target,
+            )
+            .await
+        }
+        EmailJob::PendingApproval {
+            to,
+            locale,
+            admin_name,
+            applicant_name,
+            applicant_email,
+        } => {
+            send_pending_approval_email(
+                app_state,
+                &to,
+                locale.as_deref(),
+                &admin_name,
+                &applicant_name,
+                &applicant_email,
+            )
+            .await
+        }
+        EmailJob::AccountApproved {
+            to,
+            locale,
+            username,
+        } => send_account_approved_email(app_state, &to, locale.as_deref(), &username).await,
+        EmailJob::AccountRejected {
+            to,
+            locale,
+            username,
+        } => send_account_rejected_email(app_state, &to, locale.as_deref(), &username).await,
+        EmailJob::AccountDeletion {
+            to,
+            locale,
+            username,
+            cancel_link,
+            deletion_date,
+        } => {
+            send_account_deletion_email(
+                app_state,
+                &to,
+                locale.as_deref(),
+                &username,
+                &cancel_link,
+                &deletion_date,
+            )
+            .await
+        }
+        EmailJob::DataExport {
+            to,
+            locale,
+            username,
+            download_link,
+        } => {
+            send_data_export_email(app_state, &to, locale.as_deref(), &username, &download_link)
+                .await
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::{sync::Arc, time::Duration};
+
+use metrics::{counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+
+use crate::{
+    AppState,
+    domains::audit::{AuditAction, NewAuditEvent},
+};
+
+/// Histogram buckets, in seconds, for request latency and password hashing.
+const DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Installs the process-wide Prometheus recorder. Without it every metric
+/// below is a no-op.
+pub fn install_recorder() -> Result<PrometheusHandle, String> {
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), DURATION_BUCKETS)
+        .map_err(|e| e.to_string())?
+        .install_recorder()
+        .map_err(|e| e.to_string())?;
+
+    describe_counter!("http_requests_total", "HTTP requests by matched route");
+    describe_histogram!(
+        "http_request_duration_seconds",
+        "HTTP request latency by matched route"
+    );
+    describe_counter!("auth_login_successes_total", "Successful logins");
+    describe_counter!("auth_login_failures_total", "Failed logins by reason");
+    describe_counter!("auth_registrations_total", "Completed registrations");
+    describe_counter!("auth_email_verifications_total", "Verified email addresses");
+    describe_counter!(
+        "auth_password_resets_total",
+        "Password resets by stage, requested or completed"
+    );
+    describe_counter!("emails_sent_total", "Emails handed to the transport");
+    describe_counter!("emails_failed_total", "Emails that could not be sent");
+    describe_gauge!("db_pool_connections", "Postgres pool connections by state");
+    describe_gauge!("db_pool_max_connections", "Postgres pool size limit");
+    describe_histogram!(
+        "password_hash_duration_seconds",
+        "Argon2 time by operation, hash or verify"
+    );
+
+    Ok(handle)
+}
+
+/// Folds buffered histogram samples into the exported buckets.
+pub fn spawn_metrics_upkeep(app_state: Arc<AppState>) {
+    let Some(handle) = app_state.metrics.clone() else {
+        return;
+    };
+
+    app_state.shutdown.clone().spawn(async move {
+        let mut interval = tokio::time::interval(UPKEEP_INTERVAL);
+        while app_state.shutdown.tick(&mut interval).await {
+            handle.run_upkeep();
+        }
+    });
+}
+
+/// Everything recorded so far in the Prometheus text format, with the pool
+/// gauges sampled at the time of the scrape.
+pub fn render_metrics(app_state: &AppState) -> Option<String> {
+    let handle = app_state.metrics.as_ref()?;
+
+    let pool = &app_state.db_client.pool;
+    let idle = pool.num_idle() as f64;
+    gauge!("db_pool_connections", "state" => "idle").set(idle);
+    gauge!("db_pool_connections", "state" => "active").set(pool.size() as f64 - idle);
+    gauge!("db_pool_max_connections").set(pool.options().get_max_connections() as f64);
+
+    Some(handle.render())
+}
+
+pub fn record_http_request(method: &str, route: &str, status: u16, elapsed: Duration) {
+    let labels = [
+        ("method", method.to_string()),
+        ("route", route.to_string()),
+        ("status", status.to_string()),
+    ];
+    counter!("http_requests_total", &labels).increment(1);
+    histogram!("http_request_duration_seconds", &labels).record(elapsed.as_secs_f64());
+}
+
+/// Counts the audit events that mark an auth outcome.
+pub fn record_auth_event(event: &NewAuditEvent) {
+    match event.action {
+        AuditAction::LoginSucceeded => counter!("auth_login_successes_total").increment(1),
+        AuditAction::LoginFailed => {
+            let reason = event
+                .metadata
+                .get("reason")
+                .and_then(|reason| reason.as_str())
+                .unwrap_or("unknown")
+                .to_string();
+            counter!("auth_login_failures_total", "reason" => reason).increment(1)
+        }
+        AuditAction::Registered => counter!("auth_registrations_total").increment(1),
+        AuditAction::EmailVerified => counter!("auth_email_verifications_total").increment(1),
+        AuditAction::PasswordReset => {
+            counter!("auth_password_resets_total", "stage" => "completed").increment(1)
+        }
+        _ => {}
+    }
+}
+
+pub fn record_password_reset_requested() {
+    counter!("auth_password_resets_total", "stage" => "requested").increment(1);
+}
+
+pub fn record_email(template: &str, sent: bool) {
+    let template = template.to_string();
+    if sent {
+        counter!("emails_sent_total", "template" => template).increment(1);
+    } else {
+        counter!("emails_failed_total", "template" => template).increment(1);
+    }
+}
+
+pub fn record_password_hash(operation: &'static str, elapsed: Duration) {
+    histogram!("password_hash_duration_seconds", "operation" => operation)
+        .record(elapsed.as_secs_f64());
+}
@@ -0,0 +1,156 @@
+use tracing::info;
+
+use crate::{
+    AppState,
+    config::database::MigrationMode,
+    infrastructure::{
+        database::migrations::MIGRATOR,
+        migration::{migration_impl::PgMigrationRepository, migration_trait::MigrationRepository},
+    },
+};
+
+/// How the database schema compares with the migrations built into this
+/// binary, by migration version.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationStatus {
+    pub applied: usize,
+    /// Built in but not applied yet.
+    pub pending: Vec<i64>,
+    /// Started but did not finish; the schema needs a manual look.
+    pub failed: Vec<i64>,
+    /// Applied from a file that has since been edited.
+    pub modified: Vec<i64>,
+    /// Applied by a newer build. Expected while a deploy rolls out.
+    pub unknown: Vec<i64>,
+}
+
+impl MigrationStatus {
+    /// Whether this build can safely run against the schema.
+    pub fn is_current(&self) -> bool {
+        self.pending.is_empty() && self.failed.is_empty() && self.modified.is_empty()
+    }
+
+    /// Whether the schema holds migrations from a build this one does not
+    /// know while some of its own are still pending. Applying them would mix
+    /// two lines of history, so neither verify nor apply accepts it.
+    pub fn has_diverged(&self) -> bool {
+        !self.pending.is_empty() && !self.unknown.is_empty()
+    }
+
+    pub fn summary(&self) -> String {
+        let mut parts = vec![format!("{} applied", self.applied)];
+        for (label, versions) in [
+            ("pending", &self.pending),
+            ("failed", &self.failed),
+            ("modified", &self.modified),
+            ("unknown", &self.unknown),
+        ] {
+            if !versions.is_empty() {
+                let versions: Vec<String> = versions.iter().map(i64::to_string).collect();
+                parts.push(format!(
+                    "{} {} ({})",
+                    versions.len(),
+                    label,
+                    versions.join(", ")
+                ));
+            }
+        }
+        parts.join(", ")
+    }
+}
+
+pub async fn migration_status(app_state: &AppState) -> Result<MigrationStatus, sqlx::Error> {
+    let applied = PgMigrationRepository::new(&app_state.db_client)
+        .get_applied_migrations()
+        .await?;
+
+    let expected: Vec<_> = MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .collect();
+
+    let mut status = MigrationStatus::default();
+
+    for migration in &expected {
+        match applied.iter().find(|row| row.version == migration.version) {
+            None => status.pending.push(migration.version),
+            Some(row) if !row.success => status.failed.push(row.version),
+            Some(row) if row.checksum != *migration.checksum => status.modified.push(row.version),
+            Some(_) => status.applied += 1,
+        }
+    }
+
+    status.unknown = applied
+        .iter()
+        .filter(|row| !expected.iter().any(|m| m.version == row.version))
+        .map(|row| row.version)
+        .collect();
+
+    Ok(status)
+}
+
+/// Applies the pending migrations. sqlx holds a Postgres advisory lock for
+/// the whole run, so a replica starting at the same time waits for it and
+/// then finds nothing left to do. A schema with a failed or edited migration
+/// is left alone. Returns the status afterwards.
+pub async fn apply_migrations(app_state: &AppState) -> Result<MigrationStatus, String> {
+    let status = migration_status(app_state)
+        .await
+        .map_err(|e| format!("Failed to read the migration status: {}", e))?;
+
+    if !status.failed.is_empty() || !status.modified.is_empty() {
+        return Err(format!(
+            "The schema needs a manual look before migrating: {}",
+            status.summary()
+        ));
+    }
+    if status.has_diverged() {
+        return Err(diverged_error(&status));
+    }
+    // Skipped when nothing is pending, so a build older than the schema can
+    // still start during a rolling deploy.
+    if status.pending.is_empty() {
+        return Ok(status);
+    }
+
+    MIGRATOR
+        .run(&app_state.db_client.pool)
+        .await
+        .map_err(|e| format!("Failed to apply migrations: {}", e))?;
+
+    migration_status(app_state)
+        .await
+        .map_err(|e| format!("Failed to read the migration status: {}", e))
+}
+
+/// Brings the schema in line with `database.migrations` before the server
+/// takes traffic. An error means the server must not start.
+pub async fn prepare_schema(app_state: &AppState) -> Result<(), String> {
+    let status = match app_state.config().database.migrations {
+        MigrationMode::Off => return Ok(()),
+        MigrationMode::Verify => migration_status(app_state)
+            .await
+            .map_err(|e| format!("Failed to read the migration status: {}", e))?,
+        MigrationMode::Apply => apply_migrations(app_state).await?,
+    };
+
+    if status.has_diverged() {
+        return Err(diverged_error(&status));
+    }
+    if !status.is_current() {
+        return Err(format!(
+            "The database schema is behind this build: {}. Apply the migrations, or set database.migrations = \"apply\"",
+            status.summary()
+        ));
+    }
+
+    info!(status = %status.summary(), "Database schema is current");
+    Ok(())
+}
+
+fn diverged_error(status: &MigrationStatus) -> String {
+    format!(
+        "The database schema was migrated by a build this one does not know, and this build's own migrations are still pending: {}. Deploy a build that includes every applied migration",
+        status.summary()
+    )
+}
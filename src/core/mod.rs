@@ -0,0 +1,16 @@
+pub mod account_deletion;
+pub mod account_status;
+pub mod audit;
+pub mod audit_chain;
+pub mod config_reload;
+pub mod data_export;
+pub mod email_template;
+pub mod health;
+pub mod invitation;
+pub mod job;
+pub mod metrics;
+pub mod migration;
+pub mod redirect;
+pub mod shutdown;
+pub mod telemetry;
+pub mod webhook;
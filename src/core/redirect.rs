@@ -0,0 +1,21 @@
+use crate::{
+    AppState,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+};
+
+/// Checks an optional `return_to` against the allowed origins, so our links
+/// and redirects cannot be used to bounce users to another site.
+pub fn resolve_return_to(
+    app_state: &AppState,
+    return_to: Option<&str>,
+) -> Result<Option<String>, HttpError> {
+    return_to
+        .map(|return_to| {
+            app_state
+                .config()
+                .urls
+                .allowed_return_to(return_to)
+                .ok_or_else(|| HttpError::bad_request(ErrorMessage::ReturnToNotAllowed.to_string()))
+        })
+        .transpose()
+}
@@ -0,0 +1,82 @@
+use std::{future::Future, time::Duration};
+
+use tokio::{
+    signal::unix::{SignalKind, signal},
+    time::Interval,
+};
+use tokio_util::{sync::CancellationToken, task::TaskTracker};
+
+/// Tells background tasks that the server is going down and keeps track of
+/// them so shutdown can wait for the work they have in hand.
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    token: CancellationToken,
+    tasks: TaskTracker,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts draining: readiness fails from now on and workers stop picking
+    /// up new work.
+    pub fn begin(&self) {
+        self.token.cancel();
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    pub async fn draining(&self) {
+        self.token.cancelled().await
+    }
+
+    /// Runs `task` in the background; shutdown waits for it to finish.
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.tasks.spawn(task);
+    }
+
+    /// Waits for the next tick, or returns `false` once draining has started.
+    pub async fn tick(&self, interval: &mut Interval) -> bool {
+        tokio::select! {
+            _ = self.token.cancelled() => false,
+            _ = interval.tick() => true,
+        }
+    }
+
+    /// Sleeps for `duration`, or returns `false` early once draining has
+    /// started.
+    pub async fn sleep(&self, duration: Duration) -> bool {
+        tokio::select! {
+            _ = self.token.cancelled() => false,
+            _ = tokio::time::sleep(duration) => true,
+        }
+    }
+
+    /// Waits until every task spawned through [`spawn`](Self::spawn) has
+    /// returned. No new tasks are accepted afterwards.
+    pub async fn wait_for_tasks(&self) {
+        self.tasks.close();
+        self.tasks.wait().await
+    }
+
+    pub fn running_tasks(&self) -> usize {
+        self.tasks.len()
+    }
+}
+
+/// Resolves on the first SIGTERM or SIGINT, with the signal's name.
+pub async fn shutdown_signal() -> &'static str {
+    let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+    let mut interrupt = signal(SignalKind::interrupt()).expect("Failed to listen for SIGINT");
+
+    tokio::select! {
+        _ = terminate.recv() => "SIGTERM",
+        _ = interrupt.recv() => "SIGINT",
+    }
+}
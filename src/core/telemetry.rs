@@ -0,0 +1,150 @@
+use std::{collections::HashMap, future::Future};
+
+use opentelemetry::{global, trace::TracerProvider};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    Resource,
+    propagation::TraceContextPropagator,
+    trace::{Sampler, SdkTracerProvider},
+};
+use tracing::{Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+use uuid::Uuid;
+
+use crate::config::{
+    logging::{LogFormat, LoggingConfig},
+    tracing::TracingConfig,
+};
+
+pub const TRACEPARENT: &str = "traceparent";
+
+/// Longest `X-Request-Id` taken from a client; anything else gets a fresh id.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Sets up logging and, when a collector is configured, span export over
+/// OTLP. The returned provider has to be shut down on exit so the last
+/// batch of spans is flushed.
+pub fn init_tracing(
+    logging: &LoggingConfig,
+    config: &TracingConfig,
+) -> Result<Option<SdkTracerProvider>, String> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let filter = EnvFilter::try_new(&logging.level).map_err(|e| e.to_string())?;
+    // JSON events carry the fields of the span they happened in, request_id
+    // included.
+    let (json, text) = match logging.format {
+        LogFormat::Json => (
+            Some(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .flatten_event(true)
+                    .with_current_span(true)
+                    .with_span_list(false),
+            ),
+            None,
+        ),
+        LogFormat::Text => (None, Some(tracing_subscriber::fmt::layer())),
+    };
+
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(json)
+        .with(text);
+
+    let Some(endpoint) = &config.otlp_endpoint else {
+        registry.init();
+        return Ok(None);
+    };
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sample_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build();
+
+    let tracer = provider.tracer(env!("CARGO_PKG_NAME"));
+    registry
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .init();
+    global::set_tracer_provider(provider.clone());
+
+    Ok(Some(provider))
+}
+
+/// The id of the request being handled, if any.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/// A client-supplied request id is kept only when it is short and printable,
+/// since it ends up in logs and response headers.
+pub fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id.bytes().all(|byte| byte.is_ascii_graphic())
+}
+
+pub fn new_request_id() -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// Where a unit of work came from: the request id and W3C `traceparent` to
+/// continue. Captured when a request queues work so the worker that picks it
+/// up later shows up in the same trace.
+#[derive(Debug, Clone, Default)]
+pub struct TraceContext {
+    pub request_id: Option<String>,
+    pub traceparent: Option<String>,
+}
+
+impl TraceContext {
+    /// The context of the request and span running right now.
+    pub fn current() -> Self {
+        let mut carrier = HashMap::new();
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&Span::current().context(), &mut carrier)
+        });
+
+        Self {
+            request_id: current_request_id(),
+            traceparent: carrier.remove(TRACEPARENT),
+        }
+    }
+
+    /// Runs `future` inside `span`, as a child of the captured trace and with
+    /// the captured request id. `span` needs an empty `request_id` field.
+    pub async fn run<F: Future>(self, span: Span, future: F) -> F::Output {
+        if let Some(traceparent) = self.traceparent {
+            let carrier = HashMap::from([(TRACEPARENT.to_string(), traceparent)]);
+            let parent = global::get_text_map_propagator(|propagator| propagator.extract(&carrier));
+            // Fails when span export is off, and then there is no trace to join.
+            let _ = span.set_parent(parent);
+        }
+
+        match self.request_id {
+            Some(request_id) => {
+                span.record("request_id", request_id.as_str());
+                REQUEST_ID.scope(request_id, future.instrument(span)).await
+            }
+            None => future.instrument(span).await,
+        }
+    }
+}
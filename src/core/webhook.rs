@@ -0,0 +1,230 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    domains::{
+        user::User,
+        webhook::{DueWebhookDelivery, WebhookEvent},
+    },
+    infrastructure::webhook::{
+        webhook_impl::PgWebhookRepository, webhook_trait::WebhookRepository,
+    },
+    models::user::response::FilterUser,
+    utils::backoff::Backoff,
+};
+
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+pub const DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+
+/// Attempts per delivery before it is marked failed. With the backoff below
+/// the last retry happens a little over four hours after the event.
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 10;
+
+/// 30s, 1m, 2m, ... capped at six hours.
+pub const RETRY_BACKOFF: Backoff = Backoff::new(30, 6 * 60 * 60);
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const CLAIM_LEASE_SECONDS: i64 = 120;
+const CLAIM_BATCH_SIZE: i64 = 20;
+
+#[derive(Debug)]
+pub struct DeliveryError {
+    pub response_status: Option<u16>,
+    pub message: String,
+}
+
+/// `sha256=<hex>` over `<timestamp>.<body>`. Receivers recompute it with the
+/// endpoint secret and should reject stale timestamps.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+pub fn new_webhook_secret() -> String {
+    format!(
+        "whsec_{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Posts one signed delivery. Any 2xx response counts as delivered.
+pub async fn send_webhook(
+    client: &reqwest::Client,
+    url: &str,
+    secret: &str,
+    delivery_id: Uuid,
+    event_type: &str,
+    payload: &Value,
+) -> Result<u16, DeliveryError> {
+    let body = serde_json::to_vec(payload).map_err(|e| DeliveryError {
+        response_status: None,
+        message: e.to_string(),
+    })?;
+    let timestamp = Utc::now().timestamp();
+
+    let response = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, sign_payload(secret, timestamp, &body))
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(EVENT_HEADER, event_type)
+        .header(DELIVERY_HEADER, delivery_id.to_string())
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| DeliveryError {
+            response_status: None,
+            message: e.to_string(),
+        })?;
+
+    let status = response.status();
+    if status.is_success() {
+        Ok(status.as_u16())
+    } else {
+        Err(DeliveryError {
+            response_status: Some(status.as_u16()),
+            message: format!("Receiver responded with {}", status),
+        })
+    }
+}
+
+pub fn user_event_data(user: &User) -> Value {
+    json!({ "user": FilterUser::filter_user(user) })
+}
+
+/// Queues `event` for every subscribed endpoint. Delivery happens in the
+/// worker, so a slow receiver never holds up the request that caused it.
+pub async fn dispatch_event(app_state: &AppState, event: WebhookEvent, data: Value) {
+    let repository = PgWebhookRepository::new(&app_state.db_client);
+
+    let endpoints = match repository.get_subscribed_endpoints(event.to_str()).await {
+        Ok(endpoints) => endpoints,
+        Err(e) => {
+            error!(error = %e, "Failed to load webhook endpoints");
+            return;
+        }
+    };
+
+    if endpoints.is_empty() {
+        return;
+    }
+
+    let event_id = Uuid::new_v4();
+    let payload = json!({
+        "id": event_id,
+        "type": event.to_str(),
+        "createdAt": Utc::now(),
+        "data": data,
+    });
+
+    for endpoint in endpoints {
+        if let Err(e) = repository
+            .enqueue_delivery(endpoint.id, event_id, event.to_str(), &payload)
+            .await
+        {
+            error!(
+                webhook.event = event.to_str(),
+                webhook.endpoint_id = %endpoint.id,
+                error = %e,
+                "Failed to queue webhook"
+            );
+        }
+    }
+}
+
+pub fn spawn_webhook_worker(app_state: Arc<AppState>) {
+    app_state.shutdown.clone().spawn(async move {
+        let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+            Ok(client) => client,
+            Err(e) => {
+                error!(error = %e, "Failed to build webhook client");
+                return;
+            }
+        };
+
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        while app_state.shutdown.tick(&mut interval).await {
+            deliver_due_webhooks(&app_state, &client).await;
+        }
+    });
+}
+
+pub async fn deliver_due_webhooks(app_state: &AppState, client: &reqwest::Client) {
+    let repository = PgWebhookRepository::new(&app_state.db_client);
+
+    let now = Utc::now();
+    let deliveries = match repository
+        .claim_due_deliveries(
+            now,
+            now + chrono::Duration::seconds(CLAIM_LEASE_SECONDS),
+            CLAIM_BATCH_SIZE,
+        )
+        .await
+    {
+        Ok(deliveries) => deliveries,
+        Err(e) => {
+            error!(error = %e, "Failed to claim webhook deliveries");
+            return;
+        }
+    };
+
+    for delivery in deliveries {
+        attempt_delivery(&repository, client, delivery).await;
+    }
+}
+
+async fn attempt_delivery(
+    repository: &PgWebhookRepository<'_>,
+    client: &reqwest::Client,
+    delivery: DueWebhookDelivery,
+) {
+    let result = send_webhook(
+        client,
+        &delivery.url,
+        &delivery.secret,
+        delivery.id,
+        &delivery.event_type,
+        &delivery.payload.0,
+    )
+    .await;
+
+    let recorded = match result {
+        Ok(status) => {
+            repository
+                .mark_delivery_succeeded(delivery.id, status as i32)
+                .await
+        }
+        Err(e) => {
+            let attempts = delivery.attempts + 1;
+            let retry_at = (attempts < MAX_DELIVERY_ATTEMPTS)
+                .then(|| Utc::now() + RETRY_BACKOFF.delay(attempts));
+            repository
+                .mark_delivery_failed(
+                    delivery.id,
+                    e.response_status.map(i32::from),
+                    &e.message,
+                    retry_at,
+                )
+                .await
+        }
+    };
+
+    if let Err(e) = recorded {
+        error!(webhook.delivery_id = %delivery.id, error = %e, "Failed to record webhook delivery");
+    }
+}
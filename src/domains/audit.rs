@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{prelude::FromRow, types::Json};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum AuditAction {
+    LoginSucceeded,
+    LoginFailed,
+    Registered,
+    EmailVerified,
+    PasswordChanged,
+    PasswordReset,
+    RoleChanged,
+    InvitationAccepted,
+    DeletionRequested,
+    DeletionCancelled,
+    DataExportRequested,
+    AccountLocked,
+    AdminEmailTemplateActivated,
+    AdminEmailTemplateReverted,
+    AdminEmailTemplateUpdated,
+    AdminInvitationCreated,
+    AdminInvitationResent,
+    AdminInvitationRevoked,
+    AdminJobRetried,
+    AdminUserApproved,
+    AdminUserLocked,
+    AdminUserRejected,
+    AdminUserSuspended,
+    AdminUserUnsuspended,
+    AdminWebhookCreated,
+    AdminWebhookDeleted,
+    AdminWebhookRedelivered,
+}
+
+impl AuditAction {
+    pub fn to_str(&self) -> &str {
+        match self {
+            AuditAction::LoginSucceeded => "auth.login_succeeded",
+            AuditAction::LoginFailed => "auth.login_failed",
+            AuditAction::Registered => "auth.registered",
+            AuditAction::EmailVerified => "auth.email_verified",
+            AuditAction::PasswordChanged => "user.password_changed",
+            AuditAction::PasswordReset => "auth.password_reset",
+            AuditAction::RoleChanged => "user.role_changed",
+            AuditAction::InvitationAccepted => "auth.invitation_accepted",
+            AuditAction::DeletionRequested => "user.deletion_requested",
+            AuditAction::DeletionCancelled => "user.deletion_cancelled",
+            AuditAction::DataExportRequested => "user.data_export_requested",
+            AuditAction::AccountLocked => "auth.account_locked",
+            AuditAction::AdminEmailTemplateActivated => "admin.email_template_activated",
+            AuditAction::AdminEmailTemplateReverted => "admin.email_template_reverted",
+            AuditAction::AdminEmailTemplateUpdated => "admin.email_template_updated",
+            AuditAction::AdminInvitationCreated => "admin.invitation_created",
+            AuditAction::AdminInvitationResent => "admin.invitation_resent",
+            AuditAction::AdminInvitationRevoked => "admin.invitation_revoked",
+            AuditAction::AdminJobRetried => "admin.job_retried",
+            AuditAction::AdminUserApproved => "admin.user_approved",
+            AuditAction::AdminUserLocked => "admin.user_locked",
+            AuditAction::AdminUserRejected => "admin.user_rejected",
+            AuditAction::AdminUserSuspended => "admin.user_suspended",
+            AuditAction::AdminUserUnsuspended => "admin.user_unsuspended",
+            AuditAction::AdminWebhookCreated => "admin.webhook_created",
+            AuditAction::AdminWebhookDeleted => "admin.webhook_deleted",
+            AuditAction::AdminWebhookRedelivered => "admin.webhook_redelivered",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub sequence: i64,
+    pub actor_id: Option<Uuid>,
+    pub target_user_id: Option<Uuid>,
+    pub action: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub metadata: Json<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+/// An event as hashed by the chain. `metadata_text` is Postgres' own rendering
+/// of the JSONB column, which is what the insert trigger hashed.
+#[derive(Debug, FromRow, Clone)]
+pub struct AuditChainLink {
+    pub id: Uuid,
+    pub sequence: i64,
+    pub actor_id: Option<Uuid>,
+    pub target_user_id: Option<Uuid>,
+    pub action: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub metadata_text: String,
+    pub created_at: DateTime<Utc>,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct AuditCheckpoint {
+    pub id: Uuid,
+    pub sequence: i64,
+    pub hash: String,
+    pub event_count: i64,
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAuditEvent {
+    pub action: AuditAction,
+    pub actor_id: Option<Uuid>,
+    pub target_user_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub metadata: serde_json::Value,
+}
+
+/// Filters for browsing the audit log. `before` is the sequence cursor
+/// returned by the previous page.
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub before: Option<i64>,
+}
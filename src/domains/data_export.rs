@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{
+    prelude::{FromRow, Type},
+    types::Json,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "data_export_status", rename_all = "lowercase")]
+pub enum DataExportStatus {
+    Pending,
+    Ready,
+    Failed,
+}
+
+impl DataExportStatus {
+    pub fn to_str(&self) -> &str {
+        match self {
+            DataExportStatus::Pending => "pending",
+            DataExportStatus::Ready => "ready",
+            DataExportStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct DataExport {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: DataExportStatus,
+    pub payload: Option<Json<serde_json::Value>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+/// One saved version of an admin override for a built-in email in one
+/// locale. Only the `active` version is used when sending.
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct EmailTemplate {
+    pub id: Uuid,
+    pub name: String,
+    pub locale: String,
+    pub version: i32,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+    pub active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::{FromRow, Type};
+use uuid::Uuid;
+
+use super::{organization::OrganizationRole, user::UserRole};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "invitation_status", rename_all = "lowercase")]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    Revoked,
+}
+
+impl InvitationStatus {
+    pub fn to_str(&self) -> &str {
+        match self {
+            InvitationStatus::Pending => "pending",
+            InvitationStatus::Accepted => "accepted",
+            InvitationStatus::Revoked => "revoked",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct Invitation {
+    pub id: Uuid,
+    pub email: String,
+    pub role: UserRole,
+    pub organization_id: Option<Uuid>,
+    pub organization_role: Option<OrganizationRole>,
+    pub token_nonce: String,
+    pub status: InvitationStatus,
+    pub invited_by: Option<Uuid>,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    pub fn is_usable(&self) -> bool {
+        self.status == InvitationStatus::Pending && self.expires_at > Utc::now()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewInvitation {
+    pub email: String,
+    pub role: UserRole,
+    pub organization_id: Option<Uuid>,
+    pub organization_role: Option<OrganizationRole>,
+    pub invited_by: Uuid,
+    pub token_nonce: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Who redeems an invitation: someone who already has an account, or a new
+/// account to be created for the invited address.
+#[derive(Debug, Clone, Copy)]
+pub enum Invitee<'a> {
+    Existing(Uuid),
+    New {
+        name: &'a str,
+        password: &'a str,
+        locale: Option<&'a str>,
+    },
+}
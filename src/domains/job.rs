@@ -0,0 +1,209 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{
+    prelude::{FromRow, Type},
+    types::Json,
+};
+use uuid::Uuid;
+
+use crate::utils::redact::REDACTED;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "job_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Succeeded,
+    /// Out of attempts, or the payload could not be understood. Stays in the
+    /// table until an admin retries it.
+    Dead,
+}
+
+impl JobStatus {
+    pub fn to_str(&self) -> &str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::Dead => "dead",
+        }
+    }
+}
+
+/// Everything the workers know how to run. Stored as JSON in `jobs.payload`,
+/// so renaming a variant or field strands jobs that are already queued.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", content = "data")]
+pub enum JobPayload {
+    #[serde(rename = "email.send")]
+    SendEmail(EmailJob),
+}
+
+impl JobPayload {
+    pub fn kind(&self) -> &str {
+        match self {
+            JobPayload::SendEmail(_) => "email.send",
+        }
+    }
+
+    /// Who the job is for, shown to admins instead of the payload.
+    pub fn recipient(&self) -> &str {
+        match self {
+            JobPayload::SendEmail(email) => email.recipient(),
+        }
+    }
+
+    /// A line describing the job without any of its secrets.
+    pub fn summary(&self) -> String {
+        match self {
+            JobPayload::SendEmail(email) => format!("{} email", email.template()),
+        }
+    }
+
+    /// This payload with its tokens and links blanked out, which is all that
+    /// is kept once the job has run.
+    pub fn redacted(&self) -> Self {
+        match self {
+            JobPayload::SendEmail(email) => JobPayload::SendEmail(email.redacted()),
+        }
+    }
+}
+
+/// One variant per email template. `locale` picks the translation; jobs
+/// queued before it existed decode with the default locale.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(tag = "template", rename_all = "snake_case")]
+pub enum EmailJob {
+    Verification {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        username: String,
+        token: String,
+        /// Where to send the user once the address is verified.
+        #[serde(default)]
+        return_to: Option<String>,
+    },
+    Welcome {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        username: String,
+    },
+    ForgotPassword {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        username: String,
+        reset_link: String,
+    },
+    Invitation {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        invite_link: String,
+        inviter: String,
+        target: String,
+    },
+    PendingApproval {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        admin_name: String,
+        applicant_name: String,
+        applicant_email: String,
+    },
+    AccountApproved {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        username: String,
+    },
+    AccountRejected {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        username: String,
+    },
+    AccountDeletion {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        username: String,
+        cancel_link: String,
+        deletion_date: String,
+    },
+    DataExport {
+        to: String,
+        #[serde(default)]
+        locale: Option<String>,
+        username: String,
+        download_link: String,
+    },
+}
+
+impl EmailJob {
+    pub fn recipient(&self) -> &str {
+        match self {
+            EmailJob::Verification { to, .. }
+            | EmailJob::Welcome { to, .. }
+            | EmailJob::ForgotPassword { to, .. }
+            | EmailJob::Invitation { to, .. }
+            | EmailJob::PendingApproval { to, .. }
+            | EmailJob::AccountApproved { to, .. }
+            | EmailJob::AccountRejected { to, .. }
+            | EmailJob::AccountDeletion { to, .. }
+            | EmailJob::DataExport { to, .. } => to,
+        }
+    }
+
+    pub fn template(&self) -> &str {
+        match self {
+            EmailJob::Verification { .. } => "verification",
+            EmailJob::Welcome { .. } => "welcome",
+            EmailJob::ForgotPassword { .. } => "forgot_password",
+            EmailJob::Invitation { .. } => "invitation",
+            EmailJob::PendingApproval { .. } => "pending_approval",
+            EmailJob::AccountApproved { .. } => "account_approved",
+            EmailJob::AccountRejected { .. } => "account_rejected",
+            EmailJob::AccountDeletion { .. } => "account_deletion",
+            EmailJob::DataExport { .. } => "data_export",
+        }
+    }
+
+    fn redacted(&self) -> Self {
+        let mut email = self.clone();
+        match &mut email {
+            EmailJob::Verification { token, .. } => *token = REDACTED.to_string(),
+            EmailJob::ForgotPassword { reset_link, .. } => *reset_link = REDACTED.to_string(),
+            EmailJob::Invitation { invite_link, .. } => *invite_link = REDACTED.to_string(),
+            EmailJob::AccountDeletion { cancel_link, .. } => *cancel_link = REDACTED.to_string(),
+            EmailJob::DataExport { download_link, .. } => *download_link = REDACTED.to_string(),
+            EmailJob::Welcome { .. }
+            | EmailJob::PendingApproval { .. }
+            | EmailJob::AccountApproved { .. }
+            | EmailJob::AccountRejected { .. } => {}
+        }
+        email
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct Job {
+    pub id: Uuid,
+    pub kind: String,
+    pub payload: Json<serde_json::Value>,
+    pub status: JobStatus,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Request that queued the job, so its work can be traced back to it.
+    pub request_id: Option<String>,
+    pub traceparent: Option<String>,
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A rendered message on its way to a transport.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct OutgoingMail {
+    pub id: Uuid,
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+    pub created_at: DateTime<Utc>,
+}
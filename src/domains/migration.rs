@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use sqlx::prelude::FromRow;
+
+/// A row of `_sqlx_migrations`.
+#[derive(Debug, Clone, FromRow)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: String,
+    pub installed_on: DateTime<Utc>,
+    pub success: bool,
+    pub checksum: Vec<u8>,
+}
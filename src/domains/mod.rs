@@ -1 +1,10 @@
+pub mod audit;
+pub mod data_export;
+pub mod email_template;
+pub mod invitation;
+pub mod job;
+pub mod mail;
+pub mod migration;
+pub mod organization;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "organization_role", rename_all = "lowercase")]
+pub enum OrganizationRole {
+    Owner,
+    Admin,
+    Member,
+}
+
+impl OrganizationRole {
+    pub fn to_str(&self) -> &str {
+        match self {
+            OrganizationRole::Owner => "owner",
+            OrganizationRole::Admin => "admin",
+            OrganizationRole::Member => "member",
+        }
+    }
+
+    /// Whether a member with this role may grant `role` to someone, or change
+    /// the membership of someone who currently holds it.
+    pub fn can_assign(&self, role: OrganizationRole) -> bool {
+        match self {
+            OrganizationRole::Owner => true,
+            OrganizationRole::Admin => role != OrganizationRole::Owner,
+            OrganizationRole::Member => false,
+        }
+    }
+}
+
+/// How a change to someone's membership went. Decided while the organization
+/// is locked, so concurrent changes cannot leave it without an owner.
+#[derive(Debug, Clone)]
+pub enum MembershipChange {
+    /// The membership as it was after an update, or before a removal.
+    Applied(Membership),
+    NotFound,
+    /// The acting member may not touch this membership or grant this role.
+    Forbidden,
+    /// The change would leave the organization without an owner.
+    LastOwner,
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct Membership {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub role: OrganizationRole,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// An organization seen from one of its members, with that member's role.
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct UserOrganization {
+    pub id: Uuid,
+    pub name: String,
+    pub role: OrganizationRole,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A membership joined with the member's public user fields.
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct OrganizationMember {
+    pub user_id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub role: OrganizationRole,
+    pub created_at: DateTime<Utc>,
+}
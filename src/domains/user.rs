@@ -17,6 +17,110 @@ impl UserRole {
             UserRole::User => "user",
         }
     }
+
+    /// The higher of the two roles. Invitations use this so that they can
+    /// grant a role but never take one away.
+    pub fn raised_to(self, role: UserRole) -> UserRole {
+        match (self, role) {
+            (UserRole::Admin, _) | (_, UserRole::Admin) => UserRole::Admin,
+            _ => UserRole::User,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "approval_status", rename_all = "lowercase")]
+pub enum ApprovalStatus {
+    Approved,
+    Pending,
+    Rejected,
+}
+
+impl ApprovalStatus {
+    pub fn to_str(&self) -> &str {
+        match self {
+            ApprovalStatus::Approved => "approved",
+            ApprovalStatus::Pending => "pending",
+            ApprovalStatus::Rejected => "rejected",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "account_status", rename_all = "snake_case")]
+pub enum AccountStatus {
+    PendingVerification,
+    Active,
+    Suspended,
+    Locked,
+    PendingDeletion,
+    /// Anonymized once its grace period ended. Nothing leaves this status.
+    Deleted,
+}
+
+impl AccountStatus {
+    pub fn to_str(&self) -> &str {
+        match self {
+            AccountStatus::PendingVerification => "pending_verification",
+            AccountStatus::Active => "active",
+            AccountStatus::Suspended => "suspended",
+            AccountStatus::Locked => "locked",
+            AccountStatus::PendingDeletion => "pending_deletion",
+            AccountStatus::Deleted => "deleted",
+        }
+    }
+
+    /// The allowed edges of the account lifecycle. Anything not listed here is
+    /// rejected before it reaches the database.
+    pub fn can_transition_to(&self, next: AccountStatus) -> bool {
+        use AccountStatus::*;
+
+        matches!(
+            (self, next),
+            (PendingVerification, Active)
+                | (PendingVerification, Suspended)
+                | (PendingVerification, PendingDeletion)
+                | (Active, Suspended)
+                | (Active, Locked)
+                | (Active, PendingDeletion)
+                | (Suspended, Active)
+                | (Locked, Active)
+                | (Locked, Suspended)
+                | (PendingDeletion, Active)
+                | (PendingDeletion, PendingVerification)
+                | (PendingDeletion, Deleted)
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct StatusTransition {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub from_status: AccountStatus,
+    pub to_status: AccountStatus,
+    pub reason: Option<String>,
+    pub actor_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A requested move to `to`. `suspended_until` only applies to suspensions.
+#[derive(Debug, Clone)]
+pub struct StatusChange {
+    pub to: AccountStatus,
+    pub reason: Option<String>,
+    pub suspended_until: Option<DateTime<Utc>>,
+    pub actor_id: Option<Uuid>,
+}
+
+/// Narrows a user listing; unset fields match everyone.
+#[derive(Debug, Clone, Default)]
+pub struct UserFilter {
+    pub role: Option<UserRole>,
+    pub status: Option<AccountStatus>,
+    pub approval_status: Option<ApprovalStatus>,
+    /// Matched case-insensitively against the name and email.
+    pub search: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
@@ -26,9 +130,15 @@ pub struct User {
     pub email: String,
     pub password: String,
     pub role: UserRole,
-    pub verified: bool,
+    pub status: AccountStatus,
+    pub suspended_reason: Option<String>,
+    pub suspended_until: Option<DateTime<Utc>>,
+    pub approval_status: ApprovalStatus,
+    pub locale: Option<String>,
     pub verification_token: Option<String>,
     pub token_expires_at: Option<DateTime<Utc>>,
+    pub deletion_scheduled_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{
+    prelude::{FromRow, Type},
+    types::Json,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum WebhookEvent {
+    #[serde(rename = "user.registered")]
+    UserRegistered,
+    #[serde(rename = "user.verified")]
+    UserVerified,
+    #[serde(rename = "user.password_changed")]
+    UserPasswordChanged,
+    #[serde(rename = "user.role_changed")]
+    UserRoleChanged,
+    #[serde(rename = "user.deleted")]
+    UserDeleted,
+}
+
+impl WebhookEvent {
+    pub fn to_str(&self) -> &str {
+        match self {
+            WebhookEvent::UserRegistered => "user.registered",
+            WebhookEvent::UserVerified => "user.verified",
+            WebhookEvent::UserPasswordChanged => "user.password_changed",
+            WebhookEvent::UserRoleChanged => "user.role_changed",
+            WebhookEvent::UserDeleted => "user.deleted",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "lowercase")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn to_str(&self) -> &str {
+        match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Succeeded => "succeeded",
+            WebhookDeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+/// A subscriber. An empty `events` list receives every event.
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct WebhookEndpoint {
+    pub id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub endpoint_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub payload: Json<serde_json::Value>,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// A delivery that was claimed by the worker, joined with where it goes.
+#[derive(Debug, FromRow, Clone)]
+pub struct DueWebhookDelivery {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub payload: Json<serde_json::Value>,
+    pub attempts: i32,
+    pub url: String,
+    pub secret: String,
+}
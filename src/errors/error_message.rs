@@ -14,6 +14,34 @@ pub enum ErrorMessage {
     // User management
     EmailExist,
     UserNoLongerExist,
+    AccountPendingApproval,
+    AccountRejected,
+    AccountPendingDeletion,
+    EmailNotVerified,
+    AccountSuspended,
+    AccountLocked,
+    InvalidStatusTransition,
+    ReauthenticationRequired,
+    UnsupportedLocale,
+    ReturnToNotAllowed,
+    EmailTemplateNotFound,
+
+    // Registration
+    RegistrationInviteOnly,
+    EmailDomainNotAllowed,
+    InvitationEmailMismatch,
+
+    // Organizations
+    NoActiveOrganization,
+    NotOrganizationMember,
+    MemberAlreadyExists,
+    MemberNotFound,
+    LastOrganizationOwner,
+    OrganizationNotFound,
+
+    // Invitations
+    InvitationNotFound,
+    InvitationExpired,
 
     // System
     HashingError,
@@ -33,9 +61,39 @@ impl fmt::Display for ErrorMessage {
             ErrorMessage::WrongCredentials => "Wrong email or password",
             ErrorMessage::EmailExist => "Email already exists",
             ErrorMessage::UserNoLongerExist => "User no longer exists",
+            ErrorMessage::AccountPendingApproval => "Your account is awaiting approval",
+            ErrorMessage::AccountRejected => "Your registration was not approved",
+            ErrorMessage::AccountPendingDeletion => {
+                "Your account is scheduled for deletion. Use the link in your email to cancel it"
+            }
+            ErrorMessage::EmailNotVerified => "Please verify your email address before signing in",
+            ErrorMessage::AccountSuspended => "Your account has been suspended",
+            ErrorMessage::AccountLocked => "Your account is locked. Contact support to unlock it",
+            ErrorMessage::InvalidStatusTransition => {
+                "This status change is not allowed for the account"
+            }
+            ErrorMessage::ReauthenticationRequired => "Please re-enter your password to continue",
+            ErrorMessage::UnsupportedLocale => "This locale is not supported",
+            ErrorMessage::ReturnToNotAllowed => "return_to must point to an allowed origin",
+            ErrorMessage::EmailTemplateNotFound => "Email template not found",
+            ErrorMessage::RegistrationInviteOnly => "Registration requires an invitation",
+            ErrorMessage::EmailDomainNotAllowed => {
+                "Registration is restricted to approved email domains"
+            }
+            ErrorMessage::InvitationEmailMismatch => {
+                "This invitation was issued for a different email address"
+            }
             ErrorMessage::TokenNotProvided => "Token was not provided",
             ErrorMessage::PermissionDenied => "You do not have permission to perform this action",
             ErrorMessage::UserNotAuthenticated => "User is not authenticated",
+            ErrorMessage::NoActiveOrganization => "No active organization selected",
+            ErrorMessage::NotOrganizationMember => "You are not a member of this organization",
+            ErrorMessage::MemberAlreadyExists => "User is already a member of this organization",
+            ErrorMessage::MemberNotFound => "Member not found in this organization",
+            ErrorMessage::LastOrganizationOwner => "An organization must keep at least one owner",
+            ErrorMessage::OrganizationNotFound => "Organization not found",
+            ErrorMessage::InvitationNotFound => "Invitation not found",
+            ErrorMessage::InvitationExpired => "Invitation is no longer valid",
         };
         write!(f, "{}", message)
     }
@@ -8,10 +8,15 @@ use serde::Serialize;
 use serde_json::json;
 use validator::ValidationErrors;
 
+use crate::core::telemetry::current_request_id;
+
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
     pub status: String,
     pub message: String,
+    /// Lets a client quote the failing request when reporting it.
+    #[serde(rename = "requestId", skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 impl fmt::Display for ErrorResponse {
@@ -77,6 +82,7 @@ impl HttpError {
         let json_response = Json(ErrorResponse {
             status: "fail".to_string(),
             message: self.message.clone(),
+            request_id: current_request_id(),
         });
         (self.status, json_response).into_response()
     }
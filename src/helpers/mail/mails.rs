@@ -1,45 +1,115 @@
+use minijinja::context;
+
+use crate::{AppState, infrastructure::mail::mail_trait::MailResult};
+
 use super::sendmail::send_email;
 
-type MailResult = Result<(), Box<dyn std::error::Error>>;
+pub async fn send_verification_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    username: &str,
+    token: &str,
+    return_to: Option<&str>,
+) -> MailResult {
+    let verification_link = app_state.config().urls.verification_link(token, return_to);
+    let context = context! { username, verification_link };
+    send_email(app_state, to_email, "verification", locale, context).await
+}
 
-pub async fn send_verification_email(to_email: &str, username: &str, token: &str) -> MailResult {
-    let subject = "Email Verification";
-    let template_path = "src/helpers/mail/templates/Verification-email.html";
-    let base_url = "http://localhost:8000/api/auth/verify-email";
-    let verification_link = create_verification_link(base_url, token);
-    let placeholders = vec![
-        ("{{username}}".to_string(), username.to_string()),
-        ("{{verification_link}}".to_string(), verification_link),
-    ];
-    let _ = send_email(to_email, subject, template_path, &placeholders).await;
-    Ok(())
+pub async fn send_welcome_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    username: &str,
+) -> MailResult {
+    let context = context! { username };
+    send_email(app_state, to_email, "welcome", locale, context).await
 }
 
-fn create_verification_link(base_url: &str, token: &str) -> String {
-    format!("{}?token={}", base_url, token)
+pub async fn send_forgot_password_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    reset_link: &str,
+    username: &str,
+) -> MailResult {
+    let context = context! { username, reset_link };
+    send_email(app_state, to_email, "forgot_password", locale, context).await
 }
 
-pub async fn send_welcome_email(to_email: &str, username: &str) -> MailResult {
-    let subject = "Welcome to Application";
-    let template_path = "src/helpers/mail/templates/Welcome-email.html";
+pub async fn send_invitation_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    invite_link: &str,
+    inviter: &str,
+    target: &str,
+) -> MailResult {
+    let context = context! { inviter, target, invite_link };
+    send_email(app_state, to_email, "invitation", locale, context).await
+}
 
-    let placeholders = vec![("{{username}}".to_string(), username.to_string())];
-    let _ = send_email(to_email, subject, template_path, &placeholders).await;
-    Ok(())
+pub async fn send_pending_approval_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    admin_name: &str,
+    applicant_name: &str,
+    applicant_email: &str,
+) -> MailResult {
+    let review_link = app_state.config().urls.approvals_link();
+
+    let context = context! {
+        username => admin_name,
+        applicant_name,
+        applicant_email,
+        review_link,
+    };
+    send_email(app_state, to_email, "pending_approval", locale, context).await
 }
 
-pub async fn send_forgot_password_email(
+pub async fn send_account_approved_email(
+    app_state: &AppState,
     to_email: &str,
-    rest_link: &str,
+    locale: Option<&str>,
     username: &str,
 ) -> MailResult {
-    let subject = "Welcome to Application";
-    let template_path = "src/helpers/mail/templates/RestPassword-email.html";
+    let login_link = app_state.config().urls.login_link();
+
+    let context = context! { username, login_link };
+    send_email(app_state, to_email, "account_approved", locale, context).await
+}
+
+pub async fn send_account_rejected_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    username: &str,
+) -> MailResult {
+    let context = context! { username };
+    send_email(app_state, to_email, "account_rejected", locale, context).await
+}
 
-    let placeholders = vec![
-        ("{{username}}".to_string(), username.to_string()),
-        ("{{rest_link}}".to_string(), rest_link.to_string()),
-    ];
-    let _ = send_email(to_email, subject, template_path, &placeholders).await;
-    Ok(())
+pub async fn send_account_deletion_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    username: &str,
+    cancel_link: &str,
+    deletion_date: &str,
+) -> MailResult {
+    let context = context! { username, cancel_link, deletion_date };
+    send_email(app_state, to_email, "account_deletion", locale, context).await
+}
+
+pub async fn send_data_export_email(
+    app_state: &AppState,
+    to_email: &str,
+    locale: Option<&str>,
+    username: &str,
+    download_link: &str,
+) -> MailResult {
+    let context = context! { username, download_link };
+    send_email(app_state, to_email, "data_export", locale, context).await
 }
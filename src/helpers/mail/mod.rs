@@ -1,2 +1,3 @@
 pub mod mails;
+pub mod renderer;
 pub mod sendmail;
@@ -0,0 +1,338 @@
+use std::fs;
+
+use chrono::{Datelike, Utc};
+use include_dir::{Dir, include_dir};
+use minijinja::{Environment, ErrorKind, UndefinedBehavior, Value, context, path_loader};
+use tracing::warn;
+
+use crate::{config::email::MailConfig, domains::email_template::EmailTemplate};
+
+static EMBEDDED_TEMPLATES: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/helpers/mail/templates");
+
+/// Every email the application sends. Each one needs `<name>.subject.txt`,
+/// `<name>.html` and `<name>.txt` in the default locale; other locales may
+/// translate any subset and fall back for the rest.
+pub const EMAIL_TEMPLATES: &[&str] = &[
+    "verification",
+    "welcome",
+    "forgot_password",
+    "invitation",
+    "pending_approval",
+    "account_approved",
+    "account_rejected",
+    "account_deletion",
+    "data_export",
+];
+
+/// Directories next to the locales that hold shared building blocks.
+const SHARED_DIRS: &[&str] = &["layouts", "partials"];
+
+/// The three parts of one email in one locale, as template source.
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateSource<'a> {
+    pub subject: &'a str,
+    pub html_body: &'a str,
+    pub text_body: &'a str,
+}
+
+impl<'a> From<&'a EmailTemplate> for TemplateSource<'a> {
+    fn from(template: &'a EmailTemplate) -> Self {
+        Self {
+            subject: &template.subject,
+            html_body: &template.html_body,
+            text_body: &template.text_body,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedMail {
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+/// Renders emails with auto-escaping for `.html` templates. Templates come
+/// from the binary unless `mail.template_dir` points somewhere else.
+#[derive(Debug)]
+pub struct MailRenderer {
+    env: Environment<'static>,
+    locales: Vec<String>,
+    default_locale: String,
+}
+
+impl MailRenderer {
+    pub fn new(config: &MailConfig) -> Self {
+        Self::try_new(config).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like [`new`](Self::new), but reports unusable templates instead of
+    /// panicking, for config reloads that must leave the old ones in place.
+    pub fn try_new(config: &MailConfig) -> Result<Self, String> {
+        let mut env = Environment::new();
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+        env.set_trim_blocks(true);
+
+        let mut locales = match &config.template_dir {
+            Some(dir) => {
+                env.set_loader(path_loader(dir));
+                fs::read_dir(dir)
+                    .map_err(|e| format!("mail.template_dir {} is not readable: {}", dir, e))?
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .collect::<Vec<_>>()
+            }
+            None => {
+                env.set_loader(|name| {
+                    Ok(EMBEDDED_TEMPLATES
+                        .get_file(name)
+                        .and_then(|file| file.contents_utf8())
+                        .map(str::to_string))
+                });
+                EMBEDDED_TEMPLATES
+                    .dirs()
+                    .filter_map(|dir| dir.path().to_str().map(str::to_string))
+                    .collect::<Vec<_>>()
+            }
+        };
+        locales.retain(|locale| !SHARED_DIRS.contains(&locale.as_str()));
+        locales.sort();
+
+        let renderer = Self {
+            env,
+            locales,
+            default_locale: config.default_locale.clone(),
+        };
+        renderer.check_default_locale()?;
+        Ok(renderer)
+    }
+
+    /// Fails at startup rather than on the first send when the fallback
+    /// locale is missing or broken.
+    fn check_default_locale(&self) -> Result<(), String> {
+        if !self.locales.contains(&self.default_locale) {
+            return Err(format!(
+                "mail.default_locale {} has no templates (found: {})",
+                self.default_locale,
+                self.locales.join(", ")
+            ));
+        }
+
+        for name in EMAIL_TEMPLATES {
+            for part in template_parts(&self.default_locale, name) {
+                if let Err(e) = self.env.get_template(&part) {
+                    return Err(format!("Email template {} is unusable: {:#}", part, e));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn locales(&self) -> &[String] {
+        &self.locales
+    }
+
+    /// Maps a requested locale such as `vi-VN` to one that has templates,
+    /// trying the exact tag before the language alone.
+    pub fn supported_locale(&self, requested: &str) -> Option<&str> {
+        let normalized = requested.trim().replace('_', "-").to_lowercase();
+        let language = normalized.split('-').next().unwrap_or_default();
+
+        [normalized.as_str(), language]
+            .into_iter()
+            .find_map(|candidate| {
+                self.locales
+                    .iter()
+                    .find(|locale| locale.to_lowercase() == candidate)
+            })
+            .map(String::as_str)
+    }
+
+    /// Picks the first supported language from an `Accept-Language` header.
+    /// Clients list their preferences in order, so q-values are not weighed.
+    pub fn negotiate_locale(&self, accept_language: &str) -> Option<&str> {
+        accept_language
+            .split(',')
+            .filter_map(|entry| entry.split(';').next())
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty() && *tag != "*")
+            .find_map(|tag| self.supported_locale(tag))
+    }
+
+    pub fn resolve_locale(&self, requested: Option<&str>) -> &str {
+        requested
+            .and_then(|requested| self.supported_locale(requested))
+            .unwrap_or(&self.default_locale)
+    }
+
+    /// Renders the subject, HTML and text parts of `name`. A locale that does
+    /// not translate this email falls back to the default one.
+    pub fn render(
+        &self,
+        name: &str,
+        locale: Option<&str>,
+        context: Value,
+    ) -> Result<RenderedMail, minijinja::Error> {
+        self.render_with_overrides(name, locale, context, &[])
+    }
+
+    /// Like [`render`](Self::render), but an admin override for a locale wins
+    /// over the built-in template of that locale. An override that no longer
+    /// renders is logged and skipped so the email still goes out.
+    pub fn render_with_overrides(
+        &self,
+        name: &str,
+        locale: Option<&str>,
+        context: Value,
+        overrides: &[EmailTemplate],
+    ) -> Result<RenderedMail, minijinja::Error> {
+        let locale = self.resolve_locale(locale);
+        let context = context! { year => Utc::now().year(), ..context };
+
+        let render_locale = |locale: &str| {
+            let stored = overrides
+                .iter()
+                .find(|template| template.name == name && template.locale == locale);
+
+            if let Some(template) = stored {
+                match self.render_source(name, locale, template.into(), &context) {
+                    Ok(rendered) => return Ok(rendered),
+                    Err(e) => warn!(
+                        template.name = name,
+                        template.locale = locale,
+                        template.version = template.version,
+                        error = format!("{:#}", e),
+                        "Email template override failed to render, using the built-in one"
+                    ),
+                }
+            }
+
+            self.render_parts(locale, name, &context)
+        };
+
+        match render_locale(locale) {
+            Err(e) if e.kind() == ErrorKind::TemplateNotFound && locale != self.default_locale => {
+                render_locale(&self.default_locale)
+            }
+            result => result,
+        }
+    }
+
+    /// Renders template source that is not on disk, such as an admin's draft.
+    /// Layouts and partials resolve as they do for the built-in templates.
+    pub fn render_source(
+        &self,
+        name: &str,
+        locale: &str,
+        source: TemplateSource<'_>,
+        context: &Value,
+    ) -> Result<RenderedMail, minijinja::Error> {
+        // The names decide auto-escaping, so the HTML part must end in `.html`.
+        let [subject, html, text] = template_parts(locale, name);
+        let context = context! { year => Utc::now().year(), ..context.clone() };
+
+        Ok(RenderedMail {
+            subject: self
+                .env
+                .render_named_str(&subject, source.subject, &context)?
+                .trim()
+                .to_string(),
+            html_body: self
+                .env
+                .render_named_str(&html, source.html_body, &context)?,
+            text_body: self
+                .env
+                .render_named_str(&text, source.text_body, &context)?,
+        })
+    }
+
+    /// The source of the built-in template for `name` in `locale`, if that
+    /// locale translates it.
+    pub fn builtin_source(&self, name: &str, locale: &str) -> Option<[String; 3]> {
+        let [subject, html, text] = template_parts(locale, name);
+        let source = |part: &str| {
+            self.env
+                .get_template(part)
+                .ok()
+                .map(|template| template.source().to_string())
+        };
+
+        Some([source(&subject)?, source(&html)?, source(&text)?])
+    }
+
+    fn render_parts(
+        &self,
+        locale: &str,
+        name: &str,
+        context: &Value,
+    ) -> Result<RenderedMail, minijinja::Error> {
+        let [subject, html, text] = template_parts(locale, name);
+
+        Ok(RenderedMail {
+            subject: self
+                .env
+                .get_template(&subject)?
+                .render(context)?
+                .trim()
+                .to_string(),
+            html_body: self.env.get_template(&html)?.render(context)?,
+            text_body: self.env.get_template(&text)?.render(context)?,
+        })
+    }
+}
+
+fn template_parts(locale: &str, name: &str) -> [String; 3] {
+    [
+        format!("{}/{}.subject.txt", locale, name),
+        format!("{}/{}.html", locale, name),
+        format!("{}/{}.txt", locale, name),
+    ]
+}
+
+/// Placeholder values for every variable `name` uses, for previews and for
+/// checking admin edits before they are saved.
+pub fn sample_context(name: &str) -> Option<Value> {
+    let username = "Jane Doe";
+
+    let context = match name {
+        "verification" => context! {
+            username,
+            verification_link => "https://example.com/verify-email?token=sample-token",
+        },
+        "welcome" => context! { username },
+        "forgot_password" => context! {
+            username,
+            reset_link => "https://example.com/reset-password?token=sample-token",
+        },
+        "invitation" => context! {
+            inviter => "John Smith",
+            target => "Acme Inc.",
+            invite_link => "https://example.com/invitations/accept?token=sample-token",
+        },
+        "pending_approval" => context! {
+            username => "Admin",
+            applicant_name => username,
+            applicant_email => "jane@example.com",
+            review_link => "https://example.com/admin/approvals",
+        },
+        "account_approved" => context! {
+            username,
+            login_link => "https://example.com/login",
+        },
+        "account_rejected" => context! { username },
+        "account_deletion" => context! {
+            username,
+            cancel_link => "https://example.com/account/cancel-deletion",
+            deletion_date => "2030-01-31",
+        },
+        "data_export" => context! {
+            username,
+            download_link => "https://example.com/exports/sample",
+        },
+        _ => return None,
+    };
+
+    Some(context)
+}
@@ -1,47 +1,65 @@
-use lettre::{
-    Message, SmtpTransport, Transport,
-    message::{SinglePart, header},
-    transport::smtp::authentication::Credentials,
-};
-use tokio::fs;
+use chrono::Utc;
+use minijinja::Value;
+use tracing::{Instrument, info_span, instrument};
+use uuid::Uuid;
 
-use crate::config::email::MySMTP;
+use crate::{
+    AppState,
+    core::metrics::record_email,
+    domains::mail::OutgoingMail,
+    infrastructure::{
+        email_template::{
+            email_template_impl::PgEmailTemplateRepository,
+            email_template_trait::EmailTemplateRepository,
+        },
+        mail::mail_trait::MailResult,
+    },
+};
 
+/// Renders `template` in the recipient's locale, preferring any override an
+/// admin has saved, and hands it to the configured transport.
+#[instrument(skip_all, fields(mail.template = template, mail.locale = locale), err)]
 pub async fn send_email(
+    app_state: &AppState,
     to_email: &str,
-    subject: &str,
-    template_path: &str,
-    placeholders: &[(String, String)],
-) -> Result<(), Box<dyn std::error::Error>> {
-    let smtp_env = MySMTP::init();
-
-    let mut html_template: String = fs::read_to_string(template_path).await?;
+    template: &str,
+    locale: Option<&str>,
+    context: Value,
+) -> MailResult {
+    let result = render_and_send(app_state, to_email, template, locale, context).await;
+    record_email(template, result.is_ok());
+    result
+}
 
-    for (key, value) in placeholders {
-        html_template = html_template.replace(key, value);
-    }
+async fn render_and_send(
+    app_state: &AppState,
+    to_email: &str,
+    template: &str,
+    locale: Option<&str>,
+    context: Value,
+) -> MailResult {
+    let overrides = PgEmailTemplateRepository::new(&app_state.db_client)
+        .get_active_templates(template)
+        .await?;
 
-    let email = Message::builder()
-        .from(smtp_env.username.parse()?)
-        .to(to_email.parse()?)
-        .subject(subject)
-        .header(header::ContentType::TEXT_HTML)
-        .singlepart(
-            SinglePart::builder()
-                .header(header::ContentType::TEXT_HTML)
-                .body(html_template),
-        )?;
-    let creds = Credentials::new(smtp_env.username, smtp_env.password);
-    let mailer = SmtpTransport::starttls_relay(&smtp_env.server)?
-        .credentials(creds)
-        .port(smtp_env.port)
-        .build();
-    let result = mailer.send(&email);
+    let rendered = app_state
+        .mail_renderer()
+        .render_with_overrides(template, locale, context, &overrides)?;
 
-    match result {
-        Ok(_) => println!("Email sent succcessfully!"),
-        Err(e) => println!("Failed to send email: {:?}", e),
-    }
+    let mail = OutgoingMail {
+        id: Uuid::new_v4(),
+        from: app_state.config().mail.from_address.clone(),
+        to: to_email.to_string(),
+        subject: rendered.subject,
+        html_body: rendered.html_body,
+        text_body: rendered.text_body,
+        created_at: Utc::now(),
+    };
 
-    Ok(())
+    let config = app_state.config();
+    app_state
+        .mailer
+        .send(&mail)
+        .instrument(info_span!("mail_send", mail.transport = config.mail.transport.to_str(), mail.id = %mail.id))
+        .await
 }
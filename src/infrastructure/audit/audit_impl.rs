@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Pool, Postgres, types::Json};
+use uuid::Uuid;
+
+use crate::{
+    domains::audit::{AuditChainLink, AuditCheckpoint, AuditEvent, AuditFilter, NewAuditEvent},
+    infrastructure::database::database::DBClient,
+};
+
+use super::audit_trait::AuditRepository;
+
+pub struct PgAuditRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgAuditRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+}
+
+#[async_trait]
+impl<'a> AuditRepository for PgAuditRepository<'a> {
+    async fn append_event(&self, event: NewAuditEvent) -> Result<AuditEvent, sqlx::Error> {
+        sqlx::query_as::<_, AuditEvent>(
+            r#"INSERT INTO audit_events (id, actor_id, target_user_id, action, ip_address, user_agent, metadata, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(event.actor_id)
+        .bind(event.target_user_id)
+        .bind(event.action.to_str())
+        .bind(event.ip_address)
+        .bind(event.user_agent)
+        .bind(Json(event.metadata))
+        .bind(Utc::now())
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn get_events(
+        &self,
+        filter: &AuditFilter,
+        limit: i64,
+    ) -> Result<Vec<AuditEvent>, sqlx::Error> {
+        sqlx::query_as::<_, AuditEvent>(
+            r#"SELECT * FROM audit_events
+            WHERE ($1::uuid IS NULL OR actor_id = $1)
+                AND ($2::varchar IS NULL OR action = $2)
+                AND ($3::timestamptz IS NULL OR created_at >= $3)
+                AND ($4::timestamptz IS NULL OR created_at < $4)
+                AND ($5::bigint IS NULL OR sequence < $5)
+            ORDER BY sequence DESC
+            LIMIT $6
+            "#,
+        )
+        .bind(filter.actor_id)
+        .bind(filter.action.as_deref())
+        .bind(filter.from)
+        .bind(filter.to)
+        .bind(filter.before)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_events_for_user(&self, user_id: Uuid) -> Result<Vec<AuditEvent>, sqlx::Error> {
+        sqlx::query_as::<_, AuditEvent>(
+            r#"SELECT * FROM audit_events
+            WHERE actor_id = $1 OR target_user_id = $1
+            ORDER BY sequence ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_chain_links(
+        &self,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<AuditChainLink>, sqlx::Error> {
+        sqlx::query_as::<_, AuditChainLink>(
+            r#"SELECT id, sequence, actor_id, target_user_id, action, ip_address, user_agent,
+                metadata::text AS metadata_text, created_at, prev_hash, hash
+            FROM audit_events
+            WHERE sequence > $1
+            ORDER BY sequence ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(after)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_chain_head(&self) -> Result<Option<(i64, String, i64)>, sqlx::Error> {
+        sqlx::query_as(
+            r#"SELECT sequence, hash, (SELECT COUNT(*) FROM audit_events)
+            FROM audit_events
+            ORDER BY sequence DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    async fn save_checkpoint(
+        &self,
+        checkpoint: &AuditCheckpoint,
+    ) -> Result<AuditCheckpoint, sqlx::Error> {
+        sqlx::query_as::<_, AuditCheckpoint>(
+            r#"INSERT INTO audit_checkpoints (id, sequence, hash, event_count, signature, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(checkpoint.id)
+        .bind(checkpoint.sequence)
+        .bind(&checkpoint.hash)
+        .bind(checkpoint.event_count)
+        .bind(&checkpoint.signature)
+        .bind(checkpoint.created_at)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn get_checkpoint(
+        &self,
+        checkpoint_id: Uuid,
+    ) -> Result<Option<AuditCheckpoint>, sqlx::Error> {
+        sqlx::query_as::<_, AuditCheckpoint>("SELECT * FROM audit_checkpoints WHERE id = $1")
+            .bind(checkpoint_id)
+            .fetch_optional(self.pool)
+            .await
+    }
+
+    async fn get_latest_checkpoint(&self) -> Result<Option<AuditCheckpoint>, sqlx::Error> {
+        sqlx::query_as::<_, AuditCheckpoint>(
+            "SELECT * FROM audit_checkpoints ORDER BY sequence DESC, created_at DESC LIMIT 1",
+        )
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    async fn get_checkpoints(&self, limit: i64) -> Result<Vec<AuditCheckpoint>, sqlx::Error> {
+        sqlx::query_as::<_, AuditCheckpoint>(
+            "SELECT * FROM audit_checkpoints ORDER BY created_at DESC LIMIT $1",
+        )
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_all_checkpoints(&self) -> Result<Vec<AuditCheckpoint>, sqlx::Error> {
+        sqlx::query_as::<_, AuditCheckpoint>(
+            "SELECT * FROM audit_checkpoints ORDER BY sequence ASC",
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domains::audit::{
+    AuditChainLink, AuditCheckpoint, AuditEvent, AuditFilter, NewAuditEvent,
+};
+
+#[async_trait]
+pub trait AuditRepository {
+    async fn append_event(&self, event: NewAuditEvent) -> Result<AuditEvent, sqlx::Error>;
+
+    /// Newest first, at most `limit` events older than `filter.before`.
+    async fn get_events(
+        &self,
+        filter: &AuditFilter,
+        limit: i64,
+    ) -> Result<Vec<AuditEvent>, sqlx::Error>;
+
+    /// Every event the user performed or was the target of, oldest first.
+    async fn get_events_for_user(&self, user_id: Uuid) -> Result<Vec<AuditEvent>, sqlx::Error>;
+
+    /// Up to `limit` links with a sequence greater than `after`, in chain order.
+    async fn get_chain_links(
+        &self,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<AuditChainLink>, sqlx::Error>;
+
+    /// The newest event's sequence and hash together with the total event
+    /// count, or `None` while the log is empty.
+    async fn get_chain_head(&self) -> Result<Option<(i64, String, i64)>, sqlx::Error>;
+
+    async fn save_checkpoint(
+        &self,
+        checkpoint: &AuditCheckpoint,
+    ) -> Result<AuditCheckpoint, sqlx::Error>;
+
+    async fn get_checkpoint(
+        &self,
+        checkpoint_id: Uuid,
+    ) -> Result<Option<AuditCheckpoint>, sqlx::Error>;
+
+    async fn get_latest_checkpoint(&self) -> Result<Option<AuditCheckpoint>, sqlx::Error>;
+
+    /// Newest first.
+    async fn get_checkpoints(&self, limit: i64) -> Result<Vec<AuditCheckpoint>, sqlx::Error>;
+
+    async fn get_all_checkpoints(&self) -> Result<Vec<AuditCheckpoint>, sqlx::Error>;
+}
@@ -0,0 +1,2 @@
+pub mod audit_impl;
+pub mod audit_trait;
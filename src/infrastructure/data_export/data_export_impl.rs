@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres, types::Json};
+use uuid::Uuid;
+
+use crate::{
+    domains::data_export::{DataExport, DataExportStatus},
+    infrastructure::database::database::DBClient,
+};
+
+use super::data_export_trait::DataExportRepository;
+
+pub struct PgDataExportRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgDataExportRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+}
+
+#[async_trait]
+impl<'a> DataExportRepository for PgDataExportRepository<'a> {
+    async fn save_export(&self, user_id: Uuid) -> Result<DataExport, sqlx::Error> {
+        sqlx::query_as::<_, DataExport>(
+            r#"INSERT INTO data_exports (id, user_id, status, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(DataExportStatus::Pending)
+        .bind(Utc::now())
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn get_export(&self, export_id: Uuid) -> Result<Option<DataExport>, sqlx::Error> {
+        sqlx::query_as::<_, DataExport>("SELECT * FROM data_exports WHERE id = $1")
+            .bind(export_id)
+            .fetch_optional(self.pool)
+            .await
+    }
+
+    async fn get_pending_export(&self, user_id: Uuid) -> Result<Option<DataExport>, sqlx::Error> {
+        sqlx::query_as::<_, DataExport>(
+            "SELECT * FROM data_exports WHERE user_id = $1 AND status = 'pending' LIMIT 1",
+        )
+        .bind(user_id)
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    async fn complete_export(
+        &self,
+        export_id: Uuid,
+        payload: serde_json::Value,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"UPDATE data_exports SET status = 'ready', payload = $1, expires_at = $2, completed_at = $3
+            WHERE id = $4
+            "#,
+        )
+        .bind(Json(payload))
+        .bind(expires_at)
+        .bind(Utc::now())
+        .bind(export_id)
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn fail_export(&self, export_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE data_exports SET status = 'failed', completed_at = $1 WHERE id = $2")
+            .bind(Utc::now())
+            .bind(export_id)
+            .execute(self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn clear_expired_exports(&self, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE data_exports SET payload = NULL WHERE expires_at < $1 AND payload IS NOT NULL",
+        )
+        .bind(now)
+        .execute(self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domains::data_export::DataExport;
+
+#[async_trait]
+pub trait DataExportRepository {
+    async fn save_export(&self, user_id: Uuid) -> Result<DataExport, sqlx::Error>;
+
+    async fn get_export(&self, export_id: Uuid) -> Result<Option<DataExport>, sqlx::Error>;
+
+    async fn get_pending_export(&self, user_id: Uuid) -> Result<Option<DataExport>, sqlx::Error>;
+
+    async fn complete_export(
+        &self,
+        export_id: Uuid,
+        payload: serde_json::Value,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error>;
+
+    async fn fail_export(&self, export_id: Uuid) -> Result<(), sqlx::Error>;
+
+    /// Drops the payload of ready exports that expired before `now`. Returns
+    /// how many were cleared.
+    async fn clear_expired_exports(&self, now: DateTime<Utc>) -> Result<u64, sqlx::Error>;
+}
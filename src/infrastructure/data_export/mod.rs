@@ -0,0 +1,2 @@
+pub mod data_export_impl;
+pub mod data_export_trait;
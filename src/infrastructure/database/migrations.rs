@@ -0,0 +1,4 @@
+use sqlx::migrate::Migrator;
+
+/// The migrations this build expects, embedded from `migrations/`.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{domains::email_template::EmailTemplate, infrastructure::database::database::DBClient};
+
+use super::email_template_trait::EmailTemplateRepository;
+
+pub struct PgEmailTemplateRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgEmailTemplateRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+}
+
+#[async_trait]
+impl<'a> EmailTemplateRepository for PgEmailTemplateRepository<'a> {
+    async fn get_active_templates(&self, name: &str) -> Result<Vec<EmailTemplate>, sqlx::Error> {
+        sqlx::query_as::<_, EmailTemplate>(
+            "SELECT * FROM email_templates WHERE name = $1 AND active = TRUE",
+        )
+        .bind(name)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_all_active_templates(&self) -> Result<Vec<EmailTemplate>, sqlx::Error> {
+        sqlx::query_as::<_, EmailTemplate>(
+            "SELECT * FROM email_templates WHERE active = TRUE ORDER BY name, locale",
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_template_versions(
+        &self,
+        name: &str,
+        locale: &str,
+    ) -> Result<Vec<EmailTemplate>, sqlx::Error> {
+        sqlx::query_as::<_, EmailTemplate>(
+            "SELECT * FROM email_templates WHERE name = $1 AND locale = $2 ORDER BY version DESC",
+        )
+        .bind(name)
+        .bind(locale)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn save_template_version(
+        &self,
+        name: &str,
+        locale: &str,
+        subject: &str,
+        html_body: &str,
+        text_body: &str,
+        created_by: Uuid,
+    ) -> Result<EmailTemplate, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // Serializes concurrent saves of the same template so they cannot
+        // both claim the next version number.
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1 || '/' || $2))")
+            .bind(name)
+            .bind(locale)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("UPDATE email_templates SET active = FALSE WHERE name = $1 AND locale = $2 AND active = TRUE")
+            .bind(name)
+            .bind(locale)
+            .execute(&mut *tx)
+            .await?;
+
+        let template = sqlx::query_as::<_, EmailTemplate>(
+            r#"INSERT INTO email_templates (id, name, locale, version, subject, html_body, text_body, active, created_by, created_at)
+            VALUES ($1, $2, $3,
+                (SELECT COALESCE(MAX(version), 0) + 1 FROM email_templates WHERE name = $2 AND locale = $3),
+                $4, $5, $6, TRUE, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(name)
+        .bind(locale)
+        .bind(subject)
+        .bind(html_body)
+        .bind(text_body)
+        .bind(created_by)
+        .bind(Utc::now())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(template)
+    }
+
+    async fn activate_template_version(
+        &self,
+        name: &str,
+        locale: &str,
+        version: i32,
+    ) -> Result<Option<EmailTemplate>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1 || '/' || $2))")
+            .bind(name)
+            .bind(locale)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("UPDATE email_templates SET active = FALSE WHERE name = $1 AND locale = $2 AND active = TRUE AND version <> $3")
+            .bind(name)
+            .bind(locale)
+            .bind(version)
+            .execute(&mut *tx)
+            .await?;
+
+        let template = sqlx::query_as::<_, EmailTemplate>(
+            r#"UPDATE email_templates SET active = TRUE
+            WHERE name = $1 AND locale = $2 AND version = $3
+            RETURNING *
+            "#,
+        )
+        .bind(name)
+        .bind(locale)
+        .bind(version)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        // Leave the current version in place when the requested one does
+        // not exist.
+        if template.is_none() {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        tx.commit().await?;
+        Ok(template)
+    }
+
+    async fn deactivate_template(&self, name: &str, locale: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE email_templates SET active = FALSE WHERE name = $1 AND locale = $2 AND active = TRUE",
+        )
+        .bind(name)
+        .bind(locale)
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
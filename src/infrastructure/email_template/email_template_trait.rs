@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domains::email_template::EmailTemplate;
+
+#[async_trait]
+pub trait EmailTemplateRepository {
+    /// The active override of `name` in every locale that has one.
+    async fn get_active_templates(&self, name: &str) -> Result<Vec<EmailTemplate>, sqlx::Error>;
+
+    async fn get_all_active_templates(&self) -> Result<Vec<EmailTemplate>, sqlx::Error>;
+
+    /// Every saved version of `name` in `locale`, newest first.
+    async fn get_template_versions(
+        &self,
+        name: &str,
+        locale: &str,
+    ) -> Result<Vec<EmailTemplate>, sqlx::Error>;
+
+    /// Saves the next version and makes it the active one.
+    async fn save_template_version(
+        &self,
+        name: &str,
+        locale: &str,
+        subject: &str,
+        html_body: &str,
+        text_body: &str,
+        created_by: Uuid,
+    ) -> Result<EmailTemplate, sqlx::Error>;
+
+    /// Rolls back (or forward) to an existing version. Returns `None` when no
+    /// such version was saved.
+    async fn activate_template_version(
+        &self,
+        name: &str,
+        locale: &str,
+        version: i32,
+    ) -> Result<Option<EmailTemplate>, sqlx::Error>;
+
+    /// Stops using any override so the built-in template is sent again. The
+    /// saved versions are kept.
+    async fn deactivate_template(&self, name: &str, locale: &str) -> Result<bool, sqlx::Error>;
+}
@@ -0,0 +1,2 @@
+pub mod email_template_impl;
+pub mod email_template_trait;
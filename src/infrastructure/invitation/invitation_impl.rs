@@ -0,0 +1,211 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{
+    domains::{
+        invitation::{Invitation, InvitationStatus, Invitee, NewInvitation},
+        user::{AccountStatus, User},
+    },
+    infrastructure::database::database::DBClient,
+};
+
+use super::invitation_trait::InvitationRepository;
+
+pub struct PgInvitationRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgInvitationRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+}
+
+#[async_trait]
+impl<'a> InvitationRepository for PgInvitationRepository<'a> {
+    async fn save_invitation(&self, invitation: NewInvitation) -> Result<Invitation, sqlx::Error> {
+        sqlx::query_as::<_, Invitation>(
+            r#"INSERT INTO invitations ( id, email, role, organization_id, organization_role,
+                token_nonce, status, invited_by, expires_at, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(invitation.email)
+        .bind(invitation.role)
+        .bind(invitation.organization_id)
+        .bind(invitation.organization_role)
+        .bind(invitation.token_nonce)
+        .bind(InvitationStatus::Pending)
+        .bind(invitation.invited_by)
+        .bind(invitation.expires_at)
+        .bind(Utc::now())
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn get_invitation(&self, invitation_id: Uuid) -> Result<Option<Invitation>, sqlx::Error> {
+        sqlx::query_as::<_, Invitation>("SELECT * FROM invitations WHERE id = $1")
+            .bind(invitation_id)
+            .fetch_optional(self.pool)
+            .await
+    }
+
+    async fn get_invitations_for_email(&self, email: &str) -> Result<Vec<Invitation>, sqlx::Error> {
+        sqlx::query_as::<_, Invitation>(
+            "SELECT * FROM invitations WHERE lower(email) = lower($1) ORDER BY created_at ASC",
+        )
+        .bind(email)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_pending_invitations(
+        &self,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<Invitation>, sqlx::Error> {
+        let offset = (page.saturating_sub(1) as usize) * limit;
+
+        sqlx::query_as::<_, Invitation>(
+            "SELECT * FROM invitations WHERE status = 'pending' ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+        )
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_pending_invitation_count(&self) -> Result<i64, sqlx::Error> {
+        let row: (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM invitations WHERE status = 'pending'")
+                .fetch_one(self.pool)
+                .await?;
+        Ok(row.0)
+    }
+
+    async fn refresh_invitation(
+        &self,
+        invitation_id: Uuid,
+        token_nonce: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<Invitation>, sqlx::Error> {
+        sqlx::query_as::<_, Invitation>(
+            r#"UPDATE invitations SET token_nonce = $1, expires_at = $2, updated_at = $3
+            WHERE id = $4 AND status = 'pending'
+            RETURNING *
+            "#,
+        )
+        .bind(token_nonce)
+        .bind(expires_at)
+        .bind(Utc::now())
+        .bind(invitation_id)
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    async fn revoke_invitation(&self, invitation_id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE invitations SET status = 'revoked', updated_at = $1 WHERE id = $2 AND status = 'pending'",
+        )
+        .bind(Utc::now())
+        .bind(invitation_id)
+        .execute(self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn redeem_invitation(
+        &self,
+        invitation: &Invitation,
+        invitee: Invitee<'_>,
+    ) -> Result<Option<User>, sqlx::Error> {
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        // Claiming first means a second redemption of the same link finds
+        // nothing to claim, however the two requests interleave.
+        let claimed = sqlx::query_as::<_, Invitation>(
+            r#"UPDATE invitations SET status = 'accepted', accepted_at = $1, updated_at = $1
+            WHERE id = $2 AND status = 'pending' AND token_nonce = $3 AND expires_at > $1
+            RETURNING *
+            "#,
+        )
+        .bind(now)
+        .bind(invitation.id)
+        .bind(&invitation.token_nonce)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(invitation) = claimed else {
+            return Ok(None);
+        };
+
+        let mut user = match invitee {
+            Invitee::Existing(user_id) => {
+                sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1 FOR UPDATE")
+                    .bind(user_id)
+                    .fetch_one(&mut *tx)
+                    .await?
+            }
+            Invitee::New {
+                name,
+                password,
+                locale,
+            } => {
+                sqlx::query_as::<_, User>(
+                    r#"INSERT INTO users ( id, name, email, password, role, status, locale, created_at, updated_at )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $8)
+                    RETURNING *
+                    "#,
+                )
+                .bind(Uuid::new_v4())
+                .bind(name)
+                .bind(&invitation.email)
+                .bind(password)
+                .bind(invitation.role)
+                .bind(AccountStatus::Active)
+                .bind(locale)
+                .bind(now)
+                .fetch_one(&mut *tx)
+                .await?
+            }
+        };
+
+        match (invitation.organization_id, invitation.organization_role) {
+            (Some(organization_id), Some(role)) => {
+                sqlx::query(
+                    r#"INSERT INTO organization_memberships ( organization_id, user_id, role, created_at, updated_at )
+                    VALUES ($1, $2, $3, $4, $4)
+                    ON CONFLICT DO NOTHING
+                    "#,
+                )
+                .bind(organization_id)
+                .bind(user.id)
+                .bind(role)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+            _ => {
+                let role = user.role.raised_to(invitation.role);
+                if role != user.role {
+                    user = sqlx::query_as::<_, User>(
+                        "UPDATE users SET role = $1, updated_at = $2 WHERE id = $3 RETURNING *",
+                    )
+                    .bind(role)
+                    .bind(now)
+                    .bind(user.id)
+                    .fetch_one(&mut *tx)
+                    .await?;
+                }
+            }
+        }
+
+        tx.commit().await?;
+        Ok(Some(user))
+    }
+}
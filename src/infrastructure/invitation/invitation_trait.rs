@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domains::{
+    invitation::{Invitation, Invitee, NewInvitation},
+    user::User,
+};
+
+#[async_trait]
+pub trait InvitationRepository {
+    async fn save_invitation(&self, invitation: NewInvitation) -> Result<Invitation, sqlx::Error>;
+
+    async fn get_invitation(&self, invitation_id: Uuid) -> Result<Option<Invitation>, sqlx::Error>;
+
+    async fn get_invitations_for_email(&self, email: &str) -> Result<Vec<Invitation>, sqlx::Error>;
+
+    async fn get_pending_invitations(
+        &self,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<Invitation>, sqlx::Error>;
+
+    async fn get_pending_invitation_count(&self) -> Result<i64, sqlx::Error>;
+
+    /// Rotates the nonce of a pending invitation, which invalidates every link
+    /// sent for it so far.
+    async fn refresh_invitation(
+        &self,
+        invitation_id: Uuid,
+        token_nonce: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<Invitation>, sqlx::Error>;
+
+    async fn revoke_invitation(&self, invitation_id: Uuid) -> Result<bool, sqlx::Error>;
+
+    /// Claims a pending invitation and applies it in one transaction: creates
+    /// the account for a new invitee, adds the membership or raises the system
+    /// role, and marks the invitation accepted. `None` when the invitation was
+    /// used, revoked or rotated since `invitation` was read.
+    async fn redeem_invitation(
+        &self,
+        invitation: &Invitation,
+        invitee: Invitee<'_>,
+    ) -> Result<Option<User>, sqlx::Error>;
+}
@@ -0,0 +1,2 @@
+pub mod invitation_impl;
+pub mod invitation_trait;
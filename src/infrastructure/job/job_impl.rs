@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres, types::Json};
+use uuid::Uuid;
+
+use crate::{
+    domains::job::{Job, JobStatus},
+    infrastructure::database::database::DBClient,
+};
+
+use super::job_trait::JobRepository;
+
+pub struct PgJobRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgJobRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+}
+
+#[async_trait]
+impl<'a> JobRepository for PgJobRepository<'a> {
+    async fn enqueue_job(
+        &self,
+        kind: &str,
+        payload: &serde_json::Value,
+        run_at: DateTime<Utc>,
+        max_attempts: i32,
+        request_id: Option<&str>,
+        traceparent: Option<&str>,
+    ) -> Result<Job, sqlx::Error> {
+        sqlx::query_as::<_, Job>(
+            r#"INSERT INTO jobs (id, kind, payload, status, max_attempts, run_at, created_at, updated_at, request_id, traceparent)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(kind)
+        .bind(Json(payload))
+        .bind(JobStatus::Pending)
+        .bind(max_attempts)
+        .bind(run_at)
+        .bind(Utc::now())
+        .bind(request_id)
+        .bind(traceparent)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn claim_due_jobs(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"UPDATE jobs
+            SET status = $1, locked_until = NULL, updated_at = $2,
+                last_error = 'Lease expired during the last attempt'
+            WHERE id IN (
+                SELECT id FROM jobs
+                WHERE status = $3 AND locked_until <= $2 AND attempts >= max_attempts
+                FOR UPDATE SKIP LOCKED
+            )
+            "#,
+        )
+        .bind(JobStatus::Dead)
+        .bind(now)
+        .bind(JobStatus::Running)
+        .execute(&mut *tx)
+        .await?;
+
+        let jobs = sqlx::query_as::<_, Job>(
+            r#"UPDATE jobs
+            SET status = $1, attempts = attempts + 1, locked_until = $2, updated_at = $3
+            WHERE id IN (
+                SELECT id FROM jobs
+                WHERE (status = $4 AND run_at <= $3)
+                    OR (status = $1 AND locked_until <= $3 AND attempts < max_attempts)
+                ORDER BY run_at
+                LIMIT $5
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            "#,
+        )
+        .bind(JobStatus::Running)
+        .bind(lease_until)
+        .bind(now)
+        .bind(JobStatus::Pending)
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(jobs)
+    }
+
+    async fn mark_job_succeeded(
+        &self,
+        job_id: Uuid,
+        payload: &serde_json::Value,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        sqlx::query(
+            r#"UPDATE jobs
+            SET status = $1, payload = $2, locked_until = NULL, last_error = NULL,
+                updated_at = $3, completed_at = $3
+            WHERE id = $4
+            "#,
+        )
+        .bind(JobStatus::Succeeded)
+        .bind(Json(payload))
+        .bind(now)
+        .bind(job_id)
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_job_failed(
+        &self,
+        job_id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        let status = match retry_at {
+            Some(_) => JobStatus::Pending,
+            None => JobStatus::Dead,
+        };
+
+        sqlx::query(
+            r#"UPDATE jobs
+            SET status = $1, locked_until = NULL, last_error = $2, run_at = $3, updated_at = $4
+            WHERE id = $5
+            "#,
+        )
+        .bind(status)
+        .bind(error)
+        .bind(retry_at.unwrap_or(now))
+        .bind(now)
+        .bind(job_id)
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>, sqlx::Error> {
+        sqlx::query_as::<_, Job>("SELECT * FROM jobs WHERE id = $1")
+            .bind(job_id)
+            .fetch_optional(self.pool)
+            .await
+    }
+
+    async fn get_jobs(
+        &self,
+        status: Option<JobStatus>,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<Job>, sqlx::Error> {
+        let offset = page.saturating_sub(1) * limit as u32;
+
+        sqlx::query_as::<_, Job>(
+            r#"SELECT * FROM jobs
+            WHERE ($1::job_status IS NULL OR status = $1)
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(status)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_job_count(&self, status: Option<JobStatus>) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM jobs WHERE ($1::job_status IS NULL OR status = $1)",
+        )
+        .bind(status)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn retry_job(&self, job_id: Uuid) -> Result<Option<Job>, sqlx::Error> {
+        let now = Utc::now();
+        sqlx::query_as::<_, Job>(
+            r#"UPDATE jobs
+            SET status = $1, attempts = 0, run_at = $2, locked_until = NULL, updated_at = $2
+            WHERE id = $3 AND status = $4
+            RETURNING *
+            "#,
+        )
+        .bind(JobStatus::Pending)
+        .bind(now)
+        .bind(job_id)
+        .bind(JobStatus::Dead)
+        .fetch_optional(self.pool)
+        .await
+    }
+}
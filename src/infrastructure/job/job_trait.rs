@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domains::job::{Job, JobStatus};
+
+#[async_trait]
+pub trait JobRepository {
+    async fn enqueue_job(
+        &self,
+        kind: &str,
+        payload: &serde_json::Value,
+        run_at: DateTime<Utc>,
+        max_attempts: i32,
+        request_id: Option<&str>,
+        traceparent: Option<&str>,
+    ) -> Result<Job, sqlx::Error>;
+
+    /// Claims up to `limit` jobs that are due, or whose previous worker let
+    /// its lease run out. Each claim counts as an attempt, so a job whose
+    /// lease ran out on its last attempt is dead-lettered instead.
+    async fn claim_due_jobs(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Job>, sqlx::Error>;
+
+    /// Stores `payload` in place of the one the job ran with, so secrets it
+    /// carried do not outlive it.
+    async fn mark_job_succeeded(
+        &self,
+        job_id: Uuid,
+        payload: &serde_json::Value,
+    ) -> Result<(), sqlx::Error>;
+
+    /// Records a failed attempt. Without `retry_at` the job is dead-lettered.
+    async fn mark_job_failed(
+        &self,
+        job_id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), sqlx::Error>;
+
+    async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>, sqlx::Error>;
+
+    async fn get_jobs(
+        &self,
+        status: Option<JobStatus>,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<Job>, sqlx::Error>;
+
+    async fn get_job_count(&self, status: Option<JobStatus>) -> Result<i64, sqlx::Error>;
+
+    /// Puts a dead job back on the queue with a fresh set of attempts.
+    async fn retry_job(&self, job_id: Uuid) -> Result<Option<Job>, sqlx::Error>;
+}
@@ -0,0 +1,2 @@
+pub mod job_impl;
+pub mod job_trait;
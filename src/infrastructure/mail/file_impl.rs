@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use tokio::fs;
+use tracing::info;
+
+use crate::domains::mail::OutgoingMail;
+
+use super::mail_trait::{MailResult, MailTransport, build_message};
+
+/// Writes every message to `<dir>/<timestamp>-<id>.eml`, which any mail client
+/// can open.
+#[derive(Debug, Clone)]
+pub struct FileMailTransport {
+    dir: PathBuf,
+}
+
+impl FileMailTransport {
+    pub fn new(dir: &str) -> Self {
+        Self {
+            dir: PathBuf::from(dir),
+        }
+    }
+}
+
+#[async_trait]
+impl MailTransport for FileMailTransport {
+    async fn send(&self, mail: &OutgoingMail) -> MailResult {
+        let message = build_message(mail)?;
+
+        fs::create_dir_all(&self.dir).await?;
+        let path = self.dir.join(format!(
+            "{}-{}.eml",
+            mail.created_at.format("%Y%m%dT%H%M%S"),
+            mail.id
+        ));
+        fs::write(&path, message.formatted()).await?;
+
+        info!(mail.id = %mail.id, mail.to = %mail.to, path = %path.display(), "Email written to file");
+        Ok(())
+    }
+}
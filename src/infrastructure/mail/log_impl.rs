@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::domains::mail::OutgoingMail;
+
+use super::mail_trait::{MailResult, MailTransport};
+
+/// Logs the envelope of each message instead of sending it. Bodies carry
+/// live verification and reset links, so they are never written out; use the
+/// file or mailbox transport to read them in development.
+#[derive(Debug, Clone, Copy)]
+pub struct LogMailTransport;
+
+#[async_trait]
+impl MailTransport for LogMailTransport {
+    async fn send(&self, mail: &OutgoingMail) -> MailResult {
+        info!(
+            mail.id = %mail.id,
+            mail.from = %mail.from,
+            mail.to = %mail.to,
+            mail.subject = %mail.subject,
+            "Email not sent (log transport)"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use lettre::{Message, message::MultiPart};
+
+use crate::{
+    config::email::{MailConfig, MailTransportKind},
+    domains::mail::OutgoingMail,
+};
+
+#[cfg(debug_assertions)]
+use super::mailbox_impl::MailboxTransport;
+use super::{
+    file_impl::FileMailTransport, log_impl::LogMailTransport, memory_impl::InMemoryMailTransport,
+    smtp_impl::SmtpMailTransport,
+};
+
+pub type MailResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+#[async_trait]
+pub trait MailTransport: Debug + Send + Sync {
+    async fn send(&self, mail: &OutgoingMail) -> MailResult;
+
+    /// Confirms the transport could deliver right now, for readiness checks.
+    async fn check(&self) -> MailResult {
+        Ok(())
+    }
+
+    /// The developer mailbox, when this transport is one.
+    #[cfg(debug_assertions)]
+    fn as_mailbox(&self) -> Option<&MailboxTransport> {
+        None
+    }
+}
+
+/// Builds the transport selected by `MAIL_TRANSPORT`.
+pub fn create_transport(config: &MailConfig) -> Arc<dyn MailTransport> {
+    match config.transport {
+        MailTransportKind::Smtp => Arc::new(SmtpMailTransport::new(
+            config
+                .smtp
+                .as_ref()
+                .expect("SMTP settings are loaded for the smtp transport"),
+        )),
+        MailTransportKind::File => Arc::new(FileMailTransport::new(&config.file_dir)),
+        MailTransportKind::Memory => Arc::new(InMemoryMailTransport::new()),
+        MailTransportKind::Log => Arc::new(LogMailTransport),
+        #[cfg(debug_assertions)]
+        MailTransportKind::Mailbox => Arc::new(MailboxTransport::new()),
+    }
+}
+
+pub fn build_message(
+    mail: &OutgoingMail,
+) -> Result<Message, Box<dyn std::error::Error + Send + Sync>> {
+    let message = Message::builder()
+        .message_id(Some(format!("<{}@{}>", mail.id, sender_domain(&mail.from))))
+        .from(mail.from.parse()?)
+        .to(mail.to.parse()?)
+        .subject(mail.subject.as_str())
+        .date(mail.created_at.into())
+        .multipart(MultiPart::alternative_plain_html(
+            mail.text_body.clone(),
+            mail.html_body.clone(),
+        ))?;
+    Ok(message)
+}
+
+fn sender_domain(from: &str) -> &str {
+    from.rsplit_once('@')
+        .map(|(_, domain)| domain.trim_end_matches('>'))
+        .unwrap_or("localhost")
+}
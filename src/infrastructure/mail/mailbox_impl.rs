@@ -0,0 +1,67 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domains::mail::OutgoingMail;
+
+use super::mail_trait::{MailResult, MailTransport};
+
+/// Old messages are dropped past this, so a long dev session does not grow
+/// without bound.
+const MAILBOX_CAPACITY: usize = 200;
+
+/// Captures messages for the developer mailbox instead of delivering them.
+#[derive(Debug, Default)]
+pub struct MailboxTransport {
+    messages: Mutex<VecDeque<OutgoingMail>>,
+}
+
+impl MailboxTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Captured messages, newest first.
+    pub fn messages(&self) -> Vec<OutgoingMail> {
+        self.messages
+            .lock()
+            .expect("mailbox lock poisoned")
+            .iter()
+            .rev()
+            .cloned()
+            .collect()
+    }
+
+    pub fn message(&self, id: Uuid) -> Option<OutgoingMail> {
+        self.messages
+            .lock()
+            .expect("mailbox lock poisoned")
+            .iter()
+            .find(|mail| mail.id == id)
+            .cloned()
+    }
+
+    pub fn clear(&self) -> usize {
+        let mut messages = self.messages.lock().expect("mailbox lock poisoned");
+        let cleared = messages.len();
+        messages.clear();
+        cleared
+    }
+}
+
+#[async_trait]
+impl MailTransport for MailboxTransport {
+    async fn send(&self, mail: &OutgoingMail) -> MailResult {
+        let mut messages = self.messages.lock().expect("mailbox lock poisoned");
+        if messages.len() == MAILBOX_CAPACITY {
+            messages.pop_front();
+        }
+        messages.push_back(mail.clone());
+        Ok(())
+    }
+
+    fn as_mailbox(&self) -> Option<&MailboxTransport> {
+        Some(self)
+    }
+}
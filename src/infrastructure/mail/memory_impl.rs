@@ -0,0 +1,38 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::domains::mail::OutgoingMail;
+
+use super::mail_trait::{MailResult, MailTransport};
+
+/// Keeps every message in memory, oldest first.
+#[derive(Debug, Default)]
+pub struct InMemoryMailTransport {
+    sent: Mutex<Vec<OutgoingMail>>,
+}
+
+impl InMemoryMailTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sent(&self) -> Vec<OutgoingMail> {
+        self.sent.lock().expect("mail store lock poisoned").clone()
+    }
+
+    pub fn clear(&self) {
+        self.sent.lock().expect("mail store lock poisoned").clear();
+    }
+}
+
+#[async_trait]
+impl MailTransport for InMemoryMailTransport {
+    async fn send(&self, mail: &OutgoingMail) -> MailResult {
+        self.sent
+            .lock()
+            .expect("mail store lock poisoned")
+            .push(mail.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod file_impl;
+pub mod log_impl;
+pub mod mail_trait;
+#[cfg(debug_assertions)]
+pub mod mailbox_impl;
+pub mod memory_impl;
+pub mod smtp_impl;
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+    transport::smtp::{PoolConfig, authentication::Credentials},
+};
+
+use crate::{config::email::MySMTP, domains::mail::OutgoingMail};
+
+use super::mail_trait::{MailResult, MailTransport, build_message};
+
+/// STARTTLS relay with a connection pool shared by every send.
+#[derive(Debug, Clone)]
+pub struct SmtpMailTransport {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailTransport {
+    pub fn new(config: &MySMTP) -> Self {
+        let mailer = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.server)
+            .expect("SMTP_SERVER must be a valid host name")
+            .credentials(Credentials::new(
+                config.username.clone(),
+                config.password.clone(),
+            ))
+            .port(config.port)
+            .pool_config(PoolConfig::new().max_size(config.pool_size))
+            .build();
+
+        Self { mailer }
+    }
+}
+
+#[async_trait]
+impl MailTransport for SmtpMailTransport {
+    async fn send(&self, mail: &OutgoingMail) -> MailResult {
+        let message = build_message(mail)?;
+        self.mailer.send(message).await?;
+        Ok(())
+    }
+
+    async fn check(&self) -> MailResult {
+        if self.mailer.test_connection().await? {
+            Ok(())
+        } else {
+            Err("SMTP server did not accept a connection".into())
+        }
+    }
+}
@@ -1,8 +1,7 @@
 use crate::{
     AppState,
-    domains::user::{User, UserRole},
+    domains::user::{AccountStatus, ApprovalStatus, User, UserRole},
     errors::{error_message::ErrorMessage, http_error::HttpError},
-    infrastructure::user::{user_trait::UserRepository, users_impl::PgUserRepository},
     utils::token,
 };
 use axum::{
@@ -13,6 +12,7 @@ use axum::{
     response::IntoResponse,
 };
 use axum_extra::extract::CookieJar;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
@@ -20,6 +20,8 @@ use uuid::Uuid;
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct JWTAuthMiddleware {
     pub user: User,
+    pub active_organization: Option<Uuid>,
+    pub authenticated_at: DateTime<Utc>,
 }
 
 pub async fn auth(
@@ -35,41 +37,108 @@ pub async fn auth(
             req.headers()
                 .get(header::AUTHORIZATION)
                 .and_then(|auth_header| auth_header.to_str().ok())
-                .and_then(|auth_value| {
-                    if auth_value.starts_with("Bearer ") {
-                        Some(auth_value[7..].to_owned())
-                    } else {
-                        None
-                    }
-                })
+                .and_then(|auth_value| auth_value.strip_prefix("Bearer ").map(str::to_owned))
         });
     let token = cookies
         .ok_or_else(|| HttpError::unauthorized(ErrorMessage::TokenNotProvided.to_string()))?;
 
-    let token_details = match token::decode_token(token, app_state.env.jwt_secret.as_bytes()) {
-        Ok(token_details) => token_details,
-        Err(_) => {
-            return Err(HttpError::unauthorized(
-                ErrorMessage::InvalidToken.to_string(),
-            ));
-        }
-    };
+    let token_details =
+        match token::decode_token_claims(token, app_state.config().jwt.secret.as_bytes()) {
+            Ok(token_details) => token_details,
+            Err(_) => {
+                return Err(HttpError::unauthorized(
+                    ErrorMessage::InvalidToken.to_string(),
+                ));
+            }
+        };
 
-    let user_id = Uuid::parse_str(&token_details.to_string())
+    let user_id = Uuid::parse_str(&token_details.sub)
         .map_err(|_| HttpError::unauthorized(ErrorMessage::InvalidToken.to_string()))?;
 
-    let user = PgUserRepository::new(&app_state.db_client)
+    let active_organization = token_details
+        .org
+        .map(|org| Uuid::parse_str(&org))
+        .transpose()
+        .map_err(|_| HttpError::unauthorized(ErrorMessage::InvalidToken.to_string()))?;
+
+    let user = app_state
+        .user_repository
         .get_user(Some(user_id), None, None, None)
         .await
         .map_err(|_| HttpError::unauthorized(ErrorMessage::UserNoLongerExist.to_string()))?
         .ok_or_else(|| HttpError::unauthorized(ErrorMessage::UserNoLongerExist.to_string()))?;
 
-    req.extensions_mut()
-        .insert(JWTAuthMiddleware { user: user.clone() });
+    ensure_account_usable(&user)?;
+
+    let authenticated_at = DateTime::from_timestamp(token_details.iat as i64, 0)
+        .ok_or_else(|| HttpError::unauthorized(ErrorMessage::InvalidToken.to_string()))?;
+
+    req.extensions_mut().insert(JWTAuthMiddleware {
+        user: user.clone(),
+        active_organization,
+        authenticated_at,
+    });
 
     Ok(next.run(req).await)
 }
 
+/// Rejects accounts that were deleted, are not `active`, or are waiting for
+/// (or were refused) admin approval. Every status maps to its own error.
+pub fn ensure_account_usable(user: &User) -> Result<(), HttpError> {
+    if user.deleted_at.is_some() {
+        return Err(HttpError::unauthorized(
+            ErrorMessage::UserNoLongerExist.to_string(),
+        ));
+    }
+
+    match user.status {
+        AccountStatus::Active => {}
+        AccountStatus::PendingVerification => {
+            return Err(HttpError::new(
+                ErrorMessage::EmailNotVerified.to_string(),
+                StatusCode::FORBIDDEN,
+            ));
+        }
+        AccountStatus::Suspended => {
+            // A lapsed suspension is honoured before the worker gets to it.
+            let lapsed = user
+                .suspended_until
+                .is_some_and(|until| until <= Utc::now());
+            if !lapsed {
+                let message = match &user.suspended_reason {
+                    Some(reason) => format!("{}: {}", ErrorMessage::AccountSuspended, reason),
+                    None => ErrorMessage::AccountSuspended.to_string(),
+                };
+                return Err(HttpError::new(message, StatusCode::FORBIDDEN));
+            }
+        }
+        AccountStatus::Locked => {
+            return Err(HttpError::new(
+                ErrorMessage::AccountLocked.to_string(),
+                StatusCode::LOCKED,
+            ));
+        }
+        AccountStatus::PendingDeletion => {
+            return Err(HttpError::new(
+                ErrorMessage::AccountPendingDeletion.to_string(),
+                StatusCode::FORBIDDEN,
+            ));
+        }
+        AccountStatus::Deleted => {
+            return Err(HttpError::unauthorized(
+                ErrorMessage::UserNoLongerExist.to_string(),
+            ));
+        }
+    }
+
+    let message = match user.approval_status {
+        ApprovalStatus::Approved => return Ok(()),
+        ApprovalStatus::Pending => ErrorMessage::AccountPendingApproval,
+        ApprovalStatus::Rejected => ErrorMessage::AccountRejected,
+    };
+    Err(HttpError::new(message.to_string(), StatusCode::FORBIDDEN))
+}
+
 pub async fn role_check(
     Extension(_app_state): Extension<Arc<AppState>>,
     req: Request,
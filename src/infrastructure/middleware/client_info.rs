@@ -0,0 +1,80 @@
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{HeaderMap, header, request::Parts},
+};
+
+use crate::{AppState, config::proxy::ProxyConfig};
+
+/// Where a request came from, for the audit log. `X-Forwarded-For` is only
+/// believed when the socket peer is one of `proxy.trusted_addresses`, so a
+/// client cannot pick the address it is recorded under.
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let proxy = parts
+            .extensions
+            .get::<Arc<AppState>>()
+            .map(|app_state| app_state.config().proxy.clone())
+            .unwrap_or_default();
+
+        let ip_address = client_ip(&parts.headers, peer, &proxy).map(|ip| ip.to_string());
+
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+
+        Ok(Self {
+            ip_address,
+            user_agent,
+        })
+    }
+}
+
+/// The client address for a request from `peer`. Behind trusted proxies the
+/// `X-Forwarded-For` hops are read from the nearest one back, and the first
+/// hop that is not itself a trusted proxy is the client.
+pub fn client_ip(headers: &HeaderMap, peer: Option<IpAddr>, proxy: &ProxyConfig) -> Option<IpAddr> {
+    let mut client = peer?.to_canonical();
+    if !proxy.is_trusted(client) {
+        return Some(client);
+    }
+
+    let hops: Vec<&str> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect();
+
+    for hop in hops.into_iter().rev() {
+        let Ok(ip) = hop.parse::<IpAddr>() else {
+            break;
+        };
+        client = ip.to_canonical();
+        if !proxy.is_trusted(client) {
+            break;
+        }
+    }
+
+    Some(client)
+}
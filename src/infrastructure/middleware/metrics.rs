@@ -0,0 +1,30 @@
+use std::time::Instant;
+
+use axum::{body::Body, extract::MatchedPath, middleware::Next, response::Response};
+use http::Request;
+
+use crate::core::metrics::record_http_request;
+
+/// Records the request count and latency of matched routes. Installed as a
+/// route layer so the route template is known; the raw path would give every
+/// user id its own series.
+pub async fn track_metrics(req: Request<Body>, next: Next) -> Response {
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+    let method = req.method().to_string();
+
+    let started = Instant::now();
+    let response = next.run(req).await;
+
+    record_http_request(
+        &method,
+        &route,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+
+    response
+}
@@ -1,3 +1,6 @@
 pub mod auth;
-pub mod debug_after;
-pub mod debug_before;
+pub mod client_info;
+pub mod metrics;
+pub mod organization;
+pub mod request_id;
+pub mod request_log;
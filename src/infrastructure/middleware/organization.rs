@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, extract::Request, http::StatusCode, middleware::Next, response::IntoResponse,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    domains::organization::OrganizationRole,
+    errors::{error_message::ErrorMessage, http_error::HttpError},
+    infrastructure::organization::{
+        organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+    },
+};
+
+use super::auth::JWTAuthMiddleware;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OrganizationContext {
+    pub organization_id: Uuid,
+    pub role: OrganizationRole,
+}
+
+/// Resolves the active organization from the JWT and checks that the user is
+/// still a member of it. Must run after `auth`.
+pub async fn organization_context(
+    Extension(app_state): Extension<Arc<AppState>>,
+    mut req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, HttpError> {
+    let auth = req
+        .extensions()
+        .get::<JWTAuthMiddleware>()
+        .ok_or_else(|| HttpError::unauthorized(ErrorMessage::UserNotAuthenticated.to_string()))?;
+
+    let organization_id = auth
+        .active_organization
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::NoActiveOrganization.to_string()))?;
+
+    let membership = PgOrganizationRepository::new(&app_state.db_client)
+        .get_membership(organization_id, auth.user.id)
+        .await
+        .map_err(|e| HttpError::server_error(e.to_string()))?
+        .ok_or_else(|| {
+            HttpError::new(
+                ErrorMessage::NotOrganizationMember.to_string(),
+                StatusCode::FORBIDDEN,
+            )
+        })?;
+
+    req.extensions_mut().insert(OrganizationContext {
+        organization_id,
+        role: membership.role,
+    });
+
+    Ok(next.run(req).await)
+}
+
+pub async fn organization_role_check(
+    req: Request,
+    next: Next,
+    require_roles: Vec<OrganizationRole>,
+) -> Result<impl IntoResponse, HttpError> {
+    let context = req
+        .extensions()
+        .get::<OrganizationContext>()
+        .ok_or_else(|| HttpError::bad_request(ErrorMessage::NoActiveOrganization.to_string()))?;
+
+    if !require_roles.contains(&context.role) {
+        return Err(HttpError::new(
+            ErrorMessage::PermissionDenied.to_string(),
+            StatusCode::FORBIDDEN,
+        ));
+    }
+
+    Ok(next.run(req).await)
+}
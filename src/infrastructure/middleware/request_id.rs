@@ -0,0 +1,70 @@
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use http::Request;
+use opentelemetry::trace::TraceContextExt;
+use tracing::{Span, field::Empty, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::core::telemetry::{TRACEPARENT, TraceContext, is_valid_request_id, new_request_id};
+
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Opens the server span for a request, continuing the caller's trace when
+/// it sent a `traceparent`, and echoes the request id back in
+/// `X-Request-Id`. The id is taken from the caller when it sent a usable one.
+pub async fn trace_request(req: Request<Body>, next: Next) -> Response {
+    let request_id = header(&req, X_REQUEST_ID.as_str())
+        .filter(|id| is_valid_request_id(id))
+        .unwrap_or_else(new_request_id);
+    let context = TraceContext {
+        request_id: Some(request_id.clone()),
+        traceparent: header(&req, TRACEPARENT),
+    };
+
+    let span = info_span!(
+        "http_request",
+        otel.name = %req.method(),
+        otel.kind = "server",
+        http.request.method = %req.method(),
+        http.route = Empty,
+        url.path = %req.uri().path(),
+        http.response.status_code = Empty,
+        request_id = Empty,
+    );
+
+    let mut response = context.run(span.clone(), next.run(req)).await;
+
+    span.record("http.response.status_code", response.status().as_u16());
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(X_REQUEST_ID, value);
+    }
+
+    response
+}
+
+fn header(req: &Request<Body>, name: &str) -> Option<String> {
+    req.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Names the request span after the matched route. Installed as a route
+/// layer, where the route template is known.
+pub async fn record_route(req: Request<Body>, next: Next) -> Response {
+    if let Some(route) = req.extensions().get::<MatchedPath>() {
+        let span = Span::current();
+        span.record("http.route", route.as_str());
+        // The span has started by now, so `otel.name` would no longer apply.
+        span.context()
+            .span()
+            .update_name(format!("{} {}", req.method(), route.as_str()));
+    }
+
+    next.run(req).await
+}
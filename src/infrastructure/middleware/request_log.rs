@@ -0,0 +1,107 @@
+use std::{sync::Arc, time::Instant};
+
+use axum::{
+    Extension,
+    body::{Body, HttpBody, to_bytes},
+    http::{HeaderMap, header},
+    middleware::Next,
+    response::Response,
+};
+use http::Request;
+use tracing::info;
+
+use crate::{
+    AppState,
+    config::logging::LoggingConfig,
+    utils::redact::{redact_headers, redact_json},
+};
+
+/// Logs one structured event per request. When body capture is on and the
+/// request is sampled, headers and bodies are included with credentials
+/// redacted; only JSON bodies of known size up to `logging.max_body_bytes`
+/// are read, so nothing is buffered beyond that.
+pub async fn log_request(
+    Extension(app_state): Extension<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let config = app_state.config();
+    let logging = &config.logging;
+    let capture = logging.capture_bodies && rand::random::<f64>() < logging.body_sample_rate;
+
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+    let started = Instant::now();
+
+    if !capture {
+        let response = next.run(req).await;
+        info!(
+            http.method = %method,
+            url.path = %path,
+            http.status = response.status().as_u16(),
+            latency_ms = started.elapsed().as_millis() as u64,
+            "request completed"
+        );
+        return response;
+    }
+
+    let request_headers = redact_headers(req.headers());
+    let (parts, body) = req.into_parts();
+    let (body, request_body) = capture_body(&parts.headers, body, logging).await;
+    let response = next.run(Request::from_parts(parts, body)).await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let response_headers = redact_headers(response.headers());
+    let (parts, body) = response.into_parts();
+    let (body, response_body) = capture_body(&parts.headers, body, logging).await;
+
+    info!(
+        http.method = %method,
+        url.path = %path,
+        http.status = parts.status.as_u16(),
+        latency_ms,
+        request.headers = %request_headers,
+        request.body = %request_body,
+        response.headers = %response_headers,
+        response.body = %response_body,
+        "request completed"
+    );
+
+    Response::from_parts(parts, body)
+}
+
+/// Reads a body for the log and hands back an equivalent one to pass on.
+/// Bodies that are not JSON, or too large or of unknown size, are left
+/// unread and described instead.
+async fn capture_body(headers: &HeaderMap, body: Body, logging: &LoggingConfig) -> (Body, String) {
+    let is_json = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    let size = body.size_hint().exact();
+
+    match size {
+        Some(0) => return (body, String::new()),
+        Some(size) if size as usize <= logging.max_body_bytes && is_json => {}
+        Some(size) if is_json => return (body, format!("[{} bytes, not captured]", size)),
+        None if is_json => return (body, "[streamed, not captured]".to_string()),
+        _ => return (body, "[not JSON, not captured]".to_string()),
+    }
+
+    // The size is known to be within the limit, so this only fails when the
+    // client goes away mid-body, and the handler could not have used it then.
+    let bytes = match to_bytes(body, logging.max_body_bytes).await {
+        Ok(bytes) => bytes,
+        Err(e) => return (Body::empty(), format!("[unreadable: {}]", e)),
+    };
+
+    let logged = match serde_json::from_slice(&bytes) {
+        Ok(mut value) => {
+            redact_json(&mut value, &logging.redact_fields);
+            value.to_string()
+        }
+        Err(_) => "[invalid JSON, not captured]".to_string(),
+    };
+
+    (Body::from(bytes), logged)
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+
+use crate::{domains::migration::AppliedMigration, infrastructure::database::database::DBClient};
+
+use super::migration_trait::MigrationRepository;
+
+pub struct PgMigrationRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgMigrationRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+}
+
+#[async_trait]
+impl<'a> MigrationRepository for PgMigrationRepository<'a> {
+    async fn get_applied_migrations(&self) -> Result<Vec<AppliedMigration>, sqlx::Error> {
+        let exists: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+            .fetch_one(self.pool)
+            .await?;
+        if !exists {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_as::<_, AppliedMigration>(
+            r#"SELECT version, description, installed_on, success, checksum
+            FROM _sqlx_migrations
+            ORDER BY version
+            "#,
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+}
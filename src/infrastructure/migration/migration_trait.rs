@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domains::migration::AppliedMigration;
+
+#[async_trait]
+pub trait MigrationRepository {
+    /// Empty when no migration has ever been recorded.
+    async fn get_applied_migrations(&self) -> Result<Vec<AppliedMigration>, sqlx::Error>;
+}
@@ -0,0 +1,2 @@
+pub mod migration_impl;
+pub mod migration_trait;
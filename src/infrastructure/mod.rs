@@ -1,4 +1,13 @@
-pub mod database;
+pub mod audit;
 pub mod auth;
+pub mod data_export;
+pub mod database;
+pub mod email_template;
+pub mod invitation;
+pub mod job;
+pub mod mail;
 pub mod middleware;
+pub mod migration;
+pub mod organization;
 pub mod user;
+pub mod webhook;
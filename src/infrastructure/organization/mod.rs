@@ -0,0 +1,2 @@
+pub mod organization_impl;
+pub mod organization_trait;
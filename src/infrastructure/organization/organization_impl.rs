@@ -0,0 +1,251 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{PgConnection, Pool, Postgres};
+use uuid::Uuid;
+
+use crate::{
+    domains::organization::{
+        Membership, MembershipChange, Organization, OrganizationMember, OrganizationRole,
+        UserOrganization,
+    },
+    infrastructure::database::database::DBClient,
+};
+
+use super::organization_trait::OrganizationRepository;
+
+pub struct PgOrganizationRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgOrganizationRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+
+    /// Locks the organization for the rest of the transaction, then reads the
+    /// target's membership and how many owners there are. Every change to
+    /// memberships that checks for the last owner goes through here, so the
+    /// checks see each other's writes.
+    async fn lock_membership(
+        conn: &mut PgConnection,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<(Membership, i64)>, sqlx::Error> {
+        sqlx::query("SELECT id FROM organizations WHERE id = $1 FOR UPDATE")
+            .bind(organization_id)
+            .execute(&mut *conn)
+            .await?;
+
+        let membership = sqlx::query_as::<_, Membership>(
+            "SELECT * FROM organization_memberships WHERE organization_id = $1 AND user_id = $2",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+        let Some(membership) = membership else {
+            return Ok(None);
+        };
+
+        let (owners,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM organization_memberships WHERE organization_id = $1 AND role = $2",
+        )
+        .bind(organization_id)
+        .bind(OrganizationRole::Owner)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(Some((membership, owners)))
+    }
+}
+
+#[async_trait]
+impl<'a> OrganizationRepository for PgOrganizationRepository<'a> {
+    async fn create_organization(
+        &self,
+        name: &str,
+        owner_id: Uuid,
+    ) -> Result<Organization, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let organization = sqlx::query_as::<_, Organization>(
+            r#"INSERT INTO organizations (id, name, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(name)
+        .bind(owner_id)
+        .bind(Utc::now())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"INSERT INTO organization_memberships (organization_id, user_id, role, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $4)
+            "#,
+        )
+        .bind(organization.id)
+        .bind(owner_id)
+        .bind(OrganizationRole::Owner)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(organization)
+    }
+
+    async fn get_organization(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Option<Organization>, sqlx::Error> {
+        sqlx::query_as::<_, Organization>("SELECT * FROM organizations WHERE id = $1")
+            .bind(organization_id)
+            .fetch_optional(self.pool)
+            .await
+    }
+
+    async fn get_user_organizations(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<UserOrganization>, sqlx::Error> {
+        sqlx::query_as::<_, UserOrganization>(
+            r#"SELECT o.id, o.name, m.role, o.created_at, o.updated_at
+            FROM organizations o
+            JOIN organization_memberships m ON m.organization_id = o.id
+            WHERE m.user_id = $1
+            ORDER BY o.created_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_membership(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Membership>, sqlx::Error> {
+        sqlx::query_as::<_, Membership>(
+            "SELECT * FROM organization_memberships WHERE organization_id = $1 AND user_id = $2",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    async fn get_members(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationMember>, sqlx::Error> {
+        sqlx::query_as::<_, OrganizationMember>(
+            r#"SELECT m.user_id, u.name, u.email, m.role, m.created_at
+            FROM organization_memberships m
+            JOIN users u ON u.id = m.user_id
+            WHERE m.organization_id = $1
+            ORDER BY m.created_at ASC
+            "#,
+        )
+        .bind(organization_id)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn add_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: OrganizationRole,
+    ) -> Result<Membership, sqlx::Error> {
+        sqlx::query_as::<_, Membership>(
+            r#"INSERT INTO organization_memberships (organization_id, user_id, role, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .bind(role)
+        .bind(Utc::now())
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn update_member_role(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: OrganizationRole,
+        actor_role: OrganizationRole,
+    ) -> Result<MembershipChange, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some((membership, owners)) =
+            Self::lock_membership(&mut tx, organization_id, user_id).await?
+        else {
+            return Ok(MembershipChange::NotFound);
+        };
+        if !actor_role.can_assign(membership.role) || !actor_role.can_assign(role) {
+            return Ok(MembershipChange::Forbidden);
+        }
+        if membership.role == OrganizationRole::Owner
+            && role != OrganizationRole::Owner
+            && owners <= 1
+        {
+            return Ok(MembershipChange::LastOwner);
+        }
+
+        let membership = sqlx::query_as::<_, Membership>(
+            r#"UPDATE organization_memberships SET role = $1, updated_at = $2
+            WHERE organization_id = $3 AND user_id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(role)
+        .bind(Utc::now())
+        .bind(organization_id)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(MembershipChange::Applied(membership))
+    }
+
+    async fn remove_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        actor_role: OrganizationRole,
+    ) -> Result<MembershipChange, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some((membership, owners)) =
+            Self::lock_membership(&mut tx, organization_id, user_id).await?
+        else {
+            return Ok(MembershipChange::NotFound);
+        };
+        if !actor_role.can_assign(membership.role) {
+            return Ok(MembershipChange::Forbidden);
+        }
+        if membership.role == OrganizationRole::Owner && owners <= 1 {
+            return Ok(MembershipChange::LastOwner);
+        }
+
+        sqlx::query(
+            "DELETE FROM organization_memberships WHERE organization_id = $1 AND user_id = $2",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(MembershipChange::Applied(membership))
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domains::organization::{
+    Membership, MembershipChange, Organization, OrganizationMember, OrganizationRole,
+    UserOrganization,
+};
+
+/// Every query on organization-scoped data takes the organization id as a
+/// required argument, so a caller can never read across tenants by accident.
+#[async_trait]
+pub trait OrganizationRepository {
+    async fn create_organization(
+        &self,
+        name: &str,
+        owner_id: Uuid,
+    ) -> Result<Organization, sqlx::Error>;
+
+    async fn get_organization(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Option<Organization>, sqlx::Error>;
+
+    async fn get_user_organizations(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<UserOrganization>, sqlx::Error>;
+
+    async fn get_membership(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Membership>, sqlx::Error>;
+
+    async fn get_members(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationMember>, sqlx::Error>;
+
+    async fn add_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: OrganizationRole,
+    ) -> Result<Membership, sqlx::Error>;
+
+    /// Changes `user_id`'s role on behalf of a member holding `actor_role`.
+    async fn update_member_role(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: OrganizationRole,
+        actor_role: OrganizationRole,
+    ) -> Result<MembershipChange, sqlx::Error>;
+
+    /// Removes `user_id` on behalf of a member holding `actor_role`.
+    async fn remove_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        actor_role: OrganizationRole,
+    ) -> Result<MembershipChange, sqlx::Error>;
+}
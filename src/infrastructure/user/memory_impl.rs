@@ -0,0 +1,627 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    error::Error as StdError,
+    sync::{Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::error::{DatabaseError, ErrorKind};
+use uuid::Uuid;
+
+use crate::domains::user::{
+    AccountStatus, ApprovalStatus, StatusChange, StatusTransition, User, UserFilter, UserRole,
+};
+
+use super::user_trait::UserRepository;
+
+/// Keeps users in process memory, for tests and anything else that needs
+/// users without a database. Errors mirror Postgres: a missing user on update is
+/// `RowNotFound` and a taken email address is a unique violation.
+#[derive(Debug, Default)]
+pub struct InMemoryUserRepository {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// In insertion order, which is also `created_at` order.
+    users: Vec<User>,
+    transitions: Vec<StatusTransition>,
+    /// Failed logins in a row, by user; absent means none.
+    failed_logins: HashMap<Uuid, u32>,
+}
+
+impl State {
+    fn user_mut(&mut self, user_id: Uuid) -> Result<&mut User, sqlx::Error> {
+        self.users
+            .iter_mut()
+            .find(|user| user.id == user_id)
+            .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    fn insert(&mut self, user: User) -> Result<User, sqlx::Error> {
+        if self
+            .users
+            .iter()
+            .any(|existing| existing.email == user.email)
+        {
+            return Err(sqlx::Error::Database(Box::new(UniqueViolation)));
+        }
+        self.users.push(user.clone());
+        Ok(user)
+    }
+
+    fn record_transition(
+        &mut self,
+        user_id: Uuid,
+        from: AccountStatus,
+        to: AccountStatus,
+        reason: Option<&str>,
+        actor_id: Option<Uuid>,
+    ) {
+        self.transitions.push(StatusTransition {
+            id: Uuid::new_v4(),
+            user_id,
+            from_status: from,
+            to_status: to,
+            reason: reason.map(str::to_string),
+            actor_id,
+            created_at: Utc::now(),
+        });
+    }
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // A panic elsewhere cannot leave a half-applied change behind, since
+        // every method finishes its writes before returning.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Applies `change` to the user and bumps `updated_at`.
+    fn update(&self, user_id: Uuid, change: impl FnOnce(&mut User)) -> Result<User, sqlx::Error> {
+        let mut state = self.state();
+        let user = state.user_mut(user_id)?;
+        change(user);
+        user.updated_at = Utc::now();
+        Ok(user.clone())
+    }
+
+    fn select(&self, matches: impl Fn(&User) -> bool) -> Vec<User> {
+        self.state()
+            .users
+            .iter()
+            .filter(|user| matches(user))
+            .cloned()
+            .collect()
+    }
+}
+
+fn new_user(
+    name: &str,
+    email: &str,
+    password: &str,
+    role: UserRole,
+    status: AccountStatus,
+) -> User {
+    let now = Utc::now();
+    User {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        email: email.to_string(),
+        password: password.to_string(),
+        role,
+        status,
+        suspended_reason: None,
+        suspended_until: None,
+        approval_status: ApprovalStatus::Approved,
+        locale: None,
+        verification_token: None,
+        token_expires_at: None,
+        deletion_scheduled_at: None,
+        deleted_at: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// Newest first, one page of `limit`.
+fn newest_page(mut users: Vec<User>, page: u32, limit: usize) -> Vec<User> {
+    users.reverse();
+    let offset = (page.saturating_sub(1) as usize) * limit;
+    users.into_iter().skip(offset).take(limit).collect()
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn get_user(
+        &self,
+        user_id: Option<Uuid>,
+        name: Option<&str>,
+        email: Option<&str>,
+        token: Option<&str>,
+    ) -> Result<Option<User>, sqlx::Error> {
+        let state = self.state();
+        let found = if let Some(id) = user_id {
+            state.users.iter().find(|user| user.id == id)
+        } else if let Some(n) = name {
+            state.users.iter().find(|user| user.name == n)
+        } else if let Some(e) = email {
+            state.users.iter().find(|user| user.email == e)
+        } else if let Some(t) = token {
+            state
+                .users
+                .iter()
+                .find(|user| user.verification_token.as_deref() == Some(t))
+        } else {
+            None
+        };
+
+        Ok(found.cloned())
+    }
+
+    async fn get_users(&self, page: u32, limit: usize) -> Result<Vec<User>, sqlx::Error> {
+        Ok(newest_page(self.select(|_| true), page, limit))
+    }
+
+    async fn find_users(
+        &self,
+        filter: &UserFilter,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        let search = filter.search.as_ref().map(|search| search.to_lowercase());
+        let users = self.select(|user| {
+            filter.role.is_none_or(|role| user.role == role)
+                && filter.status.is_none_or(|status| user.status == status)
+                && filter
+                    .approval_status
+                    .is_none_or(|status| user.approval_status == status)
+                && search.as_ref().is_none_or(|search| {
+                    user.name.to_lowercase().contains(search)
+                        || user.email.to_lowercase().contains(search)
+                })
+        });
+
+        Ok(newest_page(users, page, limit))
+    }
+
+    async fn save_user(
+        &self,
+        name: &str,
+        email: &str,
+        password: &str,
+        verification_token: &str,
+        token_expires_at: DateTime<Utc>,
+        approval_status: ApprovalStatus,
+    ) -> Result<User, sqlx::Error> {
+        let user = User {
+            verification_token: Some(verification_token.to_string()),
+            token_expires_at: Some(token_expires_at),
+            approval_status,
+            ..new_user(
+                name,
+                email,
+                password,
+                UserRole::User,
+                AccountStatus::PendingVerification,
+            )
+        };
+
+        self.state().insert(user)
+    }
+
+    async fn save_invited_user(
+        &self,
+        name: &str,
+        email: &str,
+        password: &str,
+        role: UserRole,
+    ) -> Result<User, sqlx::Error> {
+        let user = new_user(name, email, password, role, AccountStatus::Active);
+        self.state().insert(user)
+    }
+
+    async fn get_user_count(&self) -> Result<i64, sqlx::Error> {
+        Ok(self.state().users.len() as i64)
+    }
+
+    async fn get_users_by_approval_status(
+        &self,
+        approval_status: ApprovalStatus,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        let offset = (page.saturating_sub(1) as usize) * limit;
+        Ok(self
+            .select(|user| user.approval_status == approval_status)
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn get_user_count_by_approval_status(
+        &self,
+        approval_status: ApprovalStatus,
+    ) -> Result<i64, sqlx::Error> {
+        Ok(self
+            .select(|user| user.approval_status == approval_status)
+            .len() as i64)
+    }
+
+    async fn get_admins(&self) -> Result<Vec<User>, sqlx::Error> {
+        Ok(self.select(|user| user.role == UserRole::Admin))
+    }
+
+    async fn update_approval_status(
+        &self,
+        user_id: Uuid,
+        approval_status: ApprovalStatus,
+    ) -> Result<User, sqlx::Error> {
+        self.update(user_id, |user| user.approval_status = approval_status)
+    }
+
+    async fn update_username(&self, user_id: Uuid, name: &str) -> Result<User, sqlx::Error> {
+        self.update(user_id, |user| user.name = name.to_string())
+    }
+
+    async fn update_user_locale(
+        &self,
+        user_id: Uuid,
+        locale: Option<&str>,
+    ) -> Result<User, sqlx::Error> {
+        self.update(user_id, |user| user.locale = locale.map(str::to_string))
+    }
+
+    async fn update_user_role(&self, user_id: Uuid, role: UserRole) -> Result<User, sqlx::Error> {
+        self.update(user_id, |user| user.role = role)
+    }
+
+    async fn update_user_password(
+        &self,
+        user_id: Uuid,
+        password: String,
+    ) -> Result<User, sqlx::Error> {
+        self.update(user_id, |user| user.password = password)
+    }
+
+    async fn verified_token(&self, token: &str) -> Result<(), sqlx::Error> {
+        let mut state = self.state();
+        let Some(user) = state.users.iter_mut().find(|user| {
+            user.verification_token.as_deref() == Some(token)
+                && user.status == AccountStatus::PendingVerification
+        }) else {
+            return Ok(());
+        };
+
+        user.status = AccountStatus::Active;
+        user.verification_token = None;
+        user.token_expires_at = None;
+        user.updated_at = Utc::now();
+        let user_id = user.id;
+
+        state.record_transition(
+            user_id,
+            AccountStatus::PendingVerification,
+            AccountStatus::Active,
+            Some("email verified"),
+            Some(user_id),
+        );
+        Ok(())
+    }
+
+    async fn add_verified_token(
+        &self,
+        user_id: Uuid,
+        token: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        match self.update(user_id, |user| {
+            user.verification_token = Some(token.to_string());
+            user.token_expires_at = Some(expires_at);
+        }) {
+            Ok(_) | Err(sqlx::Error::RowNotFound) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn clear_verification_token(&self, user_id: Uuid) -> Result<(), sqlx::Error> {
+        match self.update(user_id, |user| {
+            user.verification_token = None;
+            user.token_expires_at = None;
+        }) {
+            Ok(_) | Err(sqlx::Error::RowNotFound) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn clear_expired_tokens(&self, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        let mut cleared = 0;
+        for user in self.state().users.iter_mut() {
+            if user
+                .token_expires_at
+                .is_some_and(|expires_at| expires_at < now)
+            {
+                user.verification_token = None;
+                user.token_expires_at = None;
+                user.updated_at = now;
+                cleared += 1;
+            }
+        }
+
+        Ok(cleared)
+    }
+
+    async fn schedule_deletion(
+        &self,
+        user_id: Uuid,
+        deletion_scheduled_at: DateTime<Utc>,
+    ) -> Result<Option<User>, sqlx::Error> {
+        let mut state = self.state();
+        let user = state.user_mut(user_id)?;
+        let from = user.status;
+        if from != AccountStatus::PendingDeletion
+            && !from.can_transition_to(AccountStatus::PendingDeletion)
+        {
+            return Ok(None);
+        }
+
+        user.status = AccountStatus::PendingDeletion;
+        user.deletion_scheduled_at = Some(deletion_scheduled_at);
+        user.updated_at = Utc::now();
+        let user = user.clone();
+
+        if from != AccountStatus::PendingDeletion {
+            state.record_transition(
+                user_id,
+                from,
+                AccountStatus::PendingDeletion,
+                Some("deletion requested"),
+                Some(user_id),
+            );
+        }
+        Ok(Some(user))
+    }
+
+    async fn cancel_deletion(&self, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut state = self.state();
+        let restored = state
+            .transitions
+            .iter()
+            .rev()
+            .find(|transition| {
+                transition.user_id == user_id
+                    && transition.to_status == AccountStatus::PendingDeletion
+            })
+            .map(|transition| transition.from_status)
+            .filter(|status| AccountStatus::PendingDeletion.can_transition_to(*status))
+            .unwrap_or(AccountStatus::Active);
+
+        let Some(user) = state.users.iter_mut().find(|user| {
+            user.id == user_id
+                && user.status == AccountStatus::PendingDeletion
+                && user.deleted_at.is_none()
+        }) else {
+            return Ok(false);
+        };
+
+        user.status = restored;
+        user.deletion_scheduled_at = None;
+        user.updated_at = Utc::now();
+
+        state.record_transition(
+            user_id,
+            AccountStatus::PendingDeletion,
+            restored,
+            Some("deletion cancelled"),
+            Some(user_id),
+        );
+        Ok(true)
+    }
+
+    async fn get_users_due_for_deletion(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        Ok(self.select(|user| {
+            user.status == AccountStatus::PendingDeletion
+                && user.deletion_scheduled_at.is_some_and(|at| at <= now)
+                && user.deleted_at.is_none()
+        }))
+    }
+
+    async fn delete_user(&self, user_id: Uuid) -> Result<(), sqlx::Error> {
+        let mut state = self.state();
+        state.users.retain(|user| user.id != user_id);
+        state
+            .transitions
+            .retain(|transition| transition.user_id != user_id);
+        for transition in state.transitions.iter_mut() {
+            if transition.actor_id == Some(user_id) {
+                transition.actor_id = None;
+            }
+        }
+        Ok(())
+    }
+
+    /// Memberships are kept by the organization repository, so only the user
+    /// itself is scrubbed here.
+    async fn anonymize_user(&self, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut state = self.state();
+        let Some(user) = state.users.iter_mut().find(|user| user.id == user_id) else {
+            return Ok(false);
+        };
+        let from = user.status;
+        if !from.can_transition_to(AccountStatus::Deleted) {
+            return Ok(false);
+        }
+
+        let now = Utc::now();
+        user.name = "Deleted user".to_string();
+        user.email = format!("deleted-{}@deleted.invalid", user.id);
+        user.password = String::new();
+        user.verification_token = None;
+        user.suspended_reason = None;
+        user.locale = None;
+        user.token_expires_at = None;
+        user.deletion_scheduled_at = None;
+        user.status = AccountStatus::Deleted;
+        user.deleted_at = Some(now);
+        user.updated_at = now;
+
+        state.record_transition(
+            user_id,
+            from,
+            AccountStatus::Deleted,
+            Some("account purged"),
+            None,
+        );
+        Ok(true)
+    }
+
+    async fn update_status(
+        &self,
+        user_id: Uuid,
+        from: AccountStatus,
+        change: StatusChange,
+    ) -> Result<Option<User>, sqlx::Error> {
+        if !from.can_transition_to(change.to) {
+            return Ok(None);
+        }
+
+        let mut state = self.state();
+        let Some(user) = state
+            .users
+            .iter_mut()
+            .find(|user| user.id == user_id && user.status == from)
+        else {
+            return Ok(None);
+        };
+
+        let (suspended_reason, suspended_until) = match change.to {
+            AccountStatus::Suspended => (change.reason.clone(), change.suspended_until),
+            _ => (None, None),
+        };
+        user.status = change.to;
+        user.suspended_reason = suspended_reason;
+        user.suspended_until = suspended_until;
+        user.updated_at = Utc::now();
+        let user = user.clone();
+
+        if change.to == AccountStatus::Active {
+            state.failed_logins.remove(&user_id);
+        }
+        state.record_transition(
+            user_id,
+            from,
+            change.to,
+            change.reason.as_deref(),
+            change.actor_id,
+        );
+        Ok(Some(user))
+    }
+
+    async fn record_failed_login(
+        &self,
+        user_id: Uuid,
+        threshold: u32,
+    ) -> Result<Option<User>, sqlx::Error> {
+        let mut state = self.state();
+        let Some(user) = state.users.iter().find(|user| user.id == user_id).cloned() else {
+            return Ok(None);
+        };
+        let from = user.status;
+
+        let attempts = state.failed_logins.entry(user_id).or_default();
+        *attempts += 1;
+        if threshold == 0 || *attempts < threshold || !from.can_transition_to(AccountStatus::Locked)
+        {
+            return Ok(Some(user));
+        }
+
+        let user = state.user_mut(user_id)?;
+        user.status = AccountStatus::Locked;
+        user.updated_at = Utc::now();
+        let user = user.clone();
+
+        state.record_transition(
+            user_id,
+            from,
+            AccountStatus::Locked,
+            Some("too many failed logins"),
+            None,
+        );
+        Ok(Some(user))
+    }
+
+    async fn reset_failed_logins(&self, user_id: Uuid) -> Result<(), sqlx::Error> {
+        self.state().failed_logins.remove(&user_id);
+        Ok(())
+    }
+
+    async fn get_status_transitions(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<StatusTransition>, sqlx::Error> {
+        Ok(self
+            .state()
+            .transitions
+            .iter()
+            .filter(|transition| transition.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_users_with_expired_suspension(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        Ok(self.select(|user| {
+            user.status == AccountStatus::Suspended
+                && user.suspended_until.is_some_and(|until| until <= now)
+        }))
+    }
+}
+
+/// What Postgres reports for a second account with the same email, so
+/// callers checking `is_unique_violation` behave the same on both.
+#[derive(Debug, thiserror::Error)]
+#[error("duplicate key value violates unique constraint \"users_email_key\"")]
+struct UniqueViolation;
+
+impl DatabaseError for UniqueViolation {
+    fn message(&self) -> &str {
+        "duplicate key value violates unique constraint \"users_email_key\""
+    }
+
+    fn code(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed("23505"))
+    }
+
+    fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+        self
+    }
+
+    fn constraint(&self) -> Option<&str> {
+        Some("users_email_key")
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::UniqueViolation
+    }
+}
@@ -1,2 +1,5 @@
+pub mod memory_impl;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_impl;
 pub mod user_trait;
 pub mod users_impl;
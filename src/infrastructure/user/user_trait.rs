@@ -1,11 +1,18 @@
+use std::fmt::Debug;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domains::user::{User, UserRole};
+use crate::domains::user::{
+    AccountStatus, ApprovalStatus, StatusChange, StatusTransition, User, UserFilter, UserRole,
+};
 
+/// Storage for user accounts. Object safe, so the server can hold whichever
+/// backend it was built with as `Arc<dyn UserRepository>`; every backend is
+/// held to the same behaviour by `tests/user_repository.rs`.
 #[async_trait]
-pub trait UserRepository {
+pub trait UserRepository: Debug + Send + Sync {
     async fn get_user(
         &self,
         user_id: Option<Uuid>,
@@ -15,21 +22,62 @@ pub trait UserRepository {
     ) -> Result<Option<User>, sqlx::Error>;
 
     async fn get_users(&self, page: u32, limit: usize) -> Result<Vec<User>, sqlx::Error>;
-    async fn save_user<T: Into<String> + Send>(
+
+    /// Newest first, like [`get_users`](Self::get_users).
+    async fn find_users(
+        &self,
+        filter: &UserFilter,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<User>, sqlx::Error>;
+    async fn save_user(
         &self,
-        name: T,
-        email: T,
-        password: T,
-        verification_token: T,
+        name: &str,
+        email: &str,
+        password: &str,
+        verification_token: &str,
         token_expires_at: DateTime<Utc>,
+        approval_status: ApprovalStatus,
+    ) -> Result<User, sqlx::Error>;
+
+    /// Creates an account that is already verified, for flows where the email
+    /// address has been proven some other way (e.g. an accepted invitation).
+    async fn save_invited_user(
+        &self,
+        name: &str,
+        email: &str,
+        password: &str,
+        role: UserRole,
     ) -> Result<User, sqlx::Error>;
 
     async fn get_user_count(&self) -> Result<i64, sqlx::Error>;
-    async fn update_username<T: Into<String> + Send>(
+
+    async fn get_users_by_approval_status(
+        &self,
+        approval_status: ApprovalStatus,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<User>, sqlx::Error>;
+
+    async fn get_user_count_by_approval_status(
+        &self,
+        approval_status: ApprovalStatus,
+    ) -> Result<i64, sqlx::Error>;
+
+    async fn get_admins(&self) -> Result<Vec<User>, sqlx::Error>;
+
+    async fn update_approval_status(
         &self,
         user_id: Uuid,
-        name: T,
+        approval_status: ApprovalStatus,
     ) -> Result<User, sqlx::Error>;
+    async fn update_username(&self, user_id: Uuid, name: &str) -> Result<User, sqlx::Error>;
+    async fn update_user_locale(
+        &self,
+        user_id: Uuid,
+        locale: Option<&str>,
+    ) -> Result<User, sqlx::Error>;
+
     async fn update_user_role(&self, user_id: Uuid, role: UserRole) -> Result<User, sqlx::Error>;
     async fn update_user_password(
         &self,
@@ -37,6 +85,8 @@ pub trait UserRepository {
         password: String,
     ) -> Result<User, sqlx::Error>;
 
+    /// Activates the account holding `token` if it is still waiting for email
+    /// verification, and drops the token so its link works only once.
     async fn verified_token(&self, token: &str) -> Result<(), sqlx::Error>;
 
     async fn add_verified_token(
@@ -45,4 +95,67 @@ pub trait UserRepository {
         token: &str,
         expires_at: DateTime<Utc>,
     ) -> Result<(), sqlx::Error>;
+
+    /// Drops the user's verification or reset token, so a link already sent
+    /// stops working.
+    async fn clear_verification_token(&self, user_id: Uuid) -> Result<(), sqlx::Error>;
+
+    /// Drops verification and reset tokens that expired before `now`.
+    /// Returns how many users had one.
+    async fn clear_expired_tokens(&self, now: DateTime<Utc>) -> Result<u64, sqlx::Error>;
+
+    /// Rescheduling an account already pending deletion only moves the date,
+    /// so cancelling still restores the status from before the first request.
+    /// Returns `None` when the account's status cannot move to deletion.
+    async fn schedule_deletion(
+        &self,
+        user_id: Uuid,
+        deletion_scheduled_at: DateTime<Utc>,
+    ) -> Result<Option<User>, sqlx::Error>;
+
+    async fn cancel_deletion(&self, user_id: Uuid) -> Result<bool, sqlx::Error>;
+
+    async fn get_users_due_for_deletion(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<User>, sqlx::Error>;
+
+    async fn delete_user(&self, user_id: Uuid) -> Result<(), sqlx::Error>;
+
+    /// Scrubs personal data from the row, drops the user's memberships and
+    /// moves the account to `Deleted`, keeping the id so historical references
+    /// stay valid. Returns `false` unless the account was pending deletion.
+    async fn anonymize_user(&self, user_id: Uuid) -> Result<bool, sqlx::Error>;
+
+    /// Moves the user from `from` to `change.to` and records the transition.
+    /// Returns `None` when the user is no longer in `from` or the lifecycle
+    /// has no such edge. Reactivating an account clears its failed logins.
+    async fn update_status(
+        &self,
+        user_id: Uuid,
+        from: AccountStatus,
+        change: StatusChange,
+    ) -> Result<Option<User>, sqlx::Error>;
+
+    /// Counts a failed password attempt. The `threshold`-th failure in a row
+    /// locks an active account and records the transition; 0 never locks.
+    /// Returns the account afterwards, or `None` when it no longer exists.
+    async fn record_failed_login(
+        &self,
+        user_id: Uuid,
+        threshold: u32,
+    ) -> Result<Option<User>, sqlx::Error>;
+
+    /// Starts the count of failed attempts over after a successful login.
+    async fn reset_failed_logins(&self, user_id: Uuid) -> Result<(), sqlx::Error>;
+
+    async fn get_status_transitions(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<StatusTransition>, sqlx::Error>;
+
+    async fn get_users_with_expired_suspension(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<User>, sqlx::Error>;
 }
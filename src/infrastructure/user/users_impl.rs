@@ -1,26 +1,60 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::{Pool, Postgres};
+use sqlx::{PgConnection, Pool, Postgres};
+use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
-    domains::user::{User, UserRole},
+    domains::user::{
+        AccountStatus, ApprovalStatus, StatusChange, StatusTransition, User, UserFilter, UserRole,
+    },
     infrastructure::database::database::DBClient,
 };
 
 use super::user_trait::UserRepository;
 
-pub struct PgUserRepository<'a> {
-    pub pool: &'a Pool<Postgres>,
+/// Unlike the other repositories this one owns its pool handle, so it can
+/// live in [`AppState`](crate::AppState) for the life of the process.
+#[derive(Debug)]
+pub struct PgUserRepository {
+    pub pool: Pool<Postgres>,
 }
 
-impl<'a> PgUserRepository<'a> {
-    pub fn new(db: &'a DBClient) -> Self {
-        Self { pool: &db.pool }
+impl PgUserRepository {
+    pub fn new(db: &DBClient) -> Self {
+        Self {
+            pool: db.pool.clone(),
+        }
+    }
+
+    async fn record_transition(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        from: AccountStatus,
+        to: AccountStatus,
+        reason: Option<&str>,
+        actor_id: Option<Uuid>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"INSERT INTO user_status_transitions (id, user_id, from_status, to_status, reason, actor_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .bind(reason)
+        .bind(actor_id)
+        .bind(Utc::now())
+        .execute(conn)
+        .await?;
+        Ok(())
     }
 }
 #[async_trait]
-impl<'a> UserRepository for PgUserRepository<'a> {
+impl UserRepository for PgUserRepository {
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_user(
         &self,
         user_id: Option<Uuid>,
@@ -31,27 +65,28 @@ impl<'a> UserRepository for PgUserRepository<'a> {
         if let Some(id) = user_id {
             sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
                 .bind(id)
-                .fetch_optional(self.pool)
+                .fetch_optional(&self.pool)
                 .await
         } else if let Some(n) = name {
             sqlx::query_as::<_, User>("SELECT * FROM users WHERE name = $1")
                 .bind(n)
-                .fetch_optional(self.pool)
+                .fetch_optional(&self.pool)
                 .await
         } else if let Some(e) = email {
             sqlx::query_as::<_, User>("SELECT * FROM users WHERE email = $1")
                 .bind(e)
-                .fetch_optional(self.pool)
+                .fetch_optional(&self.pool)
                 .await
         } else if let Some(t) = token {
             sqlx::query_as::<_, User>("SELECT * FROM users WHERE verification_token = $1")
                 .bind(t)
-                .fetch_optional(self.pool)
+                .fetch_optional(&self.pool)
                 .await
         } else {
             Ok(None)
         }
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_users(&self, page: u32, limit: usize) -> Result<Vec<User>, sqlx::Error> {
         let offset = (page.saturating_sub(1) as usize) * limit;
 
@@ -60,29 +95,62 @@ impl<'a> UserRepository for PgUserRepository<'a> {
         )
         .bind(limit as i64)
         .bind(offset as i64)
-        .fetch_all(self.pool)
+        .fetch_all(&self.pool)
         .await?;
 
         Ok(users)
     }
-    async fn save_user<T: Into<String> + Send>(
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn find_users(
+        &self,
+        filter: &UserFilter,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        let offset = (page.saturating_sub(1) as usize) * limit;
+        // LIKE wildcards in the search are matched literally.
+        let search = filter.search.as_ref().map(|search| {
+            let escaped = search
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            format!("%{}%", escaped)
+        });
+
+        sqlx::query_as::<_, User>(
+            r#"SELECT * FROM users
+            WHERE ($1::user_role IS NULL OR role = $1)
+            AND ($2::account_status IS NULL OR status = $2)
+            AND ($3::approval_status IS NULL OR approval_status = $3)
+            AND ($4::text IS NULL OR name ILIKE $4 OR email ILIKE $4)
+            ORDER BY created_at DESC LIMIT $5 OFFSET $6
+            "#,
+        )
+        .bind(filter.role)
+        .bind(filter.status)
+        .bind(filter.approval_status)
+        .bind(search)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn save_user(
         &self,
-        name: T,
-        email: T,
-        password: T,
-        verification_token: T,
+        name: &str,
+        email: &str,
+        password: &str,
+        verification_token: &str,
         token_expires_at: DateTime<Utc>,
+        approval_status: ApprovalStatus,
     ) -> Result<User, sqlx::Error> {
-        let name = name.into();
-        let email = email.into();
-        let password = password.into();
-        let verification_token = verification_token.into();
-
         let user = sqlx::query_as::<_, User>(
-            r#" INSERT INTO users ( id, name, email, password, role, verified, 
-                verification_token, token_expires_at, created_at, updated_at
+            r#" INSERT INTO users ( id, name, email, password, role, status, 
+                verification_token, token_expires_at, approval_status, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             RETURNING *
             "#,
         )
@@ -91,40 +159,137 @@ impl<'a> UserRepository for PgUserRepository<'a> {
         .bind(email)
         .bind(password)
         .bind(UserRole::User)
-        .bind(false)
+        .bind(AccountStatus::PendingVerification)
         .bind(verification_token)
         .bind(token_expires_at)
+        .bind(approval_status)
         .bind(Utc::now())
         .bind(Utc::now())
-        .fetch_one(self.pool)
+        .fetch_one(&self.pool)
         .await?;
 
         Ok(user)
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn save_invited_user(
+        &self,
+        name: &str,
+        email: &str,
+        password: &str,
+        role: UserRole,
+    ) -> Result<User, sqlx::Error> {
+        let user = sqlx::query_as::<_, User>(
+            r#" INSERT INTO users ( id, name, email, password, role, status, created_at, updated_at )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(name)
+        .bind(email)
+        .bind(password)
+        .bind(role)
+        .bind(AccountStatus::Active)
+        .bind(Utc::now())
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(user)
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_user_count(&self) -> Result<i64, sqlx::Error> {
         let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users")
-            .fetch_one(self.pool)
+            .fetch_one(&self.pool)
             .await?;
         Ok(row.0)
     }
 
-    async fn update_username<T: Into<String> + Send>(
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_users_by_approval_status(
+        &self,
+        approval_status: ApprovalStatus,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        let offset = (page.saturating_sub(1) as usize) * limit;
+
+        sqlx::query_as::<_, User>(
+            "SELECT * FROM users WHERE approval_status = $1 ORDER BY created_at ASC LIMIT $2 OFFSET $3",
+        )
+        .bind(approval_status)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_user_count_by_approval_status(
+        &self,
+        approval_status: ApprovalStatus,
+    ) -> Result<i64, sqlx::Error> {
+        let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users WHERE approval_status = $1")
+            .bind(approval_status)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(row.0)
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_admins(&self) -> Result<Vec<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>("SELECT * FROM users WHERE role = $1 ORDER BY created_at ASC")
+            .bind(UserRole::Admin)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn update_approval_status(
         &self,
         user_id: Uuid,
-        name: T,
+        approval_status: ApprovalStatus,
     ) -> Result<User, sqlx::Error> {
-        let name = name.into();
+        sqlx::query_as::<_, User>(
+            "UPDATE users SET approval_status = $1, updated_at = $2 WHERE id = $3 RETURNING *",
+        )
+        .bind(approval_status)
+        .bind(Utc::now())
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn update_username(&self, user_id: Uuid, name: &str) -> Result<User, sqlx::Error> {
         let user = sqlx::query_as::<_, User>(
             "UPDATE users SET name = $1, updated_at = $2 WHERE id = $3 RETURNING *",
         )
         .bind(name)
         .bind(Utc::now())
         .bind(user_id)
-        .fetch_one(self.pool)
+        .fetch_one(&self.pool)
         .await?;
         Ok(user)
     }
 
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn update_user_locale(
+        &self,
+        user_id: Uuid,
+        locale: Option<&str>,
+    ) -> Result<User, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "UPDATE users SET locale = $1, updated_at = $2 WHERE id = $3 RETURNING *",
+        )
+        .bind(locale)
+        .bind(Utc::now())
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_user_role(&self, user_id: Uuid, role: UserRole) -> Result<User, sqlx::Error> {
         let user = sqlx::query_as::<_, User>(
             "UPDATE users SET role = $1, updated_at = $2 WHERE id = $3 RETURNING *",
@@ -132,38 +297,62 @@ impl<'a> UserRepository for PgUserRepository<'a> {
         .bind(role)
         .bind(Utc::now())
         .bind(user_id)
-        .fetch_one(self.pool)
+        .fetch_one(&self.pool)
         .await?;
         Ok(user)
     }
 
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_user_password(
         &self,
         user_id: Uuid,
         password: String,
     ) -> Result<User, sqlx::Error> {
         let user = sqlx::query_as::<_, User>(
-            "UPDATE users SET password = $1, updated_at = $2 WHERE id::text = $3 RETURNING *",
+            "UPDATE users SET password = $1, updated_at = $2 WHERE id = $3 RETURNING *",
         )
         .bind(password)
         .bind(Utc::now())
         .bind(user_id)
-        .fetch_one(self.pool)
+        .fetch_one(&self.pool)
         .await?;
         Ok(user)
     }
 
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn verified_token(&self, token: &str) -> Result<(), sqlx::Error> {
-        sqlx::query(
-            "UPDATE users SET verified = true, updated_at = $1 WHERE verification_token = $2",
+        let mut tx = self.pool.begin().await?;
+
+        let verified: Option<(Uuid,)> = sqlx::query_as(
+            r#"UPDATE users SET status = $1, updated_at = $2, verification_token = NULL,
+                token_expires_at = NULL
+            WHERE verification_token = $3 AND status = $4
+            RETURNING id
+            "#,
         )
+        .bind(AccountStatus::Active)
         .bind(Utc::now())
         .bind(token)
-        .execute(self.pool)
+        .bind(AccountStatus::PendingVerification)
+        .fetch_optional(&mut *tx)
         .await?;
-        Ok(())
+
+        if let Some((user_id,)) = verified {
+            Self::record_transition(
+                &mut tx,
+                user_id,
+                AccountStatus::PendingVerification,
+                AccountStatus::Active,
+                Some("email verified"),
+                Some(user_id),
+            )
+            .await?;
+        }
+
+        tx.commit().await
     }
 
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn add_verified_token(
         &self,
         user_id: Uuid,
@@ -177,8 +366,351 @@ impl<'a> UserRepository for PgUserRepository<'a> {
         .bind(expires_at)
         .bind(Utc::now())
         .bind(user_id)
-        .execute(self.pool)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn clear_verification_token(&self, user_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"UPDATE users SET verification_token = NULL, token_expires_at = NULL, updated_at = $1
+            WHERE id = $2 AND verification_token IS NOT NULL
+            "#,
+        )
+        .bind(Utc::now())
+        .bind(user_id)
+        .execute(&self.pool)
         .await?;
         Ok(())
     }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn clear_expired_tokens(&self, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"UPDATE users SET verification_token = NULL, token_expires_at = NULL, updated_at = $1
+            WHERE token_expires_at < $1
+            "#,
+        )
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn schedule_deletion(
+        &self,
+        user_id: Uuid,
+        deletion_scheduled_at: DateTime<Utc>,
+    ) -> Result<Option<User>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let (from,): (AccountStatus,) =
+            sqlx::query_as("SELECT status FROM users WHERE id = $1 FOR UPDATE")
+                .bind(user_id)
+                .fetch_one(&mut *tx)
+                .await?;
+        if from != AccountStatus::PendingDeletion
+            && !from.can_transition_to(AccountStatus::PendingDeletion)
+        {
+            return Ok(None);
+        }
+
+        let user = sqlx::query_as::<_, User>(
+            r#"UPDATE users SET status = $1, deletion_scheduled_at = $2, updated_at = $3
+            WHERE id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(AccountStatus::PendingDeletion)
+        .bind(deletion_scheduled_at)
+        .bind(Utc::now())
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if from != AccountStatus::PendingDeletion {
+            Self::record_transition(
+                &mut tx,
+                user_id,
+                from,
+                AccountStatus::PendingDeletion,
+                Some("deletion requested"),
+                Some(user_id),
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(Some(user))
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn cancel_deletion(&self, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let previous: Option<(AccountStatus,)> = sqlx::query_as(
+            r#"SELECT from_status FROM user_status_transitions
+            WHERE user_id = $1 AND to_status = $2
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(AccountStatus::PendingDeletion)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let restored = previous
+            .map(|(status,)| status)
+            .filter(|status| AccountStatus::PendingDeletion.can_transition_to(*status))
+            .unwrap_or(AccountStatus::Active);
+
+        let result = sqlx::query(
+            r#"UPDATE users SET status = $1, deletion_scheduled_at = NULL, updated_at = $2
+            WHERE id = $3 AND status = $4 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(restored)
+        .bind(Utc::now())
+        .bind(user_id)
+        .bind(AccountStatus::PendingDeletion)
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        Self::record_transition(
+            &mut tx,
+            user_id,
+            AccountStatus::PendingDeletion,
+            restored,
+            Some("deletion cancelled"),
+            Some(user_id),
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_users_due_for_deletion(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "SELECT * FROM users WHERE status = $1 AND deletion_scheduled_at <= $2 AND deleted_at IS NULL",
+        )
+        .bind(AccountStatus::PendingDeletion)
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn delete_user(&self, user_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn anonymize_user(&self, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let from: Option<(AccountStatus,)> =
+            sqlx::query_as("SELECT status FROM users WHERE id = $1 FOR UPDATE")
+                .bind(user_id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        let Some((from,)) = from else {
+            return Ok(false);
+        };
+        if !from.can_transition_to(AccountStatus::Deleted) {
+            return Ok(false);
+        }
+
+        sqlx::query("DELETE FROM organization_memberships WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            r#"UPDATE users SET name = 'Deleted user',
+                email = 'deleted-' || id::text || '@deleted.invalid',
+                password = '', verification_token = NULL, suspended_reason = NULL, locale = NULL,
+                token_expires_at = NULL, deletion_scheduled_at = NULL, status = $1,
+                deleted_at = $2, updated_at = $2
+            WHERE id = $3
+            "#,
+        )
+        .bind(AccountStatus::Deleted)
+        .bind(Utc::now())
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        Self::record_transition(
+            &mut tx,
+            user_id,
+            from,
+            AccountStatus::Deleted,
+            Some("account purged"),
+            None,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn update_status(
+        &self,
+        user_id: Uuid,
+        from: AccountStatus,
+        change: StatusChange,
+    ) -> Result<Option<User>, sqlx::Error> {
+        if !from.can_transition_to(change.to) {
+            return Ok(None);
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let (suspended_reason, suspended_until) = match change.to {
+            AccountStatus::Suspended => (change.reason.as_deref(), change.suspended_until),
+            _ => (None, None),
+        };
+
+        let user = sqlx::query_as::<_, User>(
+            r#"UPDATE users SET status = $1, suspended_reason = $2, suspended_until = $3, updated_at = $4,
+                failed_login_attempts = CASE WHEN $1 = 'active' THEN 0 ELSE failed_login_attempts END
+            WHERE id = $5 AND status = $6
+            RETURNING *
+            "#,
+        )
+        .bind(change.to)
+        .bind(suspended_reason)
+        .bind(suspended_until)
+        .bind(Utc::now())
+        .bind(user_id)
+        .bind(from)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if user.is_none() {
+            return Ok(None);
+        }
+
+        Self::record_transition(
+            &mut tx,
+            user_id,
+            from,
+            change.to,
+            change.reason.as_deref(),
+            change.actor_id,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(user)
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn record_failed_login(
+        &self,
+        user_id: Uuid,
+        threshold: u32,
+    ) -> Result<Option<User>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let counted: Option<(AccountStatus, i32)> = sqlx::query_as(
+            r#"UPDATE users SET failed_login_attempts = failed_login_attempts + 1
+            WHERE id = $1
+            RETURNING status, failed_login_attempts
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some((from, attempts)) = counted else {
+            return Ok(None);
+        };
+
+        if threshold == 0
+            || (attempts as u32) < threshold
+            || !from.can_transition_to(AccountStatus::Locked)
+        {
+            let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+                .bind(user_id)
+                .fetch_one(&mut *tx)
+                .await?;
+            tx.commit().await?;
+            return Ok(Some(user));
+        }
+
+        let user = sqlx::query_as::<_, User>(
+            "UPDATE users SET status = $1, updated_at = $2 WHERE id = $3 RETURNING *",
+        )
+        .bind(AccountStatus::Locked)
+        .bind(Utc::now())
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Self::record_transition(
+            &mut tx,
+            user_id,
+            from,
+            AccountStatus::Locked,
+            Some("too many failed logins"),
+            None,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(user))
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn reset_failed_logins(&self, user_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE users SET failed_login_attempts = 0 WHERE id = $1 AND failed_login_attempts > 0",
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_status_transitions(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<StatusTransition>, sqlx::Error> {
+        sqlx::query_as::<_, StatusTransition>(
+            "SELECT * FROM user_status_transitions WHERE user_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_users_with_expired_suspension(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>("SELECT * FROM users WHERE status = $1 AND suspended_until <= $2")
+            .bind(AccountStatus::Suspended)
+            .bind(now)
+            .fetch_all(&self.pool)
+            .await
+    }
 }
@@ -0,0 +1,2 @@
+pub mod webhook_impl;
+pub mod webhook_trait;
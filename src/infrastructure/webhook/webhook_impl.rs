@@ -0,0 +1,235 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres, types::Json};
+use uuid::Uuid;
+
+use crate::{
+    domains::webhook::{
+        DueWebhookDelivery, WebhookDelivery, WebhookDeliveryStatus, WebhookEndpoint,
+    },
+    infrastructure::database::database::DBClient,
+};
+
+use super::webhook_trait::WebhookRepository;
+
+pub struct PgWebhookRepository<'a> {
+    pub pool: &'a Pool<Postgres>,
+}
+
+impl<'a> PgWebhookRepository<'a> {
+    pub fn new(db: &'a DBClient) -> Self {
+        Self { pool: &db.pool }
+    }
+}
+
+#[async_trait]
+impl<'a> WebhookRepository for PgWebhookRepository<'a> {
+    async fn save_endpoint(
+        &self,
+        url: &str,
+        secret: &str,
+        events: &[String],
+        created_by: Uuid,
+    ) -> Result<WebhookEndpoint, sqlx::Error> {
+        sqlx::query_as::<_, WebhookEndpoint>(
+            r#"INSERT INTO webhook_endpoints (id, url, secret, events, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(url)
+        .bind(secret)
+        .bind(events)
+        .bind(created_by)
+        .bind(Utc::now())
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn get_endpoint(
+        &self,
+        endpoint_id: Uuid,
+    ) -> Result<Option<WebhookEndpoint>, sqlx::Error> {
+        sqlx::query_as::<_, WebhookEndpoint>("SELECT * FROM webhook_endpoints WHERE id = $1")
+            .bind(endpoint_id)
+            .fetch_optional(self.pool)
+            .await
+    }
+
+    async fn get_endpoints(&self) -> Result<Vec<WebhookEndpoint>, sqlx::Error> {
+        sqlx::query_as::<_, WebhookEndpoint>(
+            "SELECT * FROM webhook_endpoints ORDER BY created_at DESC",
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_subscribed_endpoints(
+        &self,
+        event_type: &str,
+    ) -> Result<Vec<WebhookEndpoint>, sqlx::Error> {
+        sqlx::query_as::<_, WebhookEndpoint>(
+            r#"SELECT * FROM webhook_endpoints
+            WHERE active = TRUE AND (cardinality(events) = 0 OR $1 = ANY(events))
+            "#,
+        )
+        .bind(event_type)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn delete_endpoint(&self, endpoint_id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM webhook_endpoints WHERE id = $1")
+            .bind(endpoint_id)
+            .execute(self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn enqueue_delivery(
+        &self,
+        endpoint_id: Uuid,
+        event_id: Uuid,
+        event_type: &str,
+        payload: &serde_json::Value,
+    ) -> Result<WebhookDelivery, sqlx::Error> {
+        let now = Utc::now();
+        sqlx::query_as::<_, WebhookDelivery>(
+            r#"INSERT INTO webhook_deliveries (id, endpoint_id, event_id, event_type, payload, status, next_attempt_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(endpoint_id)
+        .bind(event_id)
+        .bind(event_type)
+        .bind(Json(payload))
+        .bind(WebhookDeliveryStatus::Pending)
+        .bind(now)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<DueWebhookDelivery>, sqlx::Error> {
+        sqlx::query_as::<_, DueWebhookDelivery>(
+            r#"UPDATE webhook_deliveries d
+            SET next_attempt_at = $1
+            FROM webhook_endpoints e
+            WHERE d.endpoint_id = e.id
+                AND d.id IN (
+                    SELECT id FROM webhook_deliveries
+                    WHERE status = $2 AND next_attempt_at <= $3
+                    ORDER BY next_attempt_at
+                    LIMIT $4
+                    FOR UPDATE SKIP LOCKED
+                )
+            RETURNING d.id, d.event_id, d.event_type, d.payload, d.attempts, e.url, e.secret
+            "#,
+        )
+        .bind(lease_until)
+        .bind(WebhookDeliveryStatus::Pending)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn mark_delivery_succeeded(
+        &self,
+        delivery_id: Uuid,
+        response_status: i32,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        sqlx::query(
+            r#"UPDATE webhook_deliveries
+            SET status = $1, attempts = attempts + 1, response_status = $2, last_error = NULL,
+                last_attempt_at = $3, delivered_at = $3
+            WHERE id = $4
+            "#,
+        )
+        .bind(WebhookDeliveryStatus::Succeeded)
+        .bind(response_status)
+        .bind(now)
+        .bind(delivery_id)
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_delivery_failed(
+        &self,
+        delivery_id: Uuid,
+        response_status: Option<i32>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        let status = match retry_at {
+            Some(_) => WebhookDeliveryStatus::Pending,
+            None => WebhookDeliveryStatus::Failed,
+        };
+
+        sqlx::query(
+            r#"UPDATE webhook_deliveries
+            SET status = $1, attempts = attempts + 1, response_status = $2, last_error = $3,
+                last_attempt_at = $4, next_attempt_at = $5
+            WHERE id = $6
+            "#,
+        )
+        .bind(status)
+        .bind(response_status)
+        .bind(error)
+        .bind(now)
+        .bind(retry_at.unwrap_or(now))
+        .bind(delivery_id)
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_delivery(
+        &self,
+        delivery_id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, sqlx::Error> {
+        sqlx::query_as::<_, WebhookDelivery>("SELECT * FROM webhook_deliveries WHERE id = $1")
+            .bind(delivery_id)
+            .fetch_optional(self.pool)
+            .await
+    }
+
+    async fn get_deliveries(
+        &self,
+        endpoint_id: Uuid,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, sqlx::Error> {
+        let offset = page.saturating_sub(1) * limit as u32;
+
+        sqlx::query_as::<_, WebhookDelivery>(
+            r#"SELECT * FROM webhook_deliveries
+            WHERE endpoint_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(endpoint_id)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    async fn get_delivery_count(&self, endpoint_id: Uuid) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM webhook_deliveries WHERE endpoint_id = $1")
+            .bind(endpoint_id)
+            .fetch_one(self.pool)
+            .await
+    }
+}
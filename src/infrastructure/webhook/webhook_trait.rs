@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domains::webhook::{DueWebhookDelivery, WebhookDelivery, WebhookEndpoint};
+
+#[async_trait]
+pub trait WebhookRepository {
+    async fn save_endpoint(
+        &self,
+        url: &str,
+        secret: &str,
+        events: &[String],
+        created_by: Uuid,
+    ) -> Result<WebhookEndpoint, sqlx::Error>;
+
+    async fn get_endpoint(&self, endpoint_id: Uuid)
+    -> Result<Option<WebhookEndpoint>, sqlx::Error>;
+
+    async fn get_endpoints(&self) -> Result<Vec<WebhookEndpoint>, sqlx::Error>;
+
+    /// Active endpoints whose filter includes `event_type`.
+    async fn get_subscribed_endpoints(
+        &self,
+        event_type: &str,
+    ) -> Result<Vec<WebhookEndpoint>, sqlx::Error>;
+
+    async fn delete_endpoint(&self, endpoint_id: Uuid) -> Result<bool, sqlx::Error>;
+
+    async fn enqueue_delivery(
+        &self,
+        endpoint_id: Uuid,
+        event_id: Uuid,
+        event_type: &str,
+        payload: &serde_json::Value,
+    ) -> Result<WebhookDelivery, sqlx::Error>;
+
+    /// Claims up to `limit` due deliveries by pushing their next attempt past
+    /// `lease_until`, so a crashed worker's claims are picked up again later.
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<DueWebhookDelivery>, sqlx::Error>;
+
+    async fn mark_delivery_succeeded(
+        &self,
+        delivery_id: Uuid,
+        response_status: i32,
+    ) -> Result<(), sqlx::Error>;
+
+    /// Records a failed attempt. Without `retry_at` the delivery is given up.
+    async fn mark_delivery_failed(
+        &self,
+        delivery_id: Uuid,
+        response_status: Option<i32>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), sqlx::Error>;
+
+    async fn get_delivery(&self, delivery_id: Uuid)
+    -> Result<Option<WebhookDelivery>, sqlx::Error>;
+
+    async fn get_deliveries(
+        &self,
+        endpoint_id: Uuid,
+        page: u32,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, sqlx::Error>;
+
+    async fn get_delivery_count(&self, endpoint_id: Uuid) -> Result<i64, sqlx::Error>;
+}
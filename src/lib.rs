@@ -1,24 +1,48 @@
-use api::router::create_router;
+use api::router::{create_health_router, create_metrics_router, create_router};
+use arc_swap::ArcSwap;
 use axum::{
     Extension, Router,
     http::{
-        Method,
+        HeaderName, Method,
         header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE},
     },
     middleware,
 };
-use config::{client::ClientConfig, database::Config};
+use config::{
+    app::{AppConfig, StartupOptions},
+    source::ConfigSource,
+};
+use core::{
+    account_deletion::spawn_account_worker,
+    audit_chain::spawn_checkpoint_worker,
+    config_reload::spawn_config_reloader,
+    job::spawn_job_workers,
+    metrics::{install_recorder, spawn_metrics_upkeep},
+    migration::prepare_schema,
+    shutdown::{Shutdown, shutdown_signal},
+    telemetry::{TRACEPARENT, init_tracing},
+    webhook::spawn_webhook_worker,
+};
+use helpers::mail::renderer::MailRenderer;
 use infrastructure::{
     database::database::DBClient,
-    middleware::{debug_after::debug_after, debug_before::debug_before},
+    mail::mail_trait::{MailTransport, create_transport},
+    middleware::{
+        metrics::track_metrics,
+        request_id::{X_REQUEST_ID, record_route, trace_request},
+        request_log::log_request,
+    },
+    user::{user_trait::UserRepository, users_impl::PgUserRepository},
 };
+use metrics_exporter_prometheus::PrometheusHandle;
 use sqlx::postgres::PgPoolOptions;
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::net::TcpListener;
-use tower_http::cors::CorsLayer;
-use tracing_subscriber::filter::LevelFilter;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tracing::{error, info, warn};
 
 pub mod api;
+pub mod cli;
 pub mod config;
 pub mod core;
 pub mod domains;
@@ -28,57 +52,313 @@ pub mod infrastructure;
 pub mod models;
 pub mod utils;
 
-#[derive(Debug, Clone)]
+/// Connections still checked out after the drain are not waited on longer
+/// than this.
+const POOL_CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
 pub struct AppState {
-    pub env: Config,
+    /// Swapped as a whole when the configuration is reloaded; read it through
+    /// [`AppState::config`].
+    pub config: ArcSwap<AppConfig>,
     pub db_client: DBClient,
+    /// Postgres in the server; tests and embedders can swap in another
+    /// backend.
+    pub user_repository: Arc<dyn UserRepository>,
+    pub mailer: Arc<dyn MailTransport>,
+    /// Rebuilt on reload so template changes on disk are picked up.
+    pub mail_renderer: ArcSwap<MailRenderer>,
+    pub shutdown: Shutdown,
+    /// Present when metrics are enabled.
+    pub metrics: Option<PrometheusHandle>,
+}
+
+impl AppState {
+    pub fn new(
+        config: AppConfig,
+        db_client: DBClient,
+        user_repository: Arc<dyn UserRepository>,
+        mailer: Arc<dyn MailTransport>,
+        mail_renderer: MailRenderer,
+        metrics: Option<PrometheusHandle>,
+    ) -> Self {
+        Self {
+            config: ArcSwap::from_pointee(config),
+            db_client,
+            user_repository,
+            mailer,
+            mail_renderer: ArcSwap::from_pointee(mail_renderer),
+            shutdown: Shutdown::new(),
+            metrics,
+        }
+    }
+
+    /// The configuration in effect right now.
+    pub fn config(&self) -> Arc<AppConfig> {
+        self.config.load_full()
+    }
+
+    pub fn mail_renderer(&self) -> Arc<MailRenderer> {
+        self.mail_renderer.load_full()
+    }
 }
 
 pub async fn run() {
-    tracing_subscriber::fmt()
-        .with_max_level(LevelFilter::DEBUG)
-        .init();
+    let options = match StartupOptions::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    let (config, source) = load_config(&options);
+
+    let tracer_provider = match init_tracing(&config.logging, &config.tracing) {
+        Ok(provider) => provider,
+        Err(e) => {
+            eprintln!("Failed to set up trace export: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Some(endpoint) = &config.tracing.otlp_endpoint {
+        info!(otlp.endpoint = %endpoint, "Exporting traces");
+    }
 
-    let config = Config::init();
     let pool = match PgPoolOptions::new()
         .max_connections(5)
-        .connect(&config.database_url)
+        .connect(&config.database.url)
         .await
     {
         Ok(pool) => {
-            println!("Connection to the database is successful!");
+            info!("Connected to the database");
             pool
         }
         Err(err) => {
-            println!("Failed to connect to the database: {}", err);
+            error!(error = %err, "Failed to connect to the database");
             std::process::exit(1);
         }
     };
+    let db_client = DBClient::new(pool);
+    let user_repository = Arc::new(PgUserRepository::new(&db_client));
+
+    let mailer = create_transport(&config.mail);
+    let mail_renderer = MailRenderer::new(&config.mail);
+    info!(
+        mail.transport = config.mail.transport.to_str(),
+        "Sending email through the configured transport"
+    );
+
+    let metrics = match config.metrics.enabled {
+        true => match install_recorder() {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                error!(error = %e, "Failed to install the metrics recorder");
+                None
+            }
+        },
+        false => None,
+    };
+
+    let port = config.port;
+    let metrics_config = config.metrics.clone();
+    let app_state = Arc::new(AppState::new(
+        config,
+        db_client,
+        user_repository,
+        mailer,
+        mail_renderer,
+        metrics,
+    ));
+
+    if let Err(e) = prepare_schema(&app_state).await {
+        error!(error = %e, "Failed to prepare the database schema");
+        std::process::exit(1);
+    }
+
+    // Checked per request so reloaded origins apply without a restart.
+    let cors_state = app_state.clone();
     let cors = CorsLayer::new()
-        .allow_origin(ClientConfig::init().allowed_origins)
-        .allow_headers([AUTHORIZATION, ACCEPT, CONTENT_TYPE])
+        .allow_origin(AllowOrigin::predicate(move |origin, _| {
+            cors_state
+                .config
+                .load()
+                .cors
+                .allowed_origins
+                .contains(origin)
+        }))
+        .allow_headers([
+            AUTHORIZATION,
+            ACCEPT,
+            CONTENT_TYPE,
+            X_REQUEST_ID,
+            HeaderName::from_static(TRACEPARENT),
+            HeaderName::from_static("tracestate"),
+        ])
+        .expose_headers([X_REQUEST_ID])
         .allow_credentials(true)
-        .allow_methods([Method::GET, Method::POST, Method::PUT]);
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE]);
 
-    let db_client = DBClient::new(pool);
+    spawn_account_worker(app_state.clone());
+    spawn_checkpoint_worker(app_state.clone());
+    spawn_webhook_worker(app_state.clone());
+    spawn_job_workers(app_state.clone());
+    spawn_config_reloader(app_state.clone(), options, source);
+    spawn_metrics_upkeep(app_state.clone());
 
-    let app_state = Arc::new(AppState {
-        env: config.clone(),
-        db_client,
-    });
+    let shutdown = app_state.shutdown.clone();
+    let db_client = app_state.db_client.clone();
+    let drain_timeout = app_state.config().shutdown.drain_timeout;
 
     let app = Router::new()
         .merge(create_router(app_state.clone()))
-        .layer(middleware::from_fn(debug_after))
-        .layer(middleware::from_fn(debug_before))
+        .route_layer(middleware::from_fn(track_metrics))
+        .route_layer(middleware::from_fn(record_route))
+        .layer(middleware::from_fn(log_request))
+        // Merged after the logging layer so probes do not flood the logs.
+        .merge(create_health_router());
+
+    let app = match (&app_state.metrics, metrics_config.port) {
+        (Some(_), None) => app.merge(create_metrics_router()),
+        (Some(_), Some(metrics_port)) => {
+            serve_metrics(
+                app_state.clone(),
+                SocketAddr::new(metrics_config.bind_address, metrics_port),
+            )
+            .await;
+            app
+        }
+        (None, _) => app,
+    };
+
+    let app = app
+        .layer(middleware::from_fn(trace_request))
         .layer(Extension(app_state))
         .layer(cors.clone());
 
-    println!("Server is running on http://localhost:{}", config.port);
+    info!(port, "Server is running");
 
-    let listener = TcpListener::bind(format!("0.0.0.0:{}", config.port))
+    let listener = TcpListener::bind(format!("0.0.0.0:{}", port))
         .await
         .unwrap();
 
-    axum::serve(listener, app).await.unwrap();
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown({
+        let shutdown = shutdown.clone();
+        async move {
+            let signal = shutdown_signal().await;
+            info!(
+                signal = %signal,
+                drain_timeout_secs = drain_timeout.as_secs(),
+                "Shutdown signal received, draining"
+            );
+            shutdown.begin();
+        }
+    });
+
+    // In-flight requests and background work share one drain timeout,
+    // counted from the signal.
+    let drained = async {
+        if let Err(e) = server.await {
+            error!(error = %e, "Server error");
+        }
+        shutdown.begin();
+        shutdown.wait_for_tasks().await;
+    };
+    let timed_out = async {
+        shutdown.draining().await;
+        tokio::time::sleep(drain_timeout).await;
+    };
+
+    tokio::select! {
+        _ = drained => info!("Drained all requests and background work"),
+        _ = timed_out => warn!(
+            running_tasks = shutdown.running_tasks(),
+            "Drain timeout reached, abandoning open connections and background tasks"
+        ),
+    }
+
+    if tokio::time::timeout(POOL_CLOSE_TIMEOUT, db_client.pool.close())
+        .await
+        .is_err()
+    {
+        warn!("Timed out closing the database pool");
+    }
+    if let Some(provider) = tracer_provider
+        && let Err(e) = provider.shutdown()
+    {
+        error!(error = %e, "Failed to flush traces");
+    }
+    info!("Server stopped");
+}
+
+/// Serves `/metrics` on its own port until shutdown starts.
+async fn serve_metrics(app_state: Arc<AppState>, address: SocketAddr) {
+    let listener = match TcpListener::bind(address).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!(metrics.address = %address, error = %e, "Failed to bind the metrics listener");
+            std::process::exit(1);
+        }
+    };
+    info!(metrics.address = %address, "Serving /metrics on its own listener");
+
+    let shutdown = app_state.shutdown.clone();
+    let app = create_metrics_router().layer(Extension(app_state));
+
+    shutdown.clone().spawn(async move {
+        if let Err(e) = axum::serve(listener, app)
+            .with_graceful_shutdown(async move { shutdown.draining().await })
+            .await
+        {
+            error!(error = %e, "Metrics server error");
+        }
+    });
+}
+
+/// Loads and checks the configuration from `source`, printing warnings to
+/// stderr. Unlike [`load_config`] it never exits, so the CLI can report
+/// problems through its own output.
+pub fn read_config(source: &mut ConfigSource) -> Result<AppConfig, Vec<String>> {
+    let config = AppConfig::load(source);
+
+    for warning in source.warnings() {
+        eprintln!("⚠️ {}", warning);
+    }
+
+    config
+}
+
+/// Loads and checks the configuration, exiting with every problem listed when
+/// it is invalid. With `--print-config` the resolved settings are printed,
+/// secrets redacted, and the process exits. The source is kept to tell
+/// which settings a later reload changes.
+pub fn load_config(options: &StartupOptions) -> (AppConfig, ConfigSource) {
+    let mut source = match ConfigSource::from_environment(options.config_file.as_deref()) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let config = read_config(&mut source);
+
+    if options.print_config {
+        print!("{}", source.render());
+    }
+
+    match config {
+        Ok(_) if options.print_config => std::process::exit(0),
+        Ok(config) => (config, source),
+        Err(errors) => {
+            eprintln!("Invalid configuration:");
+            for error in errors {
+                eprintln!("  - {}", error);
+            }
+            std::process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::audit_chain::{CHECKPOINT_ALGORITHM, ChainVerification},
+    domains::audit::{AuditCheckpoint, AuditEvent},
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterAuditEvent {
+    pub id: String,
+    #[serde(rename = "actorId")]
+    pub actor_id: Option<String>,
+    #[serde(rename = "targetUserId")]
+    pub target_user_id: Option<String>,
+    pub action: String,
+    #[serde(rename = "ipAddress")]
+    pub ip_address: Option<String>,
+    #[serde(rename = "userAgent")]
+    pub user_agent: Option<String>,
+    pub metadata: serde_json::Value,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterAuditEvent {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_audit_event(event: &AuditEvent) -> Self {
+        Self {
+            id: event.id.to_string(),
+            actor_id: event.actor_id.map(|id| id.to_string()),
+            target_user_id: event.target_user_id.map(|id| id.to_string()),
+            action: event.action.to_owned(),
+            ip_address: event.ip_address.to_owned(),
+            user_agent: event.user_agent.to_owned(),
+            metadata: event.metadata.0.clone(),
+            created_at: event.created_at,
+        }
+    }
+
+    pub fn filter_audit_events(events: &[AuditEvent]) -> Vec<Self> {
+        events
+            .iter()
+            .map(FilterAuditEvent::filter_audit_event)
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditEventListResponse {
+    pub status: String,
+    pub events: Vec<FilterAuditEvent>,
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterAuditCheckpoint {
+    pub id: String,
+    pub sequence: i64,
+    pub hash: String,
+    #[serde(rename = "eventCount")]
+    pub event_count: i64,
+    pub algorithm: String,
+    pub signature: String,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterAuditCheckpoint {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_audit_checkpoint(checkpoint: &AuditCheckpoint) -> Self {
+        Self {
+            id: checkpoint.id.to_string(),
+            sequence: checkpoint.sequence,
+            hash: checkpoint.hash.to_owned(),
+            event_count: checkpoint.event_count,
+            algorithm: CHECKPOINT_ALGORITHM.to_string(),
+            signature: checkpoint.signature.to_owned(),
+            created_at: checkpoint.created_at,
+        }
+    }
+
+    pub fn filter_audit_checkpoints(checkpoints: &[AuditCheckpoint]) -> Vec<Self> {
+        checkpoints
+            .iter()
+            .map(FilterAuditCheckpoint::filter_audit_checkpoint)
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditCheckpointResponse {
+    pub status: String,
+    pub checkpoint: FilterAuditCheckpoint,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditCheckpointListResponse {
+    pub status: String,
+    pub checkpoints: Vec<FilterAuditCheckpoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditVerificationResponse {
+    pub status: String,
+    pub verification: ChainVerification,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct UpdateEmailTemplate {
+    #[validate(length(min = 1, max = 500, message = "Subject must be 1-500 characters"))]
+    pub subject: String,
+    #[serde(rename = "htmlBody")]
+    #[validate(length(min = 1, message = "HTML body is required"))]
+    pub html_body: String,
+    #[serde(rename = "textBody")]
+    #[validate(length(min = 1, message = "Text body is required"))]
+    pub text_body: String,
+}
+
+/// Renders a draft when all three parts are given, otherwise whatever would
+/// be sent right now. `data` replaces the sample values.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PreviewEmailTemplate {
+    pub subject: Option<String>,
+    #[serde(rename = "htmlBody")]
+    pub html_body: Option<String>,
+    #[serde(rename = "textBody")]
+    pub text_body: Option<String>,
+    pub data: Option<serde_json::Value>,
+}
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{domains::email_template::EmailTemplate, helpers::mail::renderer::RenderedMail};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterEmailTemplate {
+    pub id: String,
+    pub name: String,
+    pub locale: String,
+    pub version: i32,
+    pub subject: String,
+    #[serde(rename = "htmlBody")]
+    pub html_body: String,
+    #[serde(rename = "textBody")]
+    pub text_body: String,
+    pub active: bool,
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<String>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterEmailTemplate {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_email_template(template: &EmailTemplate) -> Self {
+        Self {
+            id: template.id.to_string(),
+            name: template.name.to_owned(),
+            locale: template.locale.to_owned(),
+            version: template.version,
+            subject: template.subject.to_owned(),
+            html_body: template.html_body.to_owned(),
+            text_body: template.text_body.to_owned(),
+            active: template.active,
+            created_by: template.created_by.map(|id| id.to_string()),
+            created_at: template.created_at,
+        }
+    }
+
+    pub fn filter_email_templates(templates: &[EmailTemplate]) -> Vec<Self> {
+        templates
+            .iter()
+            .map(FilterEmailTemplate::filter_email_template)
+            .collect()
+    }
+}
+
+/// Subject and bodies, either as template source or as rendered output.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EmailTemplateBody {
+    pub subject: String,
+    #[serde(rename = "htmlBody")]
+    pub html_body: String,
+    #[serde(rename = "textBody")]
+    pub text_body: String,
+}
+
+impl From<[String; 3]> for EmailTemplateBody {
+    fn from([subject, html_body, text_body]: [String; 3]) -> Self {
+        Self {
+            subject,
+            html_body,
+            text_body,
+        }
+    }
+}
+
+impl From<RenderedMail> for EmailTemplateBody {
+    fn from(rendered: RenderedMail) -> Self {
+        Self {
+            subject: rendered.subject,
+            html_body: rendered.html_body,
+            text_body: rendered.text_body,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EmailTemplateSummary {
+    pub name: String,
+    pub locale: String,
+    /// Whether the binary ships a translation for this locale.
+    pub builtin: bool,
+    #[serde(rename = "activeVersion")]
+    pub active_version: Option<i32>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailTemplateListResponse {
+    pub status: String,
+    pub templates: Vec<EmailTemplateSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailTemplateDetailResponse {
+    pub status: String,
+    pub name: String,
+    pub locale: String,
+    pub builtin: Option<EmailTemplateBody>,
+    #[serde(rename = "activeVersion")]
+    pub active_version: Option<i32>,
+    pub versions: Vec<FilterEmailTemplate>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailTemplateResponse {
+    pub status: String,
+    pub template: FilterEmailTemplate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailTemplatePreviewResponse {
+    pub status: String,
+    pub locale: String,
+    pub preview: EmailTemplateBody,
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+    /// Not configured to run.
+    Skip,
+}
+
+/// The outcome of one check. Why a check failed is only logged: the
+/// endpoint is unauthenticated and errors can name hosts and users.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HealthCheck {
+    pub name: String,
+    pub status: CheckStatus,
+
+    #[serde(rename = "durationMs")]
+    pub duration_ms: u128,
+}
+
+impl HealthCheck {
+    pub fn pass(name: &str, duration_ms: u128) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Pass,
+            duration_ms,
+        }
+    }
+
+    pub fn fail(name: &str, duration_ms: u128) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Fail,
+            duration_ms,
+        }
+    }
+
+    pub fn skip(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Skip,
+            duration_ms: 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LivenessResponse {
+    pub status: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReadinessResponse {
+    pub status: String,
+    pub checks: Vec<HealthCheck>,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domains::{organization::OrganizationRole, user::UserRole};
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct CreateInvitation {
+    #[validate(
+        length(min = 1, message = "Email is required"),
+        email(message = "Email is invalid")
+    )]
+    pub email: String,
+    pub role: UserRole,
+    #[serde(rename = "organizationId")]
+    pub organization_id: Option<Uuid>,
+    #[serde(rename = "organizationRole")]
+    pub organization_role: Option<OrganizationRole>,
+}
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct AcceptInvitation {
+    #[validate(length(min = 1, message = "Token is required"))]
+    pub token: String,
+    #[validate(length(min = 1, message = "Name is required"))]
+    pub name: Option<String>,
+    #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
+    pub password: Option<String>,
+    #[serde(rename = "confirmPassword")]
+    pub confirm_password: Option<String>,
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domains::invitation::Invitation;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterInvitation {
+    pub id: String,
+    pub email: String,
+    pub role: String,
+    #[serde(rename = "organizationId")]
+    pub organization_id: Option<String>,
+    #[serde(rename = "organizationRole")]
+    pub organization_role: Option<String>,
+    pub status: String,
+
+    #[serde(rename = "expiresAt")]
+    pub expires_at: DateTime<Utc>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterInvitation {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_invitation(invitation: &Invitation) -> Self {
+        Self {
+            id: invitation.id.to_string(),
+            email: invitation.email.to_owned(),
+            role: invitation.role.to_str().to_string(),
+            organization_id: invitation.organization_id.map(|id| id.to_string()),
+            organization_role: invitation
+                .organization_role
+                .map(|role| role.to_str().to_string()),
+            status: invitation.status.to_str().to_string(),
+            expires_at: invitation.expires_at,
+            created_at: invitation.created_at,
+        }
+    }
+
+    pub fn filter_invitations(invitations: &[Invitation]) -> Vec<Self> {
+        invitations
+            .iter()
+            .map(FilterInvitation::filter_invitation)
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InvitationResponse {
+    pub status: String,
+    pub invitation: FilterInvitation,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InvitationListResponse {
+    pub status: String,
+    pub invitations: Vec<FilterInvitation>,
+    pub results: i64,
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domains::job::{Job, JobPayload};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterJob {
+    pub id: String,
+    pub kind: String,
+    /// Read from the payload, which itself is never returned since it holds
+    /// tokens and links. Unset when the payload cannot be decoded.
+    pub recipient: Option<String>,
+    pub summary: Option<String>,
+    pub status: String,
+    pub attempts: i32,
+    #[serde(rename = "maxAttempts")]
+    pub max_attempts: i32,
+    #[serde(rename = "runAt")]
+    pub run_at: DateTime<Utc>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<DateTime<Utc>>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl FilterJob {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_job(job: &Job) -> Self {
+        let payload = serde_json::from_value::<JobPayload>(job.payload.0.clone()).ok();
+
+        Self {
+            id: job.id.to_string(),
+            kind: job.kind.to_owned(),
+            recipient: payload
+                .as_ref()
+                .map(|payload| payload.recipient().to_string()),
+            summary: payload.as_ref().map(JobPayload::summary),
+            status: job.status.to_str().to_string(),
+            attempts: job.attempts,
+            max_attempts: job.max_attempts,
+            run_at: job.run_at,
+            last_error: job.last_error.to_owned(),
+            completed_at: job.completed_at,
+            created_at: job.created_at,
+            updated_at: job.updated_at,
+        }
+    }
+
+    pub fn filter_jobs(jobs: &[Job]) -> Vec<Self> {
+        jobs.iter().map(FilterJob::filter_job).collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobResponse {
+    pub status: String,
+    pub job: FilterJob,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobListResponse {
+    pub status: String,
+    pub jobs: Vec<FilterJob>,
+    pub results: i64,
+}
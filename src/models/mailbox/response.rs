@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domains::mail::OutgoingMail;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterMailboxMessage {
+    pub id: String,
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterMailboxMessage {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_mailbox_message(mail: &OutgoingMail) -> Self {
+        Self {
+            id: mail.id.to_string(),
+            from: mail.from.to_owned(),
+            to: mail.to.to_owned(),
+            subject: mail.subject.to_owned(),
+            created_at: mail.created_at,
+        }
+    }
+
+    pub fn filter_mailbox_messages(mails: &[OutgoingMail]) -> Vec<Self> {
+        mails
+            .iter()
+            .map(FilterMailboxMessage::filter_mailbox_message)
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MailHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MailboxMessageDetail {
+    #[serde(flatten)]
+    pub summary: FilterMailboxMessage,
+    /// Message headers as built for sending. MIME part headers are left out.
+    pub headers: Vec<MailHeader>,
+    #[serde(rename = "htmlBody")]
+    pub html_body: String,
+    #[serde(rename = "textBody")]
+    pub text_body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MailboxListResponse {
+    pub status: String,
+    pub messages: Vec<FilterMailboxMessage>,
+    pub results: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MailboxMessageResponse {
+    pub status: String,
+    pub message: MailboxMessageDetail,
+}
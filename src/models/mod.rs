@@ -1 +1,9 @@
+pub mod audit;
+pub mod email_template;
+pub mod health;
+pub mod invitation;
+pub mod job;
+pub mod mailbox;
+pub mod organization;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domains::organization::OrganizationRole;
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct CreateOrganization {
+    #[validate(length(min = 1, max = 100, message = "Name is required"))]
+    pub name: String,
+}
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct SwitchOrganization {
+    #[serde(rename = "organizationId")]
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct AddMember {
+    #[validate(
+        length(min = 1, message = "Email is required"),
+        email(message = "Email is invalid")
+    )]
+    pub email: String,
+    pub role: OrganizationRole,
+}
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct MemberRoleUpdate {
+    pub role: OrganizationRole,
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domains::organization::{Organization, OrganizationMember, UserOrganization};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterOrganization {
+    pub id: String,
+    pub name: String,
+    pub role: String,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl FilterOrganization {
+    pub fn from_user_organization(organization: &UserOrganization) -> Self {
+        Self {
+            id: organization.id.to_string(),
+            name: organization.name.to_owned(),
+            role: organization.role.to_str().to_string(),
+            created_at: organization.created_at,
+            updated_at: organization.updated_at,
+        }
+    }
+
+    pub fn from_created(organization: &Organization) -> Self {
+        Self {
+            id: organization.id.to_string(),
+            name: organization.name.to_owned(),
+            role: "owner".to_string(),
+            created_at: organization.created_at,
+            updated_at: organization.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterMember {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+
+    #[serde(rename = "joinedAt")]
+    pub joined_at: DateTime<Utc>,
+}
+
+impl FilterMember {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_member(member: &OrganizationMember) -> Self {
+        Self {
+            user_id: member.user_id.to_string(),
+            name: member.name.to_owned(),
+            email: member.email.to_owned(),
+            role: member.role.to_str().to_string(),
+            joined_at: member.created_at,
+        }
+    }
+
+    pub fn filter_members(members: &[OrganizationMember]) -> Vec<Self> {
+        members.iter().map(FilterMember::filter_member).collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrganizationResponse {
+    pub status: String,
+    pub organization: FilterOrganization,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrganizationListResponse {
+    pub status: String,
+    pub organizations: Vec<FilterOrganization>,
+    pub results: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MemberListResponse {
+    pub status: String,
+    pub members: Vec<FilterMember>,
+    pub results: usize,
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -11,3 +12,28 @@ pub struct LoginUser {
     #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
     pub password: String,
 }
+
+#[derive(Debug, Validate, Default, Clone, Serialize, Deserialize)]
+pub struct DeleteAccount {
+    #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Validate, Default, Clone, Serialize, Deserialize)]
+pub struct CancelDeletion {
+    #[validate(length(min = 1, message = "Token is required"))]
+    pub token: String,
+}
+
+#[derive(Debug, Validate, Default, Clone, Serialize, Deserialize)]
+pub struct SuspendUser {
+    #[validate(length(min = 1, max = 500, message = "Reason is required"))]
+    pub reason: String,
+    pub until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Validate, Default, Clone, Serialize, Deserialize)]
+pub struct LockUser {
+    #[validate(length(min = 1, max = 500, message = "Reason is required"))]
+    pub reason: String,
+}
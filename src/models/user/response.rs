@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domains::user::User;
+use crate::domains::user::{StatusTransition, User};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FilterUser {
@@ -9,7 +9,14 @@ pub struct FilterUser {
     pub name: String,
     pub email: String,
     pub role: String,
-    pub verified: bool,
+    pub status: String,
+    #[serde(rename = "suspendedReason")]
+    pub suspended_reason: Option<String>,
+    #[serde(rename = "suspendedUntil")]
+    pub suspended_until: Option<DateTime<Utc>>,
+    #[serde(rename = "approvalStatus")]
+    pub approval_status: String,
+    pub locale: Option<String>,
 
     #[serde(rename = "createdAt")]
     pub created_at: DateTime<Utc>,
@@ -19,12 +26,17 @@ pub struct FilterUser {
 }
 
 impl FilterUser {
+    #[allow(clippy::self_named_constructors)]
     pub fn filter_user(user: &User) -> Self {
         Self {
             id: user.id.to_string(),
             name: user.name.to_owned(),
             email: user.email.to_owned(),
-            verified: user.verified,
+            status: user.status.to_str().to_string(),
+            suspended_reason: user.suspended_reason.to_owned(),
+            suspended_until: user.suspended_until,
+            approval_status: user.approval_status.to_str().to_string(),
+            locale: user.locale.to_owned(),
             role: user.role.to_str().to_string(),
             created_at: user.created_at,
             updated_at: user.updated_at,
@@ -64,3 +76,39 @@ pub struct Response {
     pub status: &'static str,
     pub message: String,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FilterStatusTransition {
+    pub id: String,
+    #[serde(rename = "fromStatus")]
+    pub from_status: String,
+    #[serde(rename = "toStatus")]
+    pub to_status: String,
+    pub reason: Option<String>,
+    #[serde(rename = "actorId")]
+    pub actor_id: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterStatusTransition {
+    pub fn filter_transitions(transitions: &[StatusTransition]) -> Vec<Self> {
+        transitions
+            .iter()
+            .map(|transition| Self {
+                id: transition.id.to_string(),
+                from_status: transition.from_status.to_str().to_string(),
+                to_status: transition.to_status.to_str().to_string(),
+                reason: transition.reason.to_owned(),
+                actor_id: transition.actor_id.map(|id| id.to_string()),
+                created_at: transition.created_at,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusHistoryResponse {
+    pub status: String,
+    pub transitions: Vec<FilterStatusTransition>,
+}
@@ -8,6 +8,13 @@ pub struct NameUpdate {
     pub name: String,
 }
 
+/// `null` clears the preference and falls back to the default locale.
+#[derive(Debug, Validate, Default, Clone, Serialize, Deserialize)]
+pub struct LocaleUpdate {
+    #[validate(length(min = 2, max = 35, message = "locale must be 2 to 35 characters"))]
+    pub locale: Option<String>,
+}
+
 #[derive(Debug, Validate, Clone, Serialize, Deserialize)]
 pub struct RoleUpdate {
     #[validate(custom(function = "validate_user_role"))]
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::domains::webhook::WebhookEvent;
+
+#[derive(Debug, Validate, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookEndpoint {
+    #[validate(url(message = "URL is invalid"))]
+    pub url: String,
+    #[validate(length(min = 16, message = "Secret must be at least 16 characters"))]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub events: Vec<WebhookEvent>,
+}
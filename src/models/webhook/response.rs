@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domains::webhook::{WebhookDelivery, WebhookEndpoint};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterWebhookEndpoint {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub active: bool,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterWebhookEndpoint {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_webhook_endpoint(endpoint: &WebhookEndpoint) -> Self {
+        Self {
+            id: endpoint.id.to_string(),
+            url: endpoint.url.to_owned(),
+            events: endpoint.events.to_owned(),
+            active: endpoint.active,
+            created_at: endpoint.created_at,
+        }
+    }
+
+    pub fn filter_webhook_endpoints(endpoints: &[WebhookEndpoint]) -> Vec<Self> {
+        endpoints
+            .iter()
+            .map(FilterWebhookEndpoint::filter_webhook_endpoint)
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FilterWebhookDelivery {
+    pub id: String,
+    #[serde(rename = "eventId")]
+    pub event_id: String,
+    #[serde(rename = "eventType")]
+    pub event_type: String,
+    pub status: String,
+    pub attempts: i32,
+    #[serde(rename = "responseStatus")]
+    pub response_status: Option<i32>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+    #[serde(rename = "nextAttemptAt")]
+    pub next_attempt_at: DateTime<Utc>,
+    #[serde(rename = "lastAttemptAt")]
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    #[serde(rename = "deliveredAt")]
+    pub delivered_at: Option<DateTime<Utc>>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl FilterWebhookDelivery {
+    #[allow(clippy::self_named_constructors)]
+    pub fn filter_webhook_delivery(delivery: &WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id.to_string(),
+            event_id: delivery.event_id.to_string(),
+            event_type: delivery.event_type.to_owned(),
+            status: delivery.status.to_str().to_string(),
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error.to_owned(),
+            next_attempt_at: delivery.next_attempt_at,
+            last_attempt_at: delivery.last_attempt_at,
+            delivered_at: delivery.delivered_at,
+            created_at: delivery.created_at,
+        }
+    }
+
+    pub fn filter_webhook_deliveries(deliveries: &[WebhookDelivery]) -> Vec<Self> {
+        deliveries
+            .iter()
+            .map(FilterWebhookDelivery::filter_webhook_delivery)
+            .collect()
+    }
+}
+
+/// The secret is only ever returned here, right after creation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookEndpointCreatedResponse {
+    pub status: String,
+    pub endpoint: FilterWebhookEndpoint,
+    pub secret: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookEndpointListResponse {
+    pub status: String,
+    pub endpoints: Vec<FilterWebhookEndpoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookDeliveryResponse {
+    pub status: String,
+    pub delivery: FilterWebhookDelivery,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookDeliveryListResponse {
+    pub status: String,
+    pub deliveries: Vec<FilterWebhookDelivery>,
+    pub results: i64,
+}
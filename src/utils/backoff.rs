@@ -0,0 +1,26 @@
+/// Exponential backoff between retries: `base_seconds` after the first
+/// failed attempt, doubling after each one until it reaches `max_seconds`.
+#[derive(Debug, Clone, Copy)]
+pub struct Backoff {
+    pub base_seconds: i64,
+    pub max_seconds: i64,
+}
+
+impl Backoff {
+    pub const fn new(base_seconds: i64, max_seconds: i64) -> Self {
+        Self {
+            base_seconds,
+            max_seconds,
+        }
+    }
+
+    /// How long to wait after `attempts` failed attempts.
+    pub fn delay(&self, attempts: i32) -> chrono::Duration {
+        let exponent = attempts.clamp(1, 20) as u32 - 1;
+        let seconds = self
+            .base_seconds
+            .saturating_mul(2_i64.saturating_pow(exponent))
+            .min(self.max_seconds);
+        chrono::Duration::seconds(seconds)
+    }
+}
@@ -1,2 +1,4 @@
+pub mod backoff;
 pub mod password;
+pub mod redact;
 pub mod token;
@@ -3,7 +3,9 @@ use argon2::{
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
 };
 
-use crate::errors::error_message::ErrorMessage;
+use std::time::Instant;
+
+use crate::{core::metrics::record_password_hash, errors::error_message::ErrorMessage};
 
 const MAX_PASSWORD_LENGTH: usize = 64;
 
@@ -24,10 +26,14 @@ pub fn hash_password(password: impl AsRef<str>) -> Result<String, ErrorMessage>
     let salt = SaltString::generate(&mut OsRng);
     let argon2 = Argon2::default();
 
-    argon2
+    let started = Instant::now();
+    let hash = argon2
         .hash_password(password.as_bytes(), &salt)
         .map_err(|_| ErrorMessage::HashingError)
-        .map(|hash| hash.to_string())
+        .map(|hash| hash.to_string());
+    record_password_hash("hash", started.elapsed());
+
+    hash
 }
 
 pub fn compare(password: &str, hashed_password: &str) -> Result<bool, ErrorMessage> {
@@ -36,9 +42,11 @@ pub fn compare(password: &str, hashed_password: &str) -> Result<bool, ErrorMessa
     let parsed_hash =
         PasswordHash::new(hashed_password).map_err(|_| ErrorMessage::InvalidHashFormat)?;
 
+    let started = Instant::now();
     let compare = Argon2::default()
         .verify_password(password.as_bytes(), &parsed_hash)
         .is_ok();
+    record_password_hash("verify", started.elapsed());
 
     Ok(compare)
 }
@@ -0,0 +1,62 @@
+use axum::http::{HeaderMap, header};
+use serde_json::{Map, Value};
+
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers carrying credentials; their values are never logged.
+const SENSITIVE_HEADERS: &[header::HeaderName] = &[
+    header::AUTHORIZATION,
+    header::PROXY_AUTHORIZATION,
+    header::COOKIE,
+    header::SET_COOKIE,
+];
+
+/// Headers as a JSON object for logging, with credentials replaced.
+pub fn redact_headers(headers: &HeaderMap) -> Value {
+    let mut map = Map::new();
+
+    for (name, value) in headers {
+        let value = match SENSITIVE_HEADERS.contains(name) {
+            true => REDACTED.to_string(),
+            false => String::from_utf8_lossy(value.as_bytes()).into_owned(),
+        };
+        map.insert(name.as_str().to_string(), Value::String(value));
+    }
+
+    Value::Object(map)
+}
+
+/// Replaces, at any depth, the value of every object field whose name
+/// contains one of `fields`. Names are compared ignoring case, `_` and `-`,
+/// so `newPassword`, `new_password` and `NEW-PASSWORD` all match `password`.
+pub fn redact_json(value: &mut Value, fields: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (name, value) in map.iter_mut() {
+                if is_sensitive(name, fields) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value, fields);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                redact_json(item, fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_sensitive(name: &str, fields: &[String]) -> bool {
+    let name = normalize(name);
+    fields.iter().any(|field| name.contains(&normalize(field)))
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,180 @@
+//! Self-service deletion: the grace period, the recent-login check, cancel
+//! links, and the purge against Postgres when `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use axum_auth_backend::{
+    core::account_deletion::{
+        DELETION_CANCEL_PURPOSE, cancel_deletion_token, cancel_link_matches, deletion_due_at,
+        is_recent_auth, purge_due_accounts,
+    },
+    core::invitation::INVITATION_PURPOSE,
+    domains::user::{AccountStatus, ApprovalStatus, User},
+    infrastructure::user::{memory_impl::InMemoryUserRepository, user_trait::UserRepository},
+    utils::token,
+};
+use chrono::{Duration, Utc};
+use common::TestDatabase;
+
+const SECRET: &[u8] = b"test-secret";
+
+async fn create_user(repository: &dyn UserRepository, name: &str) -> User {
+    repository
+        .save_user(
+            name,
+            &format!("{}@example.com", name),
+            "hash",
+            "token",
+            Utc::now() + Duration::hours(1),
+            ApprovalStatus::Approved,
+        )
+        .await
+        .unwrap()
+}
+
+#[test]
+fn deletion_waits_out_the_grace_period() {
+    let now = Utc::now();
+
+    assert_eq!(deletion_due_at(now, 30), now + Duration::days(30));
+    assert_eq!(deletion_due_at(now, 0), now);
+}
+
+#[test]
+fn only_a_fresh_login_skips_the_password() {
+    let now = Utc::now();
+
+    assert!(is_recent_auth(now, now));
+    assert!(is_recent_auth(now - Duration::minutes(4), now));
+    assert!(is_recent_auth(now - Duration::minutes(5), now));
+    assert!(!is_recent_auth(now - Duration::minutes(6), now));
+    assert!(!is_recent_auth(now - Duration::days(1), now));
+}
+
+#[tokio::test]
+async fn a_cancel_link_works_for_the_deletion_it_was_sent_for() {
+    let repository = InMemoryUserRepository::new();
+    let user = create_user(&repository, "ann").await;
+    let status_before = user.status;
+    let scheduled_at = deletion_due_at(Utc::now(), 30);
+    let user = repository
+        .schedule_deletion(user.id, scheduled_at)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let link = cancel_deletion_token(&user, scheduled_at, SECRET).unwrap();
+    let claims = token::decode_signed_link_token(&link, DELETION_CANCEL_PURPOSE, SECRET).unwrap();
+    assert!(cancel_link_matches(&user, &claims));
+
+    // Links are single-purpose and signed.
+    assert!(token::decode_signed_link_token(&link, INVITATION_PURPOSE, SECRET).is_err());
+    assert!(token::decode_signed_link_token(&link, DELETION_CANCEL_PURPOSE, b"other").is_err());
+
+    // Asking again moves the date, which retires the first link.
+    let rescheduled_at = scheduled_at + Duration::days(1);
+    let user = repository
+        .schedule_deletion(user.id, rescheduled_at)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!cancel_link_matches(&user, &claims));
+
+    assert!(repository.cancel_deletion(user.id).await.unwrap());
+    let user = repository
+        .get_user(Some(user.id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.status, status_before);
+    let link = cancel_deletion_token(&user, rescheduled_at, SECRET).unwrap();
+    let claims = token::decode_signed_link_token(&link, DELETION_CANCEL_PURPOSE, SECRET).unwrap();
+    assert!(!cancel_link_matches(&user, &claims));
+}
+
+#[tokio::test]
+async fn a_cancel_link_belongs_to_one_account() {
+    let repository = InMemoryUserRepository::new();
+    let scheduled_at = deletion_due_at(Utc::now(), 30);
+    let ann = create_user(&repository, "ann").await;
+    let ann = repository
+        .schedule_deletion(ann.id, scheduled_at)
+        .await
+        .unwrap()
+        .unwrap();
+    let bob = create_user(&repository, "bob").await;
+    let bob = repository
+        .schedule_deletion(bob.id, scheduled_at)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let link = cancel_deletion_token(&ann, scheduled_at, SECRET).unwrap();
+    let claims = token::decode_signed_link_token(&link, DELETION_CANCEL_PURPOSE, SECRET).unwrap();
+
+    assert!(!cancel_link_matches(&bob, &claims));
+}
+
+#[tokio::test]
+async fn a_cancel_link_expires_with_the_grace_period() {
+    let repository = InMemoryUserRepository::new();
+    let user = create_user(&repository, "ann").await;
+    let scheduled_at = Utc::now() - Duration::minutes(5);
+    let user = repository
+        .schedule_deletion(user.id, scheduled_at)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let link = cancel_deletion_token(&user, scheduled_at, SECRET).unwrap();
+
+    assert!(token::decode_signed_link_token(&link, DELETION_CANCEL_PURPOSE, SECRET).is_err());
+}
+
+#[tokio::test]
+async fn replicas_purge_each_account_once() {
+    let Some(db) = TestDatabase::connect("account_deletion").await else {
+        return;
+    };
+    let app_state = db.app_state(&[("APP_ACCOUNT_DELETION_STRATEGY", "delete")]);
+    let repository = app_state.user_repository.as_ref();
+
+    let mut due = Vec::new();
+    for name in ["ann", "bob", "carol"] {
+        let user = create_user(repository, name).await;
+        repository
+            .schedule_deletion(user.id, Utc::now() - Duration::minutes(1))
+            .await
+            .unwrap();
+        due.push(user.id);
+    }
+    let waiting = create_user(repository, "dave").await;
+    repository
+        .schedule_deletion(waiting.id, deletion_due_at(Utc::now(), 30))
+        .await
+        .unwrap();
+
+    let (first, second) = tokio::join!(
+        purge_due_accounts(&app_state),
+        purge_due_accounts(&app_state)
+    );
+    assert_eq!(first + second, 3);
+
+    for id in due {
+        assert!(
+            repository
+                .get_user(Some(id), None, None, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+    let waiting = repository
+        .get_user(Some(waiting.id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(waiting.status, AccountStatus::PendingDeletion);
+
+    db.teardown().await;
+}
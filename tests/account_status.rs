@@ -0,0 +1,82 @@
+//! The account lifecycle table, checked edge by edge.
+
+use axum_auth_backend::domains::user::AccountStatus::{self, *};
+
+const STATUSES: [AccountStatus; 6] = [
+    PendingVerification,
+    Active,
+    Suspended,
+    Locked,
+    PendingDeletion,
+    Deleted,
+];
+
+const ALLOWED: [(AccountStatus, AccountStatus); 12] = [
+    (PendingVerification, Active),
+    (PendingVerification, Suspended),
+    (PendingVerification, PendingDeletion),
+    (Active, Suspended),
+    (Active, Locked),
+    (Active, PendingDeletion),
+    (Suspended, Active),
+    (Locked, Active),
+    (Locked, Suspended),
+    (PendingDeletion, Active),
+    (PendingDeletion, PendingVerification),
+    (PendingDeletion, Deleted),
+];
+
+#[test]
+fn allows_exactly_the_listed_edges() {
+    for from in STATUSES {
+        for to in STATUSES {
+            assert_eq!(
+                from.can_transition_to(to),
+                ALLOWED.contains(&(from, to)),
+                "{} -> {}",
+                from.to_str(),
+                to.to_str()
+            );
+        }
+    }
+}
+
+#[test]
+fn no_status_moves_to_itself() {
+    for status in STATUSES {
+        assert!(!status.can_transition_to(status), "{}", status.to_str());
+    }
+}
+
+#[test]
+fn deleted_is_terminal_and_only_reached_from_pending_deletion() {
+    for status in STATUSES {
+        assert!(!Deleted.can_transition_to(status));
+        assert_eq!(
+            status.can_transition_to(Deleted),
+            status == PendingDeletion,
+            "{}",
+            status.to_str()
+        );
+    }
+}
+
+#[test]
+fn only_active_accounts_can_be_locked() {
+    for status in STATUSES {
+        assert_eq!(
+            status.can_transition_to(Locked),
+            status == Active,
+            "{}",
+            status.to_str()
+        );
+    }
+}
+
+#[test]
+fn held_accounts_can_return_to_active() {
+    // Unsuspend and cancel-deletion both need a way back to active.
+    for status in [Suspended, Locked, PendingDeletion, PendingVerification] {
+        assert!(status.can_transition_to(Active), "{}", status.to_str());
+    }
+}
@@ -0,0 +1,286 @@
+//! The audit hash chain: the Rust hash agrees with the SQL trigger, and
+//! verification notices tampering. Runs against Postgres when
+//! `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use axum::{Extension, extract::Path, http::header};
+use axum_auth_backend::{
+    AppState,
+    api::admin::handlers::export_audit_checkpoint::export_audit_checkpoint,
+    core::{
+        audit::{audit_event, record_event},
+        audit_chain::{GENESIS_HASH, create_checkpoint, event_hash, verify_chain},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::UserRole,
+    },
+    infrastructure::{
+        audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+        middleware::client_info::ClientInfo,
+    },
+};
+use common::TestDatabase;
+use serde_json::json;
+use sqlx::Executor;
+
+/// Appends `count` login events and returns their sequences, oldest first.
+async fn append(app_state: &AppState, count: usize) -> Vec<i64> {
+    let client = ClientInfo::default();
+    for n in 0..count {
+        let event = NewAuditEvent {
+            metadata: json!({ "n": n }),
+            ..audit_event(AuditAction::LoginFailed, &client)
+        };
+        record_event(app_state, event).await;
+    }
+
+    PgAuditRepository::new(&app_state.db_client)
+        .get_chain_links(0, 1000)
+        .await
+        .unwrap()
+        .iter()
+        .map(|link| link.sequence)
+        .collect()
+}
+
+/// Runs `sql` with the append-only guard lifted, the way someone with direct
+/// database access could.
+async fn tamper(db: &TestDatabase, sql: &str) {
+    db.pool()
+        .execute(
+            format!(
+                "ALTER TABLE audit_events DISABLE TRIGGER audit_events_no_update; {}; \
+                 ALTER TABLE audit_events ENABLE TRIGGER audit_events_no_update",
+                sql
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn rust_and_sql_hash_events_alike() {
+    let Some(db) = TestDatabase::connect("audit_chain_hash").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let user = app_state
+        .user_repository
+        .save_invited_user("Zoë", "zoe@example.com", "hash", UserRole::User)
+        .await
+        .unwrap();
+
+    // Every optional column empty, then every one set to awkward values.
+    record_event(
+        &app_state,
+        audit_event(AuditAction::LoginFailed, &ClientInfo::default()),
+    )
+    .await;
+    let client = ClientInfo {
+        ip_address: Some("2001:db8::1".to_string()),
+        user_agent: Some("Agent/1.0 (ünïcode; \"quoted\")\nsecond line".to_string()),
+    };
+    let event = NewAuditEvent {
+        actor_id: Some(user.id),
+        target_user_id: Some(user.id),
+        metadata: json!({
+            "name": "Zoë ✓",
+            "nested": { "b": 1, "a": [1, 2.5, null, true] },
+            "text": "tab\tand \"quotes\"",
+        }),
+        ..audit_event(AuditAction::PasswordChanged, &client)
+    };
+    record_event(&app_state, event).await;
+
+    let links = PgAuditRepository::new(&db.db_client)
+        .get_chain_links(0, 10)
+        .await
+        .unwrap();
+    assert_eq!(links.len(), 2);
+
+    let mut prev_hash = GENESIS_HASH.to_string();
+    for link in &links {
+        let (sql_hash,): (String,) =
+            sqlx::query_as("SELECT audit_event_hash($1, e) FROM audit_events e WHERE id = $2")
+                .bind(&prev_hash)
+                .bind(link.id)
+                .fetch_one(db.pool())
+                .await
+                .unwrap();
+
+        assert_eq!(link.prev_hash, prev_hash);
+        assert_eq!(event_hash(&prev_hash, link), sql_hash);
+        assert_eq!(link.hash, sql_hash);
+        prev_hash = link.hash.clone();
+    }
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn an_intact_chain_verifies_with_its_checkpoints() {
+    let Some(db) = TestDatabase::connect("audit_chain_intact").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+
+    append(&app_state, 3).await;
+    assert!(create_checkpoint(&app_state).await.unwrap().is_some());
+    // Nothing new since, so there is nothing to sign.
+    assert!(create_checkpoint(&app_state).await.unwrap().is_none());
+    append(&app_state, 2).await;
+    let checkpoint = create_checkpoint(&app_state).await.unwrap().unwrap();
+
+    let verification = verify_chain(&app_state).await.unwrap();
+    assert!(verification.valid);
+    assert_eq!(verification.events_checked, 5);
+    assert_eq!(verification.checkpoints_checked, 2);
+    assert!(verification.broken_link.is_none());
+    assert_eq!(verification.head_hash, Some(checkpoint.hash));
+
+    let export = export_audit_checkpoint(Extension(app_state.clone()), Path(checkpoint.id))
+        .await
+        .unwrap();
+    assert_eq!(
+        export.headers()[header::CONTENT_DISPOSITION],
+        "attachment; filename=\"audit-checkpoint.json\""
+    );
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn detects_an_edited_event() {
+    let Some(db) = TestDatabase::connect("audit_chain_edit").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let sequences = append(&app_state, 4).await;
+
+    tamper(
+        &db,
+        &format!(
+            r#"UPDATE audit_events SET metadata = '{{"n": 99}}' WHERE sequence = {}"#,
+            sequences[2]
+        ),
+    )
+    .await;
+
+    let verification = verify_chain(&app_state).await.unwrap();
+    assert!(!verification.valid);
+    assert!(verification.head_hash.is_none());
+    let broken = verification.broken_link.unwrap();
+    assert_eq!(broken.sequence, sequences[2]);
+    assert_eq!(broken.reason, "event content does not match its hash");
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn detects_a_deleted_event() {
+    let Some(db) = TestDatabase::connect("audit_chain_delete").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let sequences = append(&app_state, 4).await;
+
+    tamper(
+        &db,
+        &format!("DELETE FROM audit_events WHERE sequence = {}", sequences[1]),
+    )
+    .await;
+
+    let broken = verify_chain(&app_state).await.unwrap().broken_link.unwrap();
+    assert_eq!(broken.sequence, sequences[2]);
+    assert_eq!(
+        broken.reason,
+        "previous hash does not match the preceding event"
+    );
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn detects_reordered_events() {
+    let Some(db) = TestDatabase::connect("audit_chain_reorder").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let sequences = append(&app_state, 4).await;
+
+    let (first, second) = (sequences[1], sequences[2]);
+    tamper(
+        &db,
+        &format!(
+            "UPDATE audit_events SET sequence = -1 WHERE sequence = {first}; \
+             UPDATE audit_events SET sequence = {first} WHERE sequence = {second}; \
+             UPDATE audit_events SET sequence = {second} WHERE sequence = -1"
+        ),
+    )
+    .await;
+
+    let verification = verify_chain(&app_state).await.unwrap();
+    assert!(!verification.valid);
+    assert_eq!(verification.events_checked, 1);
+    assert_eq!(verification.broken_link.unwrap().sequence, first);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn detects_a_removed_tail_covered_by_a_checkpoint() {
+    let Some(db) = TestDatabase::connect("audit_chain_tail").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let sequences = append(&app_state, 3).await;
+    create_checkpoint(&app_state).await.unwrap().unwrap();
+
+    // The chain that remains is consistent on its own; only the checkpoint
+    // shows that it used to be longer.
+    tamper(
+        &db,
+        &format!("DELETE FROM audit_events WHERE sequence = {}", sequences[2]),
+    )
+    .await;
+
+    let broken = verify_chain(&app_state).await.unwrap().broken_link.unwrap();
+    assert_eq!(broken.sequence, sequences[2]);
+    assert!(broken.event_id.is_none());
+    assert_eq!(
+        broken.reason,
+        "event covered by a signed checkpoint is missing"
+    );
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn detects_a_bad_checkpoint_signature() {
+    let Some(db) = TestDatabase::connect("audit_chain_signature").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let sequences = append(&app_state, 2).await;
+    let checkpoint = create_checkpoint(&app_state).await.unwrap().unwrap();
+
+    // Signed under another key.
+    let other_key = db.app_state(&[("APP_AUDIT_SIGNING_KEY", "another-signing-key")]);
+    let broken = verify_chain(&other_key).await.unwrap().broken_link.unwrap();
+    assert_eq!(broken.sequence, sequences[1]);
+    assert_eq!(broken.reason, "checkpoint signature is invalid");
+
+    // Or forged in place, to cover a rewritten chain.
+    sqlx::query("UPDATE audit_checkpoints SET event_count = event_count + 1 WHERE id = $1")
+        .bind(checkpoint.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    let broken = verify_chain(&app_state).await.unwrap().broken_link.unwrap();
+    assert_eq!(broken.reason, "checkpoint signature is invalid");
+
+    db.teardown().await;
+}
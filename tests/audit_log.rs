@@ -0,0 +1,242 @@
+//! Filtering and paging the audit log the way `GET /api/admin/audit` does,
+//! against Postgres when `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::{Extension, body::to_bytes, extract::Query, http::StatusCode, response::IntoResponse};
+use axum_auth_backend::{
+    AppState,
+    api::admin::handlers::get_audit_events::{RequestQuery, get_audit_events},
+    core::audit::{audit_event, record_event},
+    domains::{
+        audit::{AuditAction, AuditFilter, NewAuditEvent},
+        user::UserRole,
+    },
+    infrastructure::{
+        audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+        middleware::client_info::ClientInfo,
+    },
+    models::audit::response::AuditEventListResponse,
+};
+use chrono::{Duration, Utc};
+use common::TestDatabase;
+
+fn query() -> RequestQuery {
+    RequestQuery {
+        actor_id: None,
+        action: None,
+        from: None,
+        to: None,
+        cursor: None,
+        limit: None,
+    }
+}
+
+async fn list(
+    app_state: &Arc<AppState>,
+    query: RequestQuery,
+) -> Result<AuditEventListResponse, StatusCode> {
+    let response = match get_audit_events(Query(query), Extension(app_state.clone())).await {
+        Ok(response) => response.into_response(),
+        Err(e) => return Err(e.into_response().status()),
+    };
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    Ok(serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn filters_by_actor_action_and_time() {
+    let Some(db) = TestDatabase::connect("audit_query").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let ann = app_state
+        .user_repository
+        .save_invited_user("Ann", "ann@example.com", "hash", UserRole::Admin)
+        .await
+        .unwrap();
+    let bob = app_state
+        .user_repository
+        .save_invited_user("Bob", "bob@example.com", "hash", UserRole::User)
+        .await
+        .unwrap();
+
+    let client = ClientInfo::default();
+    for (actor, action) in [
+        (ann.id, AuditAction::LoginSucceeded),
+        (bob.id, AuditAction::LoginSucceeded),
+        (ann.id, AuditAction::AdminUserSuspended),
+        (bob.id, AuditAction::PasswordChanged),
+    ] {
+        let event = NewAuditEvent {
+            actor_id: Some(actor),
+            ..audit_event(action, &client)
+        };
+        record_event(&app_state, event).await;
+    }
+
+    let all = list(&app_state, query()).await.unwrap();
+    let actions: Vec<&str> = all.events.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(
+        actions,
+        [
+            "user.password_changed",
+            "admin.user_suspended",
+            "auth.login_succeeded",
+            "auth.login_succeeded",
+        ]
+    );
+    assert!(all.next_cursor.is_none());
+
+    let by_ann = list(
+        &app_state,
+        RequestQuery {
+            actor_id: Some(ann.id),
+            ..query()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(by_ann.events.len(), 2);
+    let ann_id = ann.id.to_string();
+    assert!(
+        by_ann
+            .events
+            .iter()
+            .all(|e| e.actor_id.as_deref() == Some(ann_id.as_str()))
+    );
+
+    let logins_by_bob = list(
+        &app_state,
+        RequestQuery {
+            actor_id: Some(bob.id),
+            action: Some("auth.login_succeeded".to_string()),
+            ..query()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(logins_by_bob.events.len(), 1);
+
+    // `from` is inclusive and `to` exclusive.
+    let suspended_at = all.events[1].created_at;
+    let since = list(
+        &app_state,
+        RequestQuery {
+            from: Some(suspended_at),
+            ..query()
+        },
+    )
+    .await
+    .unwrap();
+    assert!(since.events.iter().all(|e| e.created_at >= suspended_at));
+    assert!(since.events.iter().any(|e| e.id == all.events[1].id));
+
+    let until = list(
+        &app_state,
+        RequestQuery {
+            to: Some(suspended_at),
+            ..query()
+        },
+    )
+    .await
+    .unwrap();
+    assert!(until.events.iter().all(|e| e.created_at < suspended_at));
+
+    let future = list(
+        &app_state,
+        RequestQuery {
+            from: Some(Utc::now() + Duration::hours(1)),
+            ..query()
+        },
+    )
+    .await
+    .unwrap();
+    assert!(future.events.is_empty());
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn pages_through_every_event_once() {
+    let Some(db) = TestDatabase::connect("audit_paging").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+
+    let client = ClientInfo::default();
+    for _ in 0..7 {
+        record_event(&app_state, audit_event(AuditAction::LoginFailed, &client)).await;
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor = None;
+    let mut pages = 0;
+    loop {
+        let page = list(
+            &app_state,
+            RequestQuery {
+                cursor,
+                limit: Some(3),
+                ..query()
+            },
+        )
+        .await
+        .unwrap();
+        pages += 1;
+        assert!(page.events.len() <= 3);
+        seen.extend(page.events.into_iter().map(|e| e.id));
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    assert_eq!(pages, 3);
+
+    // Newest first, no event twice and none skipped.
+    let expected: Vec<String> = PgAuditRepository::new(&db.db_client)
+        .get_events(&AuditFilter::default(), 100)
+        .await
+        .unwrap()
+        .iter()
+        .map(|e| e.id.to_string())
+        .collect();
+    assert_eq!(seen, expected);
+
+    // An exact multiple of the page size ends without an empty extra page.
+    let first = list(
+        &app_state,
+        RequestQuery {
+            limit: Some(7),
+            ..query()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(first.events.len(), 7);
+    assert!(first.next_cursor.is_none());
+
+    let bad_cursor = list(
+        &app_state,
+        RequestQuery {
+            cursor: Some("not-a-number".to_string()),
+            ..query()
+        },
+    )
+    .await;
+    assert_eq!(bad_cursor.unwrap_err(), StatusCode::BAD_REQUEST);
+
+    let too_many = list(
+        &app_state,
+        RequestQuery {
+            limit: Some(101),
+            ..query()
+        },
+    )
+    .await;
+    assert_eq!(too_many.unwrap_err(), StatusCode::BAD_REQUEST);
+
+    db.teardown().await;
+}
@@ -0,0 +1,362 @@
+//! Argument parsing, and each command run against its own Postgres schema
+//! when `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use std::process::Command as Process;
+
+use axum_auth_backend::{
+    AppState,
+    cli::{
+        args::{Cli, Command, OutputFormat},
+        execute,
+        output::CommandOutput,
+    },
+    domains::user::{AccountStatus, ApprovalStatus, User, UserRole},
+    infrastructure::{
+        audit::{audit_impl::PgAuditRepository, audit_trait::AuditRepository},
+        database::migrations::MIGRATOR,
+    },
+};
+use chrono::{Duration, Utc};
+use clap::Parser;
+use common::TestDatabase;
+use uuid::Uuid;
+
+#[test]
+fn global_options_apply_after_the_subcommand() {
+    let cli = Cli::try_parse_from([
+        "auth-admin",
+        "set-role",
+        "ops@example.com",
+        "admin",
+        "--output",
+        "json",
+        "--config",
+        "prod.toml",
+    ])
+    .unwrap();
+
+    assert_eq!(cli.output, OutputFormat::Json);
+    assert_eq!(cli.config.unwrap().to_str(), Some("prod.toml"));
+    match cli.command {
+        Command::SetRole { user, role } => {
+            assert_eq!(user, "ops@example.com");
+            assert_eq!(role, UserRole::Admin);
+        }
+        command => panic!("parsed as {:?}", command),
+    }
+}
+
+#[test]
+fn list_filters_use_the_api_names() {
+    let cli = Cli::try_parse_from([
+        "auth-admin",
+        "list-users",
+        "--status",
+        "pending_verification",
+        "--limit",
+        "5",
+    ])
+    .unwrap();
+
+    assert_eq!(cli.output, OutputFormat::Human);
+    match cli.command {
+        Command::ListUsers {
+            role,
+            status,
+            page,
+            limit,
+            ..
+        } => {
+            assert_eq!(role, None);
+            assert_eq!(status, Some(AccountStatus::PendingVerification));
+            assert_eq!((page, limit), (1, 5));
+        }
+        command => panic!("parsed as {:?}", command),
+    }
+}
+
+#[test]
+fn unknown_values_are_rejected() {
+    assert!(Cli::try_parse_from(["auth-admin", "set-role", "ops@example.com", "owner"]).is_err());
+    assert!(Cli::try_parse_from(["auth-admin", "list-users", "--approval", "maybe"]).is_err());
+}
+
+/// Parses `args` as `auth-admin` would and runs the command.
+async fn run(app_state: &AppState, args: &[&str]) -> Result<CommandOutput, String> {
+    let cli = Cli::try_parse_from(["auth-admin"].iter().chain(args)).unwrap();
+    execute(app_state, cli.command).await
+}
+
+async fn find(app_state: &AppState, user_id: Uuid) -> User {
+    app_state
+        .user_repository
+        .get_user(Some(user_id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn audited_actions(app_state: &AppState, user_id: Uuid) -> Vec<String> {
+    PgAuditRepository::new(&app_state.db_client)
+        .get_events_for_user(user_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|event| event.action)
+        .collect()
+}
+
+/// The token at the end of a reset link.
+fn link_token(link: &str) -> &str {
+    link.rsplit("token=").next().unwrap()
+}
+
+#[tokio::test]
+async fn create_admin_makes_an_active_admin_with_a_reset_link() {
+    let Some(db) = TestDatabase::connect("cli_create_admin").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+
+    let output = run(
+        &app_state,
+        &[
+            "create-admin",
+            "--name",
+            "Ann",
+            "--email",
+            "ann@example.com",
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(
+        output.message.starts_with("Created admin ann@example.com"),
+        "{}",
+        output.message
+    );
+
+    let user_id: Uuid = output.data["user"]["id"].as_str().unwrap().parse().unwrap();
+    let user = find(&app_state, user_id).await;
+    assert_eq!(user.role, UserRole::Admin);
+    assert_eq!(user.status, AccountStatus::Active);
+
+    // The printed link carries the token stored for the account.
+    let link = output.data["resetLink"].as_str().unwrap();
+    assert!(output.message.contains(link));
+    assert_eq!(user.verification_token.as_deref(), Some(link_token(link)));
+    assert_eq!(
+        audited_actions(&app_state, user_id).await,
+        ["auth.registered"]
+    );
+
+    let again = run(
+        &app_state,
+        &[
+            "create-admin",
+            "--name",
+            "Ann",
+            "--email",
+            "ann@example.com",
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert!(again.contains("already has an account"), "{}", again);
+
+    let invalid = run(
+        &app_state,
+        &["create-admin", "--name", "Bob", "--email", "not-an-address"],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(invalid, "not-an-address is not a valid email address");
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn set_role_changes_the_role_once() {
+    let Some(db) = TestDatabase::connect("cli_set_role").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let bob = app_state
+        .user_repository
+        .save_invited_user("Bob", "bob@example.com", "hash", UserRole::User)
+        .await
+        .unwrap();
+
+    let output = run(&app_state, &["set-role", "bob@example.com", "admin"])
+        .await
+        .unwrap();
+    assert_eq!(output.message, "bob@example.com is now admin (was user)");
+    assert_eq!(find(&app_state, bob.id).await.role, UserRole::Admin);
+    assert!(
+        audited_actions(&app_state, bob.id)
+            .await
+            .contains(&"user.role_changed".to_string())
+    );
+
+    // By id this time; nothing left to change.
+    let output = run(&app_state, &["set-role", &bob.id.to_string(), "admin"])
+        .await
+        .unwrap();
+    assert_eq!(output.message, "bob@example.com is already admin");
+
+    let missing = run(&app_state, &["set-role", "nobody@example.com", "user"])
+        .await
+        .unwrap_err();
+    assert_eq!(missing, "No user matches nobody@example.com");
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn verify_user_activates_the_account_and_spends_the_link() {
+    let Some(db) = TestDatabase::connect("cli_verify_user").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let ann = app_state
+        .user_repository
+        .save_user(
+            "Ann",
+            "ann@example.com",
+            "hash",
+            "emailed-token",
+            Utc::now() + Duration::hours(24),
+            ApprovalStatus::Approved,
+        )
+        .await
+        .unwrap();
+
+    let output = run(&app_state, &["verify-user", "ann@example.com"])
+        .await
+        .unwrap();
+    assert_eq!(output.message, "Verified ann@example.com");
+    assert_eq!(output.data["user"]["status"], "active");
+
+    let verified = find(&app_state, ann.id).await;
+    assert_eq!(verified.status, AccountStatus::Active);
+    assert!(verified.verification_token.is_none());
+    assert!(
+        app_state
+            .user_repository
+            .get_user(None, None, None, Some("emailed-token"))
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        audited_actions(&app_state, ann.id)
+            .await
+            .contains(&"auth.email_verified".to_string())
+    );
+
+    let output = run(&app_state, &["verify-user", &ann.id.to_string()])
+        .await
+        .unwrap();
+    assert_eq!(
+        output.message,
+        "ann@example.com is active, nothing to verify"
+    );
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn reset_password_prints_a_link_for_a_stored_token() {
+    let Some(db) = TestDatabase::connect("cli_reset_password").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let ann = app_state
+        .user_repository
+        .save_invited_user("Ann", "ann@example.com", "hash", UserRole::User)
+        .await
+        .unwrap();
+
+    let before = Utc::now();
+    let output = run(&app_state, &["reset-password", "ann@example.com"])
+        .await
+        .unwrap();
+    let link = output.data["resetLink"].as_str().unwrap();
+    assert_eq!(
+        output.message,
+        format!("Reset link for ann@example.com: {}", link)
+    );
+    assert_eq!(output.data["userId"], ann.id.to_string());
+
+    let user = find(&app_state, ann.id).await;
+    assert_eq!(user.verification_token.as_deref(), Some(link_token(link)));
+    let expires_at = user.token_expires_at.unwrap();
+    assert!(expires_at >= before + Duration::hours(24));
+    assert!(expires_at <= Utc::now() + Duration::hours(24));
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn migrate_check_fails_while_migrations_are_pending() {
+    let Some(db) = TestDatabase::connect("cli_migrate").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+
+    let output = run(&app_state, &["migrate", "--check"]).await.unwrap();
+    assert!(
+        output.message.starts_with("Schema is current"),
+        "{}",
+        output.message
+    );
+    assert_eq!(output.data["pending"], serde_json::json!([]));
+
+    let latest = MIGRATOR
+        .iter()
+        .map(|migration| migration.version)
+        .max()
+        .unwrap();
+    sqlx::query("DELETE FROM _sqlx_migrations WHERE version = $1")
+        .bind(latest)
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let error = run(&app_state, &["migrate", "--check"]).await.unwrap_err();
+    assert!(
+        error.starts_with("Schema is not current")
+            && error.contains(&format!("1 pending ({})", latest)),
+        "{}",
+        error
+    );
+
+    db.teardown().await;
+}
+
+#[test]
+fn invalid_configuration_is_reported_through_the_output() {
+    // An empty directory and environment, so no setting is found anywhere.
+    let dir = std::env::temp_dir().join(format!("auth-admin-{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let output = Process::new(env!("CARGO_BIN_EXE_auth-admin"))
+        .args(["--output", "json", "purge-expired-tokens"])
+        .env_clear()
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "fail");
+    let message = report["message"].as_str().unwrap();
+    assert!(
+        message.starts_with("Invalid configuration: ") && message.contains("database.url"),
+        "{}",
+        message
+    );
+}
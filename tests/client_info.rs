@@ -0,0 +1,150 @@
+//! Which address a request is attributed to, with and without trusted
+//! proxies in front of the server.
+
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{HeaderMap, HeaderValue, Request},
+};
+use axum_auth_backend::{
+    config::{
+        proxy::{ProxyConfig, TrustedNetwork},
+        source::ConfigSource,
+    },
+    infrastructure::middleware::client_info::{ClientInfo, client_ip},
+};
+
+fn proxies(networks: &[&str]) -> ProxyConfig {
+    ProxyConfig {
+        trusted_addresses: networks
+            .iter()
+            .map(|network| network.parse().unwrap())
+            .collect(),
+    }
+}
+
+fn forwarded(hops: &[&str]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for hop in hops {
+        headers.append("x-forwarded-for", HeaderValue::from_str(hop).unwrap());
+    }
+    headers
+}
+
+fn ip(value: &str) -> Option<IpAddr> {
+    Some(value.parse().unwrap())
+}
+
+#[test]
+fn untrusted_peers_are_recorded_under_their_own_address() {
+    let headers = forwarded(&["203.0.113.9"]);
+
+    assert_eq!(
+        client_ip(&headers, ip("198.51.100.7"), &ProxyConfig::default()),
+        ip("198.51.100.7")
+    );
+    assert_eq!(
+        client_ip(&headers, ip("198.51.100.7"), &proxies(&["10.0.0.0/8"])),
+        ip("198.51.100.7")
+    );
+    assert_eq!(client_ip(&headers, None, &proxies(&["10.0.0.0/8"])), None);
+}
+
+#[test]
+fn trusted_proxies_pass_on_the_nearest_untrusted_hop() {
+    let proxy = proxies(&["10.0.0.0/8", "192.0.2.1"]);
+
+    // A client can prepend whatever it likes; only hops added by trusted
+    // proxies are skipped.
+    let headers = forwarded(&["6.6.6.6, 203.0.113.9, 192.0.2.1"]);
+    assert_eq!(
+        client_ip(&headers, ip("10.1.2.3"), &proxy),
+        ip("203.0.113.9")
+    );
+
+    // Several headers read as one list, in order.
+    let headers = forwarded(&["6.6.6.6", "203.0.113.9", "10.9.9.9"]);
+    assert_eq!(
+        client_ip(&headers, ip("10.1.2.3"), &proxy),
+        ip("203.0.113.9")
+    );
+
+    // Without the header the proxy itself is all there is.
+    assert_eq!(
+        client_ip(&HeaderMap::new(), ip("10.1.2.3"), &proxy),
+        ip("10.1.2.3")
+    );
+
+    // A chain of nothing but trusted hops ends at the farthest one.
+    let headers = forwarded(&["10.0.0.2, 192.0.2.1"]);
+    assert_eq!(client_ip(&headers, ip("10.1.2.3"), &proxy), ip("10.0.0.2"));
+}
+
+#[test]
+fn a_malformed_hop_ends_the_chain() {
+    let proxy = proxies(&["10.0.0.0/8"]);
+    let headers = forwarded(&["203.0.113.9, not-an-ip, 10.0.0.2"]);
+
+    assert_eq!(client_ip(&headers, ip("10.1.2.3"), &proxy), ip("10.0.0.2"));
+}
+
+#[test]
+fn matches_networks_by_prefix() {
+    let network: TrustedNetwork = "10.0.0.0/8".parse().unwrap();
+    assert!(network.contains("10.255.0.1".parse().unwrap()));
+    assert!(!network.contains("11.0.0.1".parse().unwrap()));
+    // IPv4 peers on a dual-stack socket show up mapped into IPv6.
+    assert!(network.contains("::ffff:10.0.0.1".parse().unwrap()));
+
+    let network: TrustedNetwork = "2001:db8::/32".parse().unwrap();
+    assert!(network.contains("2001:db8:1::1".parse().unwrap()));
+    assert!(!network.contains("2001:db9::1".parse().unwrap()));
+    assert!(!network.contains("10.0.0.1".parse().unwrap()));
+
+    let single: TrustedNetwork = "192.0.2.1".parse().unwrap();
+    assert!(single.contains("192.0.2.1".parse().unwrap()));
+    assert!(!single.contains("192.0.2.2".parse().unwrap()));
+
+    let everything: TrustedNetwork = "0.0.0.0/0".parse().unwrap();
+    assert!(everything.contains("203.0.113.9".parse().unwrap()));
+
+    assert!("10.0.0.0/33".parse::<TrustedNetwork>().is_err());
+    assert!("proxy.internal".parse::<TrustedNetwork>().is_err());
+}
+
+#[test]
+fn invalid_trusted_addresses_are_config_errors() {
+    let vars = HashMap::from([(
+        "APP_PROXY_TRUSTED_ADDRESSES".to_string(),
+        "10.0.0.0/8, nonsense".to_string(),
+    )]);
+    let mut source = ConfigSource::from_parts(None, vars).unwrap();
+
+    let config = ProxyConfig::load(&mut source);
+    assert_eq!(config.trusted_addresses.len(), 1);
+
+    let errors = source.finish().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("nonsense"));
+}
+
+#[tokio::test]
+async fn the_extractor_ignores_forwarded_headers_without_trusted_proxies() {
+    let request = Request::builder()
+        .header("x-forwarded-for", "203.0.113.9")
+        .header("user-agent", "curl/8.0")
+        .extension(ConnectInfo(SocketAddr::from(([198, 51, 100, 7], 4000))))
+        .body(())
+        .unwrap();
+    let (mut parts, ()) = request.into_parts();
+
+    let client = ClientInfo::from_request_parts(&mut parts, &())
+        .await
+        .unwrap();
+    assert_eq!(client.ip_address.as_deref(), Some("198.51.100.7"));
+    assert_eq!(client.user_agent.as_deref(), Some("curl/8.0"));
+}
@@ -0,0 +1,115 @@
+//! Postgres scaffolding shared by the integration tests. A test that needs the
+//! database calls [`TestDatabase::connect`] and returns early when it yields
+//! `None`, so the suite still passes where `TEST_DATABASE_URL` is not set.
+
+#![allow(dead_code)]
+
+use std::{collections::HashMap, sync::Arc};
+
+use axum_auth_backend::{
+    AppState,
+    config::{app::AppConfig, source::ConfigSource},
+    helpers::mail::renderer::MailRenderer,
+    infrastructure::{
+        database::{database::DBClient, migrations::MIGRATOR},
+        mail::memory_impl::InMemoryMailTransport,
+        user::users_impl::PgUserRepository,
+    },
+};
+use sqlx::{Executor, PgPool, postgres::PgPoolOptions};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+pub struct TestDatabase {
+    pub db_client: DBClient,
+    schema: String,
+}
+
+impl TestDatabase {
+    /// A freshly migrated schema of its own, named after `prefix`.
+    pub async fn connect(prefix: &str) -> Option<Self> {
+        static EXTENSIONS: OnceCell<()> = OnceCell::const_new();
+
+        let url = std::env::var("TEST_DATABASE_URL").ok()?;
+
+        // Installed once up front; tests creating it concurrently would collide.
+        EXTENSIONS
+            .get_or_init(|| async {
+                let pool = PgPool::connect(&url).await.unwrap();
+                pool.execute(r#"CREATE EXTENSION IF NOT EXISTS "uuid-ossp" SCHEMA public"#)
+                    .await
+                    .unwrap();
+                pool.close().await;
+            })
+            .await;
+
+        let schema = format!("{}_{}", prefix, Uuid::new_v4().simple());
+        let setup = format!(
+            "CREATE SCHEMA IF NOT EXISTS {0}; SET search_path TO {0}, public",
+            schema
+        );
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .after_connect(move |conn, _| {
+                let setup = setup.clone();
+                Box::pin(async move {
+                    conn.execute(setup.as_str()).await?;
+                    Ok(())
+                })
+            })
+            .connect(&url)
+            .await
+            .unwrap();
+        MIGRATOR.run(&pool).await.unwrap();
+
+        Some(Self {
+            db_client: DBClient::new(pool),
+            schema,
+        })
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.db_client.pool
+    }
+
+    /// Server state on this schema, mailing into memory. `vars` are set on
+    /// top of the few settings every configuration needs.
+    pub fn app_state(&self, vars: &[(&str, &str)]) -> Arc<AppState> {
+        let settings: HashMap<String, String> = [
+            ("APP_DATABASE_URL", "postgresql://unused"),
+            ("APP_JWT_SECRET_KEY", "test-secret"),
+            ("APP_JWT_MAXAGE", "60"),
+            ("APP_CORS_ALLOWED_ORIGINS", "http://localhost:3000"),
+            ("APP_AUDIT_SIGNING_KEY", "test-signing-key"),
+            // The memory transport is refused outside development.
+            ("APP_ENV", "development"),
+            ("APP_MAIL_TRANSPORT", "memory"),
+        ]
+        .iter()
+        .chain(vars)
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+        let mut source = ConfigSource::from_parts(None, settings).unwrap();
+        let config = AppConfig::load(&mut source).unwrap();
+        let mail_renderer = MailRenderer::try_new(&config.mail).unwrap();
+
+        Arc::new(AppState::new(
+            config,
+            self.db_client.clone(),
+            Arc::new(PgUserRepository::new(&self.db_client)),
+            Arc::new(InMemoryMailTransport::new()),
+            mail_renderer,
+            None,
+        ))
+    }
+
+    /// Drops the schema. Left in place when a test panics, for inspection.
+    pub async fn teardown(self) {
+        let pool = self.db_client.pool;
+        pool.execute(format!("DROP SCHEMA {} CASCADE", self.schema).as_str())
+            .await
+            .unwrap();
+        pool.close().await;
+    }
+}
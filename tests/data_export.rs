@@ -0,0 +1,169 @@
+//! The shape of a user's data export and the lifetime of its payload, against
+//! Postgres when `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use axum_auth_backend::{
+    core::{
+        audit::{audit_event, record_event},
+        data_export::{EXPORT_FORMAT, build_export, clear_expired_exports},
+    },
+    domains::{
+        audit::{AuditAction, NewAuditEvent},
+        user::ApprovalStatus,
+    },
+    infrastructure::{
+        data_export::{
+            data_export_impl::PgDataExportRepository, data_export_trait::DataExportRepository,
+        },
+        middleware::client_info::ClientInfo,
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+    },
+};
+use chrono::{Duration, Utc};
+use common::TestDatabase;
+use serde_json::json;
+
+#[tokio::test]
+async fn an_export_holds_every_section() {
+    let Some(db) = TestDatabase::connect("data_export").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let user = app_state
+        .user_repository
+        .save_user(
+            "Ann",
+            "ann@example.com",
+            "secret-hash",
+            "secret-token",
+            Utc::now() + Duration::hours(1),
+            ApprovalStatus::Approved,
+        )
+        .await
+        .unwrap();
+    PgOrganizationRepository::new(&db.db_client)
+        .create_organization("Acme", user.id)
+        .await
+        .unwrap();
+
+    let client = ClientInfo {
+        ip_address: Some("203.0.113.7".to_string()),
+        user_agent: Some("curl/8".to_string()),
+    };
+    for action in [
+        AuditAction::LoginFailed,
+        AuditAction::LoginSucceeded,
+        AuditAction::PasswordChanged,
+    ] {
+        let event = NewAuditEvent {
+            actor_id: Some(user.id),
+            target_user_id: Some(user.id),
+            ..audit_event(action, &client)
+        };
+        record_event(&app_state, event).await;
+    }
+
+    let export = build_export(&app_state, &user).await.unwrap();
+
+    assert_eq!(export["format"], EXPORT_FORMAT);
+    let mut sections: Vec<&str> = export
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    sections.sort();
+    assert_eq!(
+        sections,
+        [
+            "auditEvents",
+            "exportedAt",
+            "format",
+            "invitations",
+            "loginHistory",
+            "organizations",
+            "profile",
+            "statusHistory",
+        ]
+    );
+
+    assert_eq!(export["profile"]["email"], "ann@example.com");
+    assert_eq!(export["organizations"][0]["name"], "Acme");
+    assert_eq!(export["auditEvents"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        export["loginHistory"],
+        json!([
+            {
+                "succeeded": false,
+                "ipAddress": "203.0.113.7",
+                "userAgent": "curl/8",
+                "createdAt": export["loginHistory"][0]["createdAt"],
+            },
+            {
+                "succeeded": true,
+                "ipAddress": "203.0.113.7",
+                "userAgent": "curl/8",
+                "createdAt": export["loginHistory"][1]["createdAt"],
+            },
+        ])
+    );
+
+    // Credentials are not personal data to hand back.
+    let text = export.to_string();
+    assert!(!text.contains("secret-hash"));
+    assert!(!text.contains("secret-token"));
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn expired_exports_lose_their_payload() {
+    let Some(db) = TestDatabase::connect("data_export").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let user = app_state
+        .user_repository
+        .save_user(
+            "Ann",
+            "ann@example.com",
+            "hash",
+            "token",
+            Utc::now() + Duration::hours(1),
+            ApprovalStatus::Approved,
+        )
+        .await
+        .unwrap();
+    let repository = PgDataExportRepository::new(&db.db_client);
+
+    let expired = repository.save_export(user.id).await.unwrap();
+    repository
+        .complete_export(
+            expired.id,
+            json!({ "a": 1 }),
+            Utc::now() - Duration::minutes(1),
+        )
+        .await
+        .unwrap();
+    let current = repository.save_export(user.id).await.unwrap();
+    repository
+        .complete_export(
+            current.id,
+            json!({ "b": 2 }),
+            Utc::now() + Duration::hours(1),
+        )
+        .await
+        .unwrap();
+
+    clear_expired_exports(&app_state).await;
+
+    let expired = repository.get_export(expired.id).await.unwrap().unwrap();
+    assert!(expired.payload.is_none());
+    let current = repository.get_export(current.id).await.unwrap().unwrap();
+    assert!(current.payload.is_some());
+
+    db.teardown().await;
+}
@@ -0,0 +1,259 @@
+//! Redeeming invitations against Postgres, when `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use axum_auth_backend::{
+    domains::{
+        invitation::{Invitation, InvitationStatus, Invitee, NewInvitation},
+        organization::OrganizationRole,
+        user::{AccountStatus, ApprovalStatus, UserRole},
+    },
+    infrastructure::{
+        invitation::{
+            invitation_impl::PgInvitationRepository, invitation_trait::InvitationRepository,
+        },
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+        user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    },
+};
+use chrono::{Duration, Utc};
+use common::TestDatabase;
+use uuid::Uuid;
+
+#[test]
+fn invitations_only_raise_the_system_role() {
+    assert_eq!(UserRole::User.raised_to(UserRole::Admin), UserRole::Admin);
+    assert_eq!(UserRole::Admin.raised_to(UserRole::User), UserRole::Admin);
+    assert_eq!(UserRole::User.raised_to(UserRole::User), UserRole::User);
+}
+
+async fn create_user(db: &TestDatabase, name: &str) -> Uuid {
+    PgUserRepository::new(&db.db_client)
+        .save_user(
+            name,
+            &format!("{}@example.com", name),
+            "hash",
+            "token",
+            Utc::now() + Duration::hours(1),
+            ApprovalStatus::Approved,
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn invite(
+    db: &TestDatabase,
+    email: &str,
+    role: UserRole,
+    organization: Option<(Uuid, OrganizationRole)>,
+    invited_by: Uuid,
+) -> Invitation {
+    PgInvitationRepository::new(&db.db_client)
+        .save_invitation(NewInvitation {
+            email: email.to_string(),
+            role,
+            organization_id: organization.map(|(id, _)| id),
+            organization_role: organization.map(|(_, role)| role),
+            invited_by,
+            token_nonce: Uuid::new_v4().to_string(),
+            expires_at: Utc::now() + Duration::days(7),
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn an_invitation_creates_an_active_account_once() {
+    let Some(db) = TestDatabase::connect("invitations").await else {
+        return;
+    };
+    let repository = PgInvitationRepository::new(&db.db_client);
+    let inviter = create_user(&db, "inviter").await;
+    let invitation = invite(&db, "new@example.com", UserRole::User, None, inviter).await;
+    let invitee = Invitee::New {
+        name: "New",
+        password: "hash",
+        locale: Some("vi"),
+    };
+
+    let user = repository
+        .redeem_invitation(&invitation, invitee)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.email, "new@example.com");
+    assert_eq!(user.status, AccountStatus::Active);
+    assert_eq!(user.locale.as_deref(), Some("vi"));
+
+    assert!(
+        repository
+            .redeem_invitation(&invitation, invitee)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let stored = repository
+        .get_invitation(invitation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.status, InvitationStatus::Accepted);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn concurrent_redemptions_claim_the_invitation_once() {
+    let Some(db) = TestDatabase::connect("invitations").await else {
+        return;
+    };
+    let repository = PgInvitationRepository::new(&db.db_client);
+    let inviter = create_user(&db, "inviter").await;
+    let invitee = create_user(&db, "invitee").await;
+    let invitation = invite(&db, "invitee@example.com", UserRole::Admin, None, inviter).await;
+
+    let (first, second) = tokio::join!(
+        repository.redeem_invitation(&invitation, Invitee::Existing(invitee)),
+        repository.redeem_invitation(&invitation, Invitee::Existing(invitee)),
+    );
+    let redeemed = [first.unwrap(), second.unwrap()]
+        .iter()
+        .filter(|user| user.is_some())
+        .count();
+    assert_eq!(redeemed, 1);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn a_stale_link_does_not_redeem() {
+    let Some(db) = TestDatabase::connect("invitations").await else {
+        return;
+    };
+    let repository = PgInvitationRepository::new(&db.db_client);
+    let inviter = create_user(&db, "inviter").await;
+    let invitee = create_user(&db, "invitee").await;
+    let invitation = invite(&db, "invitee@example.com", UserRole::User, None, inviter).await;
+    repository
+        .refresh_invitation(invitation.id, "rotated", Utc::now() + Duration::days(7))
+        .await
+        .unwrap();
+
+    assert!(
+        repository
+            .redeem_invitation(&invitation, Invitee::Existing(invitee))
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn redeeming_never_demotes_an_admin() {
+    let Some(db) = TestDatabase::connect("invitations").await else {
+        return;
+    };
+    let users = PgUserRepository::new(&db.db_client);
+    let repository = PgInvitationRepository::new(&db.db_client);
+    let inviter = create_user(&db, "inviter").await;
+    let admin = create_user(&db, "admin").await;
+    users
+        .update_user_role(admin, UserRole::Admin)
+        .await
+        .unwrap();
+    let member = create_user(&db, "member").await;
+
+    let invitation = invite(&db, "admin@example.com", UserRole::User, None, inviter).await;
+    let user = repository
+        .redeem_invitation(&invitation, Invitee::Existing(admin))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.role, UserRole::Admin);
+
+    let invitation = invite(&db, "member@example.com", UserRole::Admin, None, inviter).await;
+    let user = repository
+        .redeem_invitation(&invitation, Invitee::Existing(member))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.role, UserRole::Admin);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn an_organization_invitation_adds_the_membership() {
+    let Some(db) = TestDatabase::connect("invitations").await else {
+        return;
+    };
+    let organizations = PgOrganizationRepository::new(&db.db_client);
+    let repository = PgInvitationRepository::new(&db.db_client);
+    let inviter = create_user(&db, "inviter").await;
+    let invitee = create_user(&db, "invitee").await;
+    let organization = organizations
+        .create_organization("Acme", inviter)
+        .await
+        .unwrap();
+
+    let invitation = invite(
+        &db,
+        "invitee@example.com",
+        UserRole::User,
+        Some((organization.id, OrganizationRole::Admin)),
+        inviter,
+    )
+    .await;
+    let user = repository
+        .redeem_invitation(&invitation, Invitee::Existing(invitee))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.role, UserRole::User);
+
+    let membership = organizations
+        .get_membership(organization.id, invitee)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(membership.role, OrganizationRole::Admin);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn a_failed_redemption_leaves_the_invitation_pending() {
+    let Some(db) = TestDatabase::connect("invitations").await else {
+        return;
+    };
+    let repository = PgInvitationRepository::new(&db.db_client);
+    let inviter = create_user(&db, "inviter").await;
+    create_user(&db, "taken").await;
+    let invitation = invite(&db, "taken@example.com", UserRole::User, None, inviter).await;
+
+    let result = repository
+        .redeem_invitation(
+            &invitation,
+            Invitee::New {
+                name: "Taken",
+                password: "hash",
+                locale: None,
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(sqlx::Error::Database(e)) if e.is_unique_violation()));
+
+    let stored = repository
+        .get_invitation(invitation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.status, InvitationStatus::Pending);
+
+    db.teardown().await;
+}
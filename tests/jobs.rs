@@ -0,0 +1,167 @@
+//! The job queue: backoff, claiming and reclaiming leases, and what is kept
+//! of a payload once it ran. Queue checks run against Postgres when
+//! `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use axum_auth_backend::{
+    core::job::{RETRY_BACKOFF, enqueue_email, run_next_job},
+    domains::job::{EmailJob, JobPayload, JobStatus},
+    infrastructure::job::{job_impl::PgJobRepository, job_trait::JobRepository},
+    models::job::response::FilterJob,
+    utils::backoff::Backoff,
+};
+use chrono::{Duration, Utc};
+use common::TestDatabase;
+
+fn verification_email() -> EmailJob {
+    EmailJob::Verification {
+        to: "ann@example.com".to_string(),
+        locale: None,
+        username: "Ann".to_string(),
+        token: "secret-token".to_string(),
+        return_to: None,
+    }
+}
+
+#[test]
+fn backs_off_exponentially_up_to_a_cap() {
+    assert_eq!(RETRY_BACKOFF.delay(1).num_seconds(), 15);
+    assert_eq!(RETRY_BACKOFF.delay(2).num_seconds(), 30);
+    assert_eq!(RETRY_BACKOFF.delay(3).num_seconds(), 60);
+    assert_eq!(RETRY_BACKOFF.delay(8).num_seconds(), 15 * 128);
+    assert_eq!(RETRY_BACKOFF.delay(9).num_seconds(), 60 * 60);
+    assert_eq!(RETRY_BACKOFF.delay(1000).num_seconds(), 60 * 60);
+
+    // Attempts below one wait as long as the first retry.
+    let backoff = Backoff::new(10, 100);
+    assert_eq!(backoff.delay(0).num_seconds(), 10);
+    assert_eq!(backoff.delay(-5).num_seconds(), 10);
+    assert_eq!(backoff.delay(4).num_seconds(), 80);
+    assert_eq!(backoff.delay(5).num_seconds(), 100);
+}
+
+#[test]
+fn redacting_keeps_everything_but_secrets() {
+    let payload = JobPayload::SendEmail(verification_email());
+    let redacted = payload.redacted();
+
+    assert_eq!(redacted.recipient(), "ann@example.com");
+    assert_eq!(redacted.summary(), "verification email");
+    let value = serde_json::to_value(&redacted).unwrap();
+    assert_eq!(value["data"]["token"], "[REDACTED]");
+    assert_eq!(value["data"]["username"], "Ann");
+
+    let link = JobPayload::SendEmail(EmailJob::ForgotPassword {
+        to: "ann@example.com".to_string(),
+        locale: None,
+        username: "Ann".to_string(),
+        reset_link: "https://app.example.com/reset?token=secret".to_string(),
+    });
+    let value = serde_json::to_value(link.redacted()).unwrap();
+    assert_eq!(value["data"]["reset_link"], "[REDACTED]");
+}
+
+#[tokio::test]
+async fn claims_due_jobs_once_and_reclaims_lapsed_leases() {
+    let Some(db) = TestDatabase::connect("jobs_claim").await else {
+        return;
+    };
+    let repository = PgJobRepository::new(&db.db_client);
+    let payload = serde_json::to_value(JobPayload::SendEmail(verification_email())).unwrap();
+    let now = Utc::now();
+
+    let due = repository
+        .enqueue_job("email.send", &payload, now, 2, None, None)
+        .await
+        .unwrap();
+    repository
+        .enqueue_job(
+            "email.send",
+            &payload,
+            now + Duration::hours(1),
+            2,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // The worker that claims it holds a lease; nobody else gets it meanwhile.
+    let lease = now + Duration::minutes(5);
+    let claimed = repository.claim_due_jobs(now, lease, 10).await.unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].id, due.id);
+    assert_eq!(claimed[0].status, JobStatus::Running);
+    assert_eq!(claimed[0].attempts, 1);
+    assert!(
+        repository
+            .claim_due_jobs(now, lease, 10)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    // Once the lease lapses the job is picked up again, as another attempt.
+    let later = lease + Duration::seconds(1);
+    let reclaimed = repository
+        .claim_due_jobs(later, later + Duration::minutes(5), 1)
+        .await
+        .unwrap();
+    assert_eq!(reclaimed.len(), 1);
+    assert_eq!(reclaimed[0].id, due.id);
+    assert_eq!(reclaimed[0].attempts, 2);
+
+    // A lease lapsing on the last attempt dead-letters the job.
+    let latest = later + Duration::minutes(10);
+    assert!(
+        repository
+            .claim_due_jobs(latest, latest + Duration::minutes(5), 1)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let dead = repository.get_job(due.id).await.unwrap().unwrap();
+    assert_eq!(dead.status, JobStatus::Dead);
+    assert_eq!(dead.attempts, 2);
+    assert!(dead.locked_until.is_none());
+    assert!(dead.last_error.is_some());
+
+    // A retry by an admin starts over.
+    let retried = repository.retry_job(due.id).await.unwrap().unwrap();
+    assert_eq!(retried.status, JobStatus::Pending);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn sent_emails_keep_no_secrets() {
+    let Some(db) = TestDatabase::connect("jobs_redact").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let repository = PgJobRepository::new(&db.db_client);
+
+    enqueue_email(&app_state, verification_email()).await;
+    let queued = repository.get_jobs(None, 1, 10).await.unwrap();
+    assert_eq!(queued[0].payload.0["data"]["token"], "secret-token");
+    // Page 0 is read as the first page rather than underflowing.
+    assert_eq!(repository.get_jobs(None, 0, 10).await.unwrap().len(), 1);
+
+    assert!(run_next_job(&app_state).await);
+    assert!(!run_next_job(&app_state).await);
+
+    let job = repository.get_job(queued[0].id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Succeeded);
+    assert!(!job.payload.0.to_string().contains("secret-token"));
+
+    // Admins see who a job is for and what it is, never the payload.
+    let listed = serde_json::to_value(FilterJob::filter_job(&job)).unwrap();
+    assert!(listed.get("payload").is_none());
+    assert_eq!(listed["recipient"], "ann@example.com");
+    assert_eq!(listed["summary"], "verification email");
+    let pending = serde_json::to_value(FilterJob::filter_job(&queued[0])).unwrap();
+    assert!(!pending.to_string().contains("secret-token"));
+
+    db.teardown().await;
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use axum::http::{HeaderMap, HeaderValue, header};
+use axum_auth_backend::{
+    config::{
+        environment::AppEnvironment,
+        logging::{LogFormat, LoggingConfig},
+        source::ConfigSource,
+    },
+    utils::redact::{REDACTED, redact_headers, redact_json},
+};
+use serde_json::json;
+
+fn load(environment: AppEnvironment, vars: &[(&str, &str)]) -> LoggingConfig {
+    let vars = vars
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect::<HashMap<_, _>>();
+    let mut source = ConfigSource::from_parts(None, vars).unwrap();
+    LoggingConfig::load(&mut source, environment)
+}
+
+#[test]
+fn bodies_are_only_captured_by_default_in_development() {
+    let production = load(AppEnvironment::Production, &[]);
+    assert_eq!(production.format, LogFormat::Json);
+    assert!(!production.capture_bodies);
+
+    let development = load(AppEnvironment::Development, &[]);
+    assert_eq!(development.format, LogFormat::Text);
+    assert!(development.capture_bodies);
+
+    let opted_in = load(
+        AppEnvironment::Production,
+        &[("APP_LOGGING_CAPTURE_BODIES", "true")],
+    );
+    assert!(opted_in.capture_bodies);
+}
+
+#[test]
+fn secrets_are_redacted_at_any_depth() {
+    let fields = load(AppEnvironment::Production, &[]).redact_fields;
+    let mut body = json!({
+        "email": "bob@example.com",
+        "password": "hunter22",
+        "passwordConfirm": "hunter22",
+        "data": { "user": { "name": "bob" }, "access_token": "eyJ..." },
+        "sessions": [{ "refresh-token": "abc", "device": "phone" }],
+    });
+
+    redact_json(&mut body, &fields);
+
+    assert_eq!(
+        body,
+        json!({
+            "email": "bob@example.com",
+            "password": REDACTED,
+            "passwordConfirm": REDACTED,
+            "data": { "user": { "name": "bob" }, "access_token": REDACTED },
+            "sessions": [{ "refresh-token": REDACTED, "device": "phone" }],
+        })
+    );
+}
+
+#[test]
+fn credential_headers_are_redacted() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::AUTHORIZATION,
+        HeaderValue::from_static("Bearer eyJ..."),
+    );
+    headers.insert(header::COOKIE, HeaderValue::from_static("token=eyJ..."));
+    headers.insert(header::USER_AGENT, HeaderValue::from_static("curl/8.0"));
+
+    assert_eq!(
+        redact_headers(&headers),
+        json!({
+            "authorization": REDACTED,
+            "cookie": REDACTED,
+            "user-agent": "curl/8.0",
+        })
+    );
+}
@@ -0,0 +1,173 @@
+use axum_auth_backend::{
+    config::email::{MailConfig, MailTransportKind},
+    domains::email_template::EmailTemplate,
+    helpers::mail::renderer::{EMAIL_TEMPLATES, MailRenderer, sample_context},
+};
+use chrono::Utc;
+use minijinja::context;
+use uuid::Uuid;
+
+fn renderer() -> MailRenderer {
+    MailRenderer::new(&MailConfig {
+        transport: MailTransportKind::Memory,
+        from_address: "no-reply@example.com".to_string(),
+        file_dir: "mail".to_string(),
+        template_dir: None,
+        default_locale: "en".to_string(),
+        smtp: None,
+    })
+}
+
+fn full_context() -> minijinja::Value {
+    context! {
+        username => "Alice",
+        verification_link => "http://localhost/verify?token=abc",
+        reset_link => "http://localhost/reset?token=abc",
+        invite_link => "http://localhost/invite?token=abc",
+        inviter => "Bob",
+        target => "Acme",
+        applicant_name => "Carol",
+        applicant_email => "carol@example.com",
+        review_link => "http://localhost/admin/approvals",
+        login_link => "http://localhost/login",
+        cancel_link => "http://localhost/cancel?token=abc",
+        deletion_date => "2030-01-01",
+        download_link => "http://localhost/download?token=abc",
+    }
+}
+
+#[test]
+fn every_template_renders_in_every_locale() {
+    let renderer = renderer();
+    assert_eq!(renderer.locales(), ["en", "vi"]);
+
+    for locale in renderer.locales() {
+        for name in EMAIL_TEMPLATES {
+            let mail = renderer
+                .render(name, Some(locale), full_context())
+                .unwrap_or_else(|e| panic!("{}/{}: {:#}", locale, name, e));
+
+            assert!(!mail.subject.is_empty());
+            assert!(!mail.subject.contains('\n'));
+            assert!(mail.html_body.contains(&format!("lang=\"{}\"", locale)));
+            assert!(!mail.text_body.contains('<'));
+        }
+    }
+}
+
+#[test]
+fn html_part_escapes_user_input() {
+    let mail = renderer()
+        .render(
+            "welcome",
+            None,
+            context! { username => "<script>alert(1)</script>" },
+        )
+        .unwrap();
+
+    assert!(!mail.html_body.contains("<script>"));
+    assert!(mail.html_body.contains("&lt;script&gt;"));
+    assert!(mail.text_body.contains("Hello <script>alert(1)</script>,"));
+}
+
+#[test]
+fn missing_variables_are_errors() {
+    assert!(renderer().render("welcome", None, context! {}).is_err());
+}
+
+#[test]
+fn locale_is_negotiated_and_falls_back() {
+    let renderer = renderer();
+
+    assert_eq!(renderer.supported_locale("vi-VN"), Some("vi"));
+    assert_eq!(renderer.supported_locale("fr"), None);
+    assert_eq!(
+        renderer.negotiate_locale("fr-FR,vi;q=0.8,en;q=0.5"),
+        Some("vi")
+    );
+    assert_eq!(renderer.resolve_locale(Some("de")), "en");
+
+    let mail = renderer
+        .render("welcome", Some("vi"), context! { username => "An" })
+        .unwrap();
+    assert!(mail.text_body.starts_with("Xin chào An,"));
+
+    let mail = renderer
+        .render("welcome", Some("de"), context! { username => "An" })
+        .unwrap();
+    assert!(mail.text_body.starts_with("Hello An,"));
+}
+
+fn stored(locale: &str, subject: &str, html_body: &str, text_body: &str) -> EmailTemplate {
+    EmailTemplate {
+        id: Uuid::new_v4(),
+        name: "welcome".to_string(),
+        locale: locale.to_string(),
+        version: 1,
+        subject: subject.to_string(),
+        html_body: html_body.to_string(),
+        text_body: text_body.to_string(),
+        active: true,
+        created_by: None,
+        created_at: Utc::now(),
+    }
+}
+
+#[test]
+fn sample_data_covers_every_template() {
+    let renderer = renderer();
+
+    for locale in renderer.locales() {
+        for name in EMAIL_TEMPLATES {
+            let context = sample_context(name).unwrap();
+            renderer
+                .render(name, Some(locale), context)
+                .unwrap_or_else(|e| panic!("{}/{}: {:#}", locale, name, e));
+        }
+    }
+}
+
+#[test]
+fn overrides_win_over_builtin_templates_of_their_locale() {
+    let renderer = renderer();
+    let overrides = [stored(
+        "en",
+        "Hi {{ username }}",
+        "{% extends \"en/layout.html\" %}{% block content %}<p>{{ username }}</p>{% endblock %}",
+        "Hi {{ username }}",
+    )];
+
+    let mail = renderer
+        .render_with_overrides(
+            "welcome",
+            Some("en"),
+            context! { username => "<An>" },
+            &overrides,
+        )
+        .unwrap();
+    assert_eq!(mail.subject, "Hi <An>");
+    assert!(mail.html_body.contains("<p>&lt;An&gt;</p>"));
+    assert_eq!(mail.text_body, "Hi <An>");
+
+    // Vietnamese ships its own translation, so the English override does not
+    // apply to it.
+    let mail = renderer
+        .render_with_overrides(
+            "welcome",
+            Some("vi"),
+            context! { username => "An" },
+            &overrides,
+        )
+        .unwrap();
+    assert!(mail.text_body.starts_with("Xin chào An,"));
+}
+
+#[test]
+fn broken_overrides_fall_back_to_builtin_templates() {
+    let overrides = [stored("en", "Hi {{ missing }}", "x", "y")];
+
+    let mail = renderer()
+        .render_with_overrides("welcome", None, context! { username => "An" }, &overrides)
+        .unwrap();
+    assert!(mail.text_body.starts_with("Hello An,"));
+}
@@ -0,0 +1,209 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use axum_auth_backend::{
+    AppState,
+    api::router::create_router,
+    config::{app::AppConfig, source::ConfigSource},
+    domains::mail::OutgoingMail,
+    helpers::mail::renderer::MailRenderer,
+    infrastructure::{
+        database::database::DBClient,
+        mail::{
+            file_impl::FileMailTransport, log_impl::LogMailTransport, mail_trait::MailTransport,
+            memory_impl::InMemoryMailTransport,
+        },
+        user::memory_impl::InMemoryUserRepository,
+    },
+};
+use chrono::Utc;
+use sqlx::postgres::PgPoolOptions;
+use tower::ServiceExt;
+use tracing_subscriber::fmt::MakeWriter;
+use uuid::Uuid;
+
+/// Collects everything logged through it.
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Captured {
+    type Writer = Captured;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+fn mail(to: &str) -> OutgoingMail {
+    OutgoingMail {
+        id: Uuid::new_v4(),
+        from: "no-reply@example.com".to_string(),
+        to: to.to_string(),
+        subject: "Email Verification".to_string(),
+        html_body: "<a href=\"http://localhost/verify?token=abc\">Verify</a>".to_string(),
+        text_body: "Verify: http://localhost/verify?token=abc".to_string(),
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn memory_transport_records_and_clears() {
+    let transport = InMemoryMailTransport::new();
+
+    transport.send(&mail("a@example.com")).await.unwrap();
+    transport.send(&mail("b@example.com")).await.unwrap();
+
+    let sent = transport.sent();
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].to, "a@example.com");
+    assert!(sent[1].html_body.contains("token=abc"));
+
+    transport.clear();
+    assert!(transport.sent().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn log_transport_never_logs_the_body() {
+    let captured = Captured::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(captured.clone())
+        .with_ansi(false)
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    LogMailTransport.send(&mail("a@example.com")).await.unwrap();
+
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    assert!(output.contains("a@example.com"));
+    assert!(output.contains("Email Verification"));
+    assert!(!output.contains("token=abc"));
+}
+
+#[tokio::test]
+async fn file_transport_writes_eml() {
+    let dir = std::env::temp_dir().join(format!("mail-{}", Uuid::new_v4()));
+    let transport = FileMailTransport::new(dir.to_str().unwrap());
+    let message = mail("a@example.com");
+
+    transport.send(&message).await.unwrap();
+
+    let entries: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|e| e.unwrap())
+        .collect();
+    assert_eq!(entries.len(), 1);
+    let name = entries[0].file_name().into_string().unwrap();
+    assert!(name.ends_with(&format!("{}.eml", message.id)));
+
+    let content = std::fs::read_to_string(entries[0].path()).unwrap();
+    assert!(content.contains("To: a@example.com"));
+    assert!(content.contains("Subject: Email Verification"));
+    assert!(content.contains("multipart/alternative"));
+    assert!(content.contains("Content-Type: text/plain"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn file_transport_rejects_invalid_address() {
+    let dir = std::env::temp_dir().join(format!("mail-{}", Uuid::new_v4()));
+    let transport = FileMailTransport::new(dir.to_str().unwrap());
+
+    assert!(transport.send(&mail("not an address")).await.is_err());
+}
+
+#[cfg(debug_assertions)]
+#[tokio::test]
+async fn mailbox_transport_lists_newest_first_and_is_bounded() {
+    use axum_auth_backend::infrastructure::mail::mailbox_impl::MailboxTransport;
+
+    let mailbox = MailboxTransport::new();
+    let first = mail("first@example.com");
+    mailbox.send(&first).await.unwrap();
+    for i in 0..250 {
+        mailbox
+            .send(&mail(&format!("user{}@example.com", i)))
+            .await
+            .unwrap();
+    }
+
+    let messages = mailbox.messages();
+    assert_eq!(messages.len(), 200);
+    assert_eq!(messages[0].to, "user249@example.com");
+    assert!(mailbox.message(first.id).is_none());
+    assert!(mailbox.message(messages[10].id).is_some());
+    assert!(mailbox.as_mailbox().is_some());
+
+    assert_eq!(mailbox.clear(), 200);
+    assert!(mailbox.messages().is_empty());
+}
+
+/// The API router around `mailer`, on a database it never connects to.
+fn api(mailer: Arc<dyn MailTransport>) -> Router {
+    let settings = [
+        ("APP_ENV", "development"),
+        ("APP_DATABASE_URL", "postgresql://unused@127.0.0.1:1/unused"),
+        ("APP_JWT_SECRET_KEY", "test-secret"),
+        ("APP_JWT_MAXAGE", "60"),
+        ("APP_CORS_ALLOWED_ORIGINS", "http://localhost:3000"),
+        ("APP_AUDIT_SIGNING_KEY", "test-signing-key"),
+        ("APP_MAIL_TRANSPORT", "memory"),
+    ]
+    .iter()
+    .map(|(name, value)| (name.to_string(), value.to_string()))
+    .collect();
+    let mut source = ConfigSource::from_parts(None, settings).unwrap();
+    let config = AppConfig::load(&mut source).unwrap();
+    let mail_renderer = MailRenderer::try_new(&config.mail).unwrap();
+    let pool = PgPoolOptions::new()
+        .connect_lazy(&config.database.url)
+        .unwrap();
+
+    create_router(Arc::new(AppState::new(
+        config,
+        DBClient::new(pool),
+        Arc::new(InMemoryUserRepository::new()),
+        mailer,
+        mail_renderer,
+        None,
+    )))
+}
+
+async fn get_mailbox(app: Router) -> StatusCode {
+    let request = Request::get("/api/dev/mailbox")
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn dev_mailbox_is_not_mounted_for_other_transports() {
+    let app = api(Arc::new(InMemoryMailTransport::new()));
+    assert_eq!(get_mailbox(app).await, StatusCode::NOT_FOUND);
+}
+
+#[cfg(debug_assertions)]
+#[tokio::test]
+async fn dev_mailbox_is_mounted_for_the_mailbox_transport() {
+    use axum_auth_backend::infrastructure::mail::mailbox_impl::MailboxTransport;
+
+    let app = api(Arc::new(MailboxTransport::new()));
+    assert_eq!(get_mailbox(app).await, StatusCode::OK);
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use axum_auth_backend::{
+    core::migration::{migration_status, prepare_schema},
+    infrastructure::database::migrations::MIGRATOR,
+};
+use common::TestDatabase;
+use sqlx::PgPool;
+
+const NEWER_VERSION: i64 = 20300101000000;
+
+fn versions() -> Vec<i64> {
+    MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .map(|migration| migration.version)
+        .collect()
+}
+
+fn latest_version() -> i64 {
+    versions().into_iter().max().unwrap()
+}
+
+/// Drops the record of a migration, as if it had never been applied.
+async fn forget(pool: &PgPool, version: i64) {
+    sqlx::query("DELETE FROM _sqlx_migrations WHERE version = $1")
+        .bind(version)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+/// Records a migration this build does not ship, as a newer build would.
+async fn record_unknown(pool: &PgPool, version: i64) {
+    sqlx::query(
+        r#"INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+        VALUES ($1, 'from a newer build', true, $2, 0)"#,
+    )
+    .bind(version)
+    .bind(vec![0u8; 48])
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn a_migrated_schema_is_current() {
+    let Some(db) = TestDatabase::connect("migration_current").await else {
+        return;
+    };
+
+    let app_state = db.app_state(&[("APP_DATABASE_MIGRATIONS", "verify")]);
+    let status = migration_status(&app_state).await.unwrap();
+    assert_eq!(status.applied, versions().len());
+    assert!(status.is_current());
+    assert!(!status.has_diverged());
+    assert!(prepare_schema(&app_state).await.is_ok());
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn pending_migrations_fail_verify_and_are_applied() {
+    let Some(db) = TestDatabase::connect("migration_pending").await else {
+        return;
+    };
+
+    // Reverted with its down migration so applying it again is safe.
+    let version = latest_version();
+    let down = MIGRATOR
+        .iter()
+        .find(|migration| {
+            migration.version == version && migration.migration_type.is_down_migration()
+        })
+        .unwrap();
+    sqlx::raw_sql(&down.sql).execute(db.pool()).await.unwrap();
+    forget(db.pool(), version).await;
+
+    let verify = db.app_state(&[("APP_DATABASE_MIGRATIONS", "verify")]);
+    let status = migration_status(&verify).await.unwrap();
+    assert_eq!(status.applied, versions().len() - 1);
+    assert_eq!(status.pending, [version]);
+    let error = prepare_schema(&verify).await.unwrap_err();
+    assert!(error.contains("behind this build"), "{}", error);
+
+    let apply = db.app_state(&[("APP_DATABASE_MIGRATIONS", "apply")]);
+    assert!(prepare_schema(&apply).await.is_ok());
+    let status = migration_status(&apply).await.unwrap();
+    assert_eq!(status.applied, versions().len());
+    assert!(status.is_current());
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn migrations_from_a_newer_build_are_tolerated() {
+    let Some(db) = TestDatabase::connect("migration_newer").await else {
+        return;
+    };
+
+    record_unknown(db.pool(), NEWER_VERSION).await;
+
+    for mode in ["verify", "apply"] {
+        let app_state = db.app_state(&[("APP_DATABASE_MIGRATIONS", mode)]);
+        let status = migration_status(&app_state).await.unwrap();
+        assert_eq!(status.unknown, [NEWER_VERSION]);
+        assert!(status.is_current());
+        assert!(prepare_schema(&app_state).await.is_ok(), "{}", mode);
+    }
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn an_edited_migration_needs_a_manual_look() {
+    let Some(db) = TestDatabase::connect("migration_modified").await else {
+        return;
+    };
+
+    let version = latest_version();
+    sqlx::query("UPDATE _sqlx_migrations SET checksum = $1 WHERE version = $2")
+        .bind(vec![0u8; 48])
+        .bind(version)
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let verify = db.app_state(&[("APP_DATABASE_MIGRATIONS", "verify")]);
+    let status = migration_status(&verify).await.unwrap();
+    assert_eq!(status.modified, [version]);
+    assert!(!status.is_current());
+    assert!(prepare_schema(&verify).await.is_err());
+
+    let apply = db.app_state(&[("APP_DATABASE_MIGRATIONS", "apply")]);
+    let error = prepare_schema(&apply).await.unwrap_err();
+    assert!(error.contains("manual look"), "{}", error);
+    assert!(
+        error.contains(&format!("1 modified ({})", version)),
+        "{}",
+        error
+    );
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn unknown_migrations_alongside_pending_ones_are_refused() {
+    let Some(db) = TestDatabase::connect("migration_diverged").await else {
+        return;
+    };
+
+    let version = latest_version();
+    forget(db.pool(), version).await;
+    record_unknown(db.pool(), NEWER_VERSION).await;
+
+    for mode in ["verify", "apply"] {
+        let app_state = db.app_state(&[("APP_DATABASE_MIGRATIONS", mode)]);
+        let status = migration_status(&app_state).await.unwrap();
+        assert_eq!(status.pending, [version]);
+        assert_eq!(status.unknown, [NEWER_VERSION]);
+        assert!(status.has_diverged());
+
+        let error = prepare_schema(&app_state).await.unwrap_err();
+        assert!(error.contains("does not know"), "{}", error);
+        assert!(error.contains(&NEWER_VERSION.to_string()), "{}", error);
+    }
+
+    // Apply refused before running anything, so the migration is still pending.
+    let app_state = db.app_state(&[]);
+    let status = migration_status(&app_state).await.unwrap();
+    assert_eq!(status.pending, [version]);
+
+    db.teardown().await;
+}
@@ -0,0 +1,202 @@
+//! Organization roles, plus the repository's tenant scoping and last-owner
+//! guard against Postgres when `TEST_DATABASE_URL` is set.
+
+mod common;
+
+use axum_auth_backend::{
+    domains::{
+        organization::{MembershipChange, OrganizationRole},
+        user::ApprovalStatus,
+    },
+    infrastructure::{
+        organization::{
+            organization_impl::PgOrganizationRepository, organization_trait::OrganizationRepository,
+        },
+        user::{user_trait::UserRepository, users_impl::PgUserRepository},
+    },
+};
+use chrono::{Duration, Utc};
+use common::TestDatabase;
+use uuid::Uuid;
+
+use OrganizationRole::{Admin, Member, Owner};
+
+#[test]
+fn owners_may_assign_every_role() {
+    assert!(Owner.can_assign(Owner));
+    assert!(Owner.can_assign(Admin));
+    assert!(Owner.can_assign(Member));
+}
+
+#[test]
+fn admins_may_not_touch_owners() {
+    assert!(!Admin.can_assign(Owner));
+    assert!(Admin.can_assign(Admin));
+    assert!(Admin.can_assign(Member));
+}
+
+#[test]
+fn members_may_assign_nothing() {
+    assert!(!Member.can_assign(Owner));
+    assert!(!Member.can_assign(Admin));
+    assert!(!Member.can_assign(Member));
+}
+
+async fn create_user(db: &TestDatabase, name: &str) -> Uuid {
+    PgUserRepository::new(&db.db_client)
+        .save_user(
+            name,
+            &format!("{}@example.com", name),
+            "hash",
+            "token",
+            Utc::now() + Duration::hours(1),
+            ApprovalStatus::Approved,
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+#[tokio::test]
+async fn queries_stay_inside_their_organization() {
+    let Some(db) = TestDatabase::connect("organizations").await else {
+        return;
+    };
+    let repository = PgOrganizationRepository::new(&db.db_client);
+    let alice = create_user(&db, "alice").await;
+    let bob = create_user(&db, "bob").await;
+
+    let first = repository
+        .create_organization("First", alice)
+        .await
+        .unwrap();
+    let second = repository.create_organization("Second", bob).await.unwrap();
+    repository.add_member(first.id, bob, Member).await.unwrap();
+
+    assert!(
+        repository
+            .get_membership(second.id, alice)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let members: Vec<Uuid> = repository
+        .get_members(second.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|member| member.user_id)
+        .collect();
+    assert_eq!(members, vec![bob]);
+
+    // Alice owns the first organization, which gives her nothing in the second.
+    assert!(matches!(
+        repository
+            .remove_member(second.id, alice, Owner)
+            .await
+            .unwrap(),
+        MembershipChange::NotFound
+    ));
+    assert!(matches!(
+        repository
+            .update_member_role(second.id, alice, Admin, Owner)
+            .await
+            .unwrap(),
+        MembershipChange::NotFound
+    ));
+    let bob_in_second = repository
+        .get_membership(second.id, bob)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(bob_in_second.role, Owner);
+
+    let organizations = repository.get_user_organizations(alice).await.unwrap();
+    assert_eq!(organizations.len(), 1);
+    assert_eq!(organizations[0].id, first.id);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn the_last_owner_cannot_be_removed_or_demoted() {
+    let Some(db) = TestDatabase::connect("organizations").await else {
+        return;
+    };
+    let repository = PgOrganizationRepository::new(&db.db_client);
+    let alice = create_user(&db, "alice").await;
+    let bob = create_user(&db, "bob").await;
+    let organization = repository.create_organization("Acme", alice).await.unwrap();
+    repository
+        .add_member(organization.id, bob, Admin)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        repository
+            .remove_member(organization.id, alice, Owner)
+            .await
+            .unwrap(),
+        MembershipChange::LastOwner
+    ));
+    assert!(matches!(
+        repository
+            .update_member_role(organization.id, alice, Member, Owner)
+            .await
+            .unwrap(),
+        MembershipChange::LastOwner
+    ));
+    assert!(matches!(
+        repository
+            .remove_member(organization.id, alice, Admin)
+            .await
+            .unwrap(),
+        MembershipChange::Forbidden
+    ));
+    assert!(matches!(
+        repository
+            .update_member_role(organization.id, bob, Owner, Admin)
+            .await
+            .unwrap(),
+        MembershipChange::Forbidden
+    ));
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn concurrent_removals_keep_one_owner() {
+    let Some(db) = TestDatabase::connect("organizations").await else {
+        return;
+    };
+    let repository = PgOrganizationRepository::new(&db.db_client);
+    let alice = create_user(&db, "alice").await;
+    let bob = create_user(&db, "bob").await;
+    let organization = repository.create_organization("Acme", alice).await.unwrap();
+    repository
+        .add_member(organization.id, bob, Owner)
+        .await
+        .unwrap();
+
+    // Each owner removes the other at the same time; only one may succeed.
+    let (first, second) = tokio::join!(
+        repository.remove_member(organization.id, alice, Owner),
+        repository.remove_member(organization.id, bob, Owner),
+    );
+    let outcomes = [first.unwrap(), second.unwrap()];
+    let applied = outcomes
+        .iter()
+        .filter(|change| matches!(change, MembershipChange::Applied(_)))
+        .count();
+    let refused = outcomes
+        .iter()
+        .filter(|change| matches!(change, MembershipChange::LastOwner))
+        .count();
+    assert_eq!((applied, refused), (1, 1));
+    assert_eq!(
+        repository.get_members(organization.id).await.unwrap().len(),
+        1
+    );
+
+    db.teardown().await;
+}
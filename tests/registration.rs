@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use axum_auth_backend::{
+    config::{
+        registration::{RegistrationConfig, RegistrationMode},
+        source::ConfigSource,
+    },
+    domains::user::ApprovalStatus,
+};
+
+fn load(pairs: &[(&str, &str)]) -> (RegistrationConfig, Result<(), Vec<String>>) {
+    let vars = pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect::<HashMap<_, _>>();
+    let mut source = ConfigSource::from_parts(None, vars).unwrap();
+    let config = RegistrationConfig::load(&mut source);
+    (config, source.finish())
+}
+
+#[test]
+fn registration_is_open_by_default() {
+    let (config, result) = load(&[]);
+
+    assert!(result.is_ok());
+    assert_eq!(config.mode, RegistrationMode::Open);
+    assert!(config.allowed_email_domains.is_empty());
+}
+
+#[test]
+fn every_mode_parses() {
+    for (value, mode) in [
+        ("open", RegistrationMode::Open),
+        ("invite_only", RegistrationMode::InviteOnly),
+        ("Approval_Required", RegistrationMode::ApprovalRequired),
+    ] {
+        let (config, result) = load(&[("APP_REGISTRATION_MODE", value)]);
+        assert!(result.is_ok(), "{}: {:?}", value, result);
+        assert_eq!(config.mode, mode);
+    }
+
+    let (config, _) = load(&[
+        ("APP_REGISTRATION_MODE", "allowed_domains"),
+        ("APP_REGISTRATION_ALLOWED_EMAIL_DOMAINS", "example.com"),
+    ]);
+    assert_eq!(config.mode, RegistrationMode::AllowedDomains);
+}
+
+#[test]
+fn unknown_modes_are_errors() {
+    let (_, result) = load(&[("APP_REGISTRATION_MODE", "closed")]);
+
+    let errors = result.unwrap_err();
+    assert!(
+        errors[0].starts_with("registration.mode (APP_REGISTRATION_MODE): must be one of"),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn allowed_domains_mode_needs_a_domain_list() {
+    let (_, result) = load(&[("APP_REGISTRATION_MODE", "allowed_domains")]);
+
+    let errors = result.unwrap_err();
+    assert!(
+        errors[0].starts_with("registration.allowed_email_domains"),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn only_listed_domains_are_allowed() {
+    let (config, _) = load(&[
+        ("APP_REGISTRATION_MODE", "allowed_domains"),
+        (
+            "APP_REGISTRATION_ALLOWED_EMAIL_DOMAINS",
+            "@Example.com, partner.org",
+        ),
+    ]);
+
+    assert_eq!(config.allowed_email_domains, ["example.com", "partner.org"]);
+    assert!(config.is_domain_allowed("ann@example.com"));
+    assert!(config.is_domain_allowed("Bob@EXAMPLE.COM"));
+    assert!(config.is_domain_allowed("carol@partner.org"));
+
+    assert!(!config.is_domain_allowed("dave@other.com"));
+    assert!(!config.is_domain_allowed("eve@mail.example.com"));
+    assert!(!config.is_domain_allowed("mallory@example.com.evil.io"));
+    assert!(!config.is_domain_allowed("example.com"));
+}
+
+#[test]
+fn only_approval_required_holds_new_accounts() {
+    assert_eq!(
+        RegistrationMode::ApprovalRequired.initial_approval_status(),
+        ApprovalStatus::Pending
+    );
+    for mode in [
+        RegistrationMode::Open,
+        RegistrationMode::InviteOnly,
+        RegistrationMode::AllowedDomains,
+    ] {
+        assert_eq!(mode.initial_approval_status(), ApprovalStatus::Approved);
+    }
+}
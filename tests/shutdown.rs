@@ -0,0 +1,72 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use axum_auth_backend::core::shutdown::Shutdown;
+use tokio::time::timeout;
+
+/// Long enough that a test only passes if the wait was cut short.
+const FOREVER: Duration = Duration::from_secs(3600);
+const PROMPTLY: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn tick_and_sleep_stop_once_draining_starts() {
+    let shutdown = Shutdown::new();
+    let mut interval = tokio::time::interval(FOREVER);
+
+    // The first tick is immediate.
+    assert!(shutdown.tick(&mut interval).await);
+    assert!(shutdown.sleep(Duration::from_millis(1)).await);
+    assert!(!shutdown.is_draining());
+
+    shutdown.begin();
+    assert!(shutdown.is_draining());
+    assert!(
+        !timeout(PROMPTLY, shutdown.tick(&mut interval))
+            .await
+            .unwrap()
+    );
+    assert!(!timeout(PROMPTLY, shutdown.sleep(FOREVER)).await.unwrap());
+}
+
+#[tokio::test]
+async fn sleeping_workers_wake_up_when_draining_starts() {
+    let shutdown = Shutdown::new();
+    let sleeper = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { shutdown.sleep(FOREVER).await }
+    });
+
+    shutdown.begin();
+    assert!(!timeout(PROMPTLY, sleeper).await.unwrap().unwrap());
+}
+
+#[tokio::test]
+async fn waits_for_spawned_work_to_finish() {
+    let shutdown = Shutdown::new();
+    let finished = Arc::new(AtomicBool::new(false));
+
+    shutdown.spawn({
+        let finished = finished.clone();
+        async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            finished.store(true, Ordering::SeqCst);
+        }
+    });
+    // A worker loop that only returns once draining starts.
+    shutdown.spawn({
+        let shutdown = shutdown.clone();
+        async move { while shutdown.sleep(FOREVER).await {} }
+    });
+    assert_eq!(shutdown.running_tasks(), 2);
+
+    shutdown.begin();
+    timeout(PROMPTLY, shutdown.wait_for_tasks()).await.unwrap();
+
+    assert!(finished.load(Ordering::SeqCst));
+    assert_eq!(shutdown.running_tasks(), 0);
+}
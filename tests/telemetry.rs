@@ -0,0 +1,41 @@
+use axum::{body::to_bytes, response::IntoResponse};
+use axum_auth_backend::{
+    core::telemetry::{TraceContext, current_request_id, is_valid_request_id},
+    errors::http_error::HttpError,
+};
+use tracing::{field::Empty, info_span};
+
+#[test]
+fn only_short_printable_request_ids_are_kept() {
+    assert!(is_valid_request_id("abc-123"));
+    assert!(is_valid_request_id("0af7651916cd43dd8448eb211c80319c"));
+    assert!(!is_valid_request_id(""));
+    assert!(!is_valid_request_id("has space"));
+    assert!(!is_valid_request_id(&"a".repeat(129)));
+}
+
+#[tokio::test]
+async fn error_responses_carry_the_request_id() {
+    let context = TraceContext {
+        request_id: Some("req-42".to_string()),
+        traceparent: None,
+    };
+    let span = info_span!("test", request_id = Empty);
+
+    let response = context
+        .run(span, async {
+            HttpError::bad_request("Nope").into_response()
+        })
+        .await;
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+        serde_json::json!({ "status": "fail", "message": "Nope", "requestId": "req-42" })
+    );
+
+    let outside = HttpError::bad_request("Nope").into_response();
+    let body = to_bytes(outside.into_body(), usize::MAX).await.unwrap();
+    assert!(!String::from_utf8_lossy(&body).contains("requestId"));
+    assert_eq!(current_request_id(), None);
+}
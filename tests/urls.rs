@@ -0,0 +1,63 @@
+use axum_auth_backend::config::urls::UrlConfig;
+
+fn urls() -> UrlConfig {
+    UrlConfig {
+        api_base_url: "https://api.example.com".to_string(),
+        frontend_url: "https://app.example.com".to_string(),
+        verify_email_path: "/api/auth/verify-email?token={token}".to_string(),
+        email_verified_path: "/settings".to_string(),
+        reset_password_path: "/reset-password?token={token}".to_string(),
+        accept_invitation_path: "/accept-invitation?token={token}".to_string(),
+        cancel_deletion_path: "/cancel-deletion?token={token}".to_string(),
+        data_export_path: "/api/exports/download?token={token}".to_string(),
+        login_path: "/login".to_string(),
+        approvals_path: "/admin/approvals".to_string(),
+        return_to_origins: vec![
+            "https://app.example.com".to_string(),
+            "https://admin.example.com".to_string(),
+        ],
+    }
+}
+
+#[test]
+fn links_fill_in_tokens_and_return_to() {
+    let urls = urls();
+
+    assert_eq!(
+        urls.verification_link("abc", None),
+        "https://api.example.com/api/auth/verify-email?token=abc"
+    );
+    assert_eq!(
+        urls.reset_password_link("a+b", Some("https://app.example.com/next")),
+        "https://app.example.com/reset-password?token=a%2Bb&return_to=https%3A%2F%2Fapp.example.com%2Fnext"
+    );
+    assert_eq!(urls.login_link(), "https://app.example.com/login");
+}
+
+#[test]
+fn return_to_is_limited_to_allowed_origins() {
+    let urls = urls();
+
+    assert_eq!(
+        urls.allowed_return_to("/welcome?step=2").as_deref(),
+        Some("https://app.example.com/welcome?step=2")
+    );
+    assert_eq!(
+        urls.allowed_return_to("https://admin.example.com/users")
+            .as_deref(),
+        Some("https://admin.example.com/users")
+    );
+
+    for rejected in [
+        "https://evil.com/",
+        "//evil.com/",
+        "/\\evil.com",
+        "https://app.example.com.evil.com/",
+        "https://app.example.com@evil.com/",
+        "http://app.example.com/",
+        "javascript:alert(1)",
+        "not a url",
+    ] {
+        assert_eq!(urls.allowed_return_to(rejected), None, "{}", rejected);
+    }
+}
@@ -0,0 +1,915 @@
+//! One set of expectations for every `UserRepository` backend. The in-memory
+//! backend always runs, SQLite with `--features sqlite`, and Postgres when
+//! `TEST_DATABASE_URL` points at a database the tests may create schemas in.
+
+use std::sync::Arc;
+
+use axum_auth_backend::{
+    domains::user::{AccountStatus, ApprovalStatus, StatusChange, User, UserFilter, UserRole},
+    infrastructure::{
+        database::{database::DBClient, migrations::MIGRATOR},
+        user::{
+            memory_impl::InMemoryUserRepository, user_trait::UserRepository,
+            users_impl::PgUserRepository,
+        },
+    },
+};
+use chrono::{Duration, Utc};
+use sqlx::{Executor, PgPool, postgres::PgPoolOptions};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+struct Backend {
+    repository: Arc<dyn UserRepository>,
+    /// Each Postgres test gets a schema of its own, dropped when it passes.
+    schema: Option<(PgPool, String)>,
+}
+
+impl Backend {
+    async fn teardown(self) {
+        if let Some((pool, schema)) = self.schema {
+            pool.execute(format!("DROP SCHEMA {} CASCADE", schema).as_str())
+                .await
+                .unwrap();
+            pool.close().await;
+        }
+    }
+}
+
+async fn memory() -> Option<Backend> {
+    Some(Backend {
+        repository: Arc::new(InMemoryUserRepository::new()),
+        schema: None,
+    })
+}
+
+#[cfg(feature = "sqlite")]
+async fn sqlite() -> Option<Backend> {
+    use axum_auth_backend::infrastructure::user::sqlite_impl::SqliteUserRepository;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    // An in-memory database lives as long as its one connection.
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    let repository = SqliteUserRepository::new(pool);
+    repository.create_schema().await.unwrap();
+
+    Some(Backend {
+        repository: Arc::new(repository),
+        schema: None,
+    })
+}
+
+async fn postgres() -> Option<Backend> {
+    static EXTENSIONS: OnceCell<()> = OnceCell::const_new();
+
+    let url = std::env::var("TEST_DATABASE_URL").ok()?;
+
+    // Installed once up front; tests creating it concurrently would collide.
+    EXTENSIONS
+        .get_or_init(|| async {
+            let pool = PgPool::connect(&url).await.unwrap();
+            pool.execute(r#"CREATE EXTENSION IF NOT EXISTS "uuid-ossp" SCHEMA public"#)
+                .await
+                .unwrap();
+            pool.close().await;
+        })
+        .await;
+
+    let schema = format!("user_repository_{}", Uuid::new_v4().simple());
+    let setup = format!(
+        "CREATE SCHEMA IF NOT EXISTS {0}; SET search_path TO {0}, public",
+        schema
+    );
+    let pool = PgPoolOptions::new()
+        .max_connections(2)
+        .after_connect(move |conn, _| {
+            let setup = setup.clone();
+            Box::pin(async move {
+                conn.execute(setup.as_str()).await?;
+                Ok(())
+            })
+        })
+        .connect(&url)
+        .await
+        .unwrap();
+    MIGRATOR.run(&pool).await.unwrap();
+
+    Some(Backend {
+        repository: Arc::new(PgUserRepository::new(&DBClient::new(pool.clone()))),
+        schema: Some((pool, schema)),
+    })
+}
+
+/// Runs every check below against the backend the function named
+/// `$backend` connects to, skipping them when it returns `None`.
+macro_rules! conformance {
+    ($backend:ident) => {
+        conformance!(
+            $backend;
+            saves_and_finds_users_by_each_key,
+            rejects_a_second_account_for_an_email,
+            invited_users_are_active,
+            lists_newest_first_in_pages,
+            filters_users,
+            lists_users_by_approval_status,
+            updates_profile_fields,
+            updating_a_missing_user_fails,
+            verifies_a_token_once,
+            replaces_and_expires_tokens,
+            status_changes_are_conditional_and_recorded,
+            deletion_can_be_scheduled_and_cancelled,
+            anonymizing_scrubs_personal_data,
+            failed_logins_lock_an_active_account,
+            refuses_transitions_outside_the_lifecycle,
+            deleting_removes_the_user_and_its_history
+        );
+    };
+    ($backend:ident; $($check:ident),* $(,)?) => {
+        mod $backend {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    if let Some(backend) = super::$backend().await {
+                        super::$check(backend.repository.as_ref()).await;
+                        backend.teardown().await;
+                    }
+                }
+            )*
+        }
+    };
+}
+
+conformance!(memory);
+#[cfg(feature = "sqlite")]
+conformance!(sqlite);
+conformance!(postgres);
+
+async fn register(repository: &dyn UserRepository, name: &str, token: &str) -> Uuid {
+    let email = format!("{}@example.com", name.to_lowercase());
+    repository
+        .save_user(
+            name,
+            &email,
+            "hash",
+            token,
+            Utc::now() + Duration::hours(24),
+            ApprovalStatus::Approved,
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn saves_and_finds_users_by_each_key(repository: &dyn UserRepository) {
+    let expires_at = Utc::now() + Duration::hours(24);
+    let user = repository
+        .save_user(
+            "Ann",
+            "ann@example.com",
+            "hash",
+            "token-1",
+            expires_at,
+            ApprovalStatus::Pending,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(user.name, "Ann");
+    assert_eq!(user.email, "ann@example.com");
+    assert_eq!(user.password, "hash");
+    assert_eq!(user.role, UserRole::User);
+    assert_eq!(user.status, AccountStatus::PendingVerification);
+    assert_eq!(user.approval_status, ApprovalStatus::Pending);
+    assert_eq!(user.verification_token.as_deref(), Some("token-1"));
+    assert!(user.token_expires_at.is_some());
+
+    for found in [
+        repository.get_user(Some(user.id), None, None, None).await,
+        repository.get_user(None, Some("Ann"), None, None).await,
+        repository
+            .get_user(None, None, Some("ann@example.com"), None)
+            .await,
+        repository.get_user(None, None, None, Some("token-1")).await,
+    ] {
+        assert_eq!(found.unwrap().map(|found| found.id), Some(user.id));
+    }
+
+    assert!(
+        repository
+            .get_user(Some(Uuid::new_v4()), None, None, None)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        repository
+            .get_user(None, None, None, None)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+async fn rejects_a_second_account_for_an_email(repository: &dyn UserRepository) {
+    register(repository, "Ann", "token-1").await;
+
+    let error = repository
+        .save_invited_user("Other Ann", "ann@example.com", "hash", UserRole::User)
+        .await
+        .unwrap_err();
+
+    match error {
+        sqlx::Error::Database(e) => assert!(e.is_unique_violation()),
+        e => panic!("expected a unique violation, got {:?}", e),
+    }
+    assert_eq!(repository.get_user_count().await.unwrap(), 1);
+}
+
+async fn invited_users_are_active(repository: &dyn UserRepository) {
+    let first = repository
+        .save_invited_user("Root", "root@example.com", "hash", UserRole::Admin)
+        .await
+        .unwrap();
+    register(repository, "Ann", "token-1").await;
+    let second = repository
+        .save_invited_user("Ops", "ops@example.com", "hash", UserRole::Admin)
+        .await
+        .unwrap();
+
+    assert_eq!(first.status, AccountStatus::Active);
+    assert_eq!(first.approval_status, ApprovalStatus::Approved);
+    assert!(first.verification_token.is_none());
+
+    let admins: Vec<Uuid> = repository
+        .get_admins()
+        .await
+        .unwrap()
+        .iter()
+        .map(|user| user.id)
+        .collect();
+    assert_eq!(admins, vec![first.id, second.id]);
+}
+
+async fn lists_newest_first_in_pages(repository: &dyn UserRepository) {
+    let a = register(repository, "A", "token-a").await;
+    let b = register(repository, "B", "token-b").await;
+    let c = register(repository, "C", "token-c").await;
+
+    let ids = |users: Vec<User>| -> Vec<Uuid> { users.iter().map(|user| user.id).collect() };
+    assert_eq!(ids(repository.get_users(1, 2).await.unwrap()), vec![c, b]);
+    assert_eq!(ids(repository.get_users(2, 2).await.unwrap()), vec![a]);
+    assert!(repository.get_users(3, 2).await.unwrap().is_empty());
+    assert_eq!(repository.get_user_count().await.unwrap(), 3);
+}
+
+async fn filters_users(repository: &dyn UserRepository) {
+    let ann = register(repository, "Ann", "token-1").await;
+    let bob = repository
+        .save_invited_user("Bob", "bob@example.com", "hash", UserRole::Admin)
+        .await
+        .unwrap()
+        .id;
+    let carol = repository
+        .save_user(
+            "Carol 100%",
+            "carol@example.org",
+            "hash",
+            "token-3",
+            Utc::now() + Duration::hours(24),
+            ApprovalStatus::Pending,
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let find = |filter: UserFilter| async move {
+        repository
+            .find_users(&filter, 1, 10)
+            .await
+            .unwrap()
+            .iter()
+            .map(|user| user.id)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(find(UserFilter::default()).await, vec![carol, bob, ann]);
+    assert_eq!(
+        find(UserFilter {
+            role: Some(UserRole::Admin),
+            ..Default::default()
+        })
+        .await,
+        vec![bob]
+    );
+    assert_eq!(
+        find(UserFilter {
+            status: Some(AccountStatus::PendingVerification),
+            ..Default::default()
+        })
+        .await,
+        vec![carol, ann]
+    );
+    assert_eq!(
+        find(UserFilter {
+            status: Some(AccountStatus::PendingVerification),
+            approval_status: Some(ApprovalStatus::Approved),
+            ..Default::default()
+        })
+        .await,
+        vec![ann]
+    );
+    // Matches name or email, ignoring case.
+    assert_eq!(
+        find(UserFilter {
+            search: Some("EXAMPLE.COM".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec![bob, ann]
+    );
+    assert_eq!(
+        find(UserFilter {
+            search: Some("carol".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec![carol]
+    );
+    // Wildcards are taken literally.
+    assert_eq!(
+        find(UserFilter {
+            search: Some("100%".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec![carol]
+    );
+    assert!(
+        find(UserFilter {
+            search: Some("a_n".to_string()),
+            ..Default::default()
+        })
+        .await
+        .is_empty()
+    );
+}
+
+async fn lists_users_by_approval_status(repository: &dyn UserRepository) {
+    let a = register(repository, "A", "token-a").await;
+    register(repository, "B", "token-b").await;
+    let c = register(repository, "C", "token-c").await;
+
+    for id in [a, c] {
+        let user = repository
+            .update_approval_status(id, ApprovalStatus::Pending)
+            .await
+            .unwrap();
+        assert_eq!(user.approval_status, ApprovalStatus::Pending);
+    }
+
+    // Oldest first, so the longest waiting are reviewed first.
+    let pending: Vec<Uuid> = repository
+        .get_users_by_approval_status(ApprovalStatus::Pending, 1, 10)
+        .await
+        .unwrap()
+        .iter()
+        .map(|user| user.id)
+        .collect();
+    assert_eq!(pending, vec![a, c]);
+
+    let second_page = repository
+        .get_users_by_approval_status(ApprovalStatus::Pending, 2, 1)
+        .await
+        .unwrap();
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page[0].id, c);
+
+    let count = |status| repository.get_user_count_by_approval_status(status);
+    assert_eq!(count(ApprovalStatus::Pending).await.unwrap(), 2);
+    assert_eq!(count(ApprovalStatus::Approved).await.unwrap(), 1);
+    assert_eq!(count(ApprovalStatus::Rejected).await.unwrap(), 0);
+}
+
+async fn updates_profile_fields(repository: &dyn UserRepository) {
+    let id = register(repository, "Ann", "token-1").await;
+
+    let user = repository.update_username(id, "Annie").await.unwrap();
+    assert_eq!(user.name, "Annie");
+
+    let user = repository.update_user_locale(id, Some("vi")).await.unwrap();
+    assert_eq!(user.locale.as_deref(), Some("vi"));
+    let user = repository.update_user_locale(id, None).await.unwrap();
+    assert_eq!(user.locale, None);
+
+    let user = repository
+        .update_user_role(id, UserRole::Admin)
+        .await
+        .unwrap();
+    assert_eq!(user.role, UserRole::Admin);
+
+    let user = repository
+        .update_user_password(id, "new-hash".to_string())
+        .await
+        .unwrap();
+    assert_eq!(user.password, "new-hash");
+    assert!(user.updated_at >= user.created_at);
+
+    let stored = repository
+        .get_user(Some(id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.name, "Annie");
+    assert_eq!(stored.role, UserRole::Admin);
+    assert_eq!(stored.password, "new-hash");
+}
+
+async fn updating_a_missing_user_fails(repository: &dyn UserRepository) {
+    let missing = Uuid::new_v4();
+
+    assert!(matches!(
+        repository.update_username(missing, "Nobody").await,
+        Err(sqlx::Error::RowNotFound)
+    ));
+    assert!(matches!(
+        repository.update_user_role(missing, UserRole::Admin).await,
+        Err(sqlx::Error::RowNotFound)
+    ));
+    assert!(matches!(
+        repository
+            .schedule_deletion(missing, Utc::now() + Duration::days(30))
+            .await,
+        Err(sqlx::Error::RowNotFound)
+    ));
+    // Writes that only touch tokens or scrub data quietly do nothing.
+    repository
+        .add_verified_token(missing, "token", Utc::now())
+        .await
+        .unwrap();
+    assert!(!repository.anonymize_user(missing).await.unwrap());
+    assert!(
+        repository
+            .record_failed_login(missing, 1)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    repository.reset_failed_logins(missing).await.unwrap();
+    repository.delete_user(missing).await.unwrap();
+}
+
+async fn verifies_a_token_once(repository: &dyn UserRepository) {
+    let id = register(repository, "Ann", "token-1").await;
+
+    repository.verified_token("token-1").await.unwrap();
+    repository.verified_token("token-1").await.unwrap();
+    repository.verified_token("unknown").await.unwrap();
+
+    let user = repository
+        .get_user(Some(id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.status, AccountStatus::Active);
+
+    let transitions = repository.get_status_transitions(id).await.unwrap();
+    assert_eq!(transitions.len(), 1);
+    assert_eq!(
+        transitions[0].from_status,
+        AccountStatus::PendingVerification
+    );
+    assert_eq!(transitions[0].to_status, AccountStatus::Active);
+    assert_eq!(transitions[0].reason.as_deref(), Some("email verified"));
+    assert_eq!(transitions[0].actor_id, Some(id));
+
+    // The link works once: nothing finds the user by the token any more.
+    assert!(user.verification_token.is_none());
+    assert!(
+        repository
+            .get_user(None, None, None, Some("token-1"))
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let other = register(repository, "Bob", "token-2").await;
+    repository.clear_verification_token(other).await.unwrap();
+    repository
+        .clear_verification_token(Uuid::new_v4())
+        .await
+        .unwrap();
+    let other = repository
+        .get_user(Some(other), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(other.verification_token.is_none());
+    assert!(other.token_expires_at.is_none());
+}
+
+async fn replaces_and_expires_tokens(repository: &dyn UserRepository) {
+    let expired = register(repository, "Ann", "token-1").await;
+    let current = register(repository, "Bob", "token-2").await;
+    let now = Utc::now();
+
+    repository
+        .add_verified_token(expired, "reset-1", now - Duration::minutes(1))
+        .await
+        .unwrap();
+    repository
+        .add_verified_token(current, "reset-2", now + Duration::hours(1))
+        .await
+        .unwrap();
+
+    let found = repository
+        .get_user(None, None, None, Some("reset-1"))
+        .await
+        .unwrap();
+    assert_eq!(found.map(|user| user.id), Some(expired));
+    assert!(
+        repository
+            .get_user(None, None, None, Some("token-1"))
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    assert_eq!(repository.clear_expired_tokens(now).await.unwrap(), 1);
+    assert_eq!(repository.clear_expired_tokens(now).await.unwrap(), 0);
+
+    let expired = repository
+        .get_user(Some(expired), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(expired.verification_token.is_none());
+    assert!(expired.token_expires_at.is_none());
+    let current = repository
+        .get_user(Some(current), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(current.verification_token.as_deref(), Some("reset-2"));
+}
+
+async fn status_changes_are_conditional_and_recorded(repository: &dyn UserRepository) {
+    let admin = repository
+        .save_invited_user("Root", "root@example.com", "hash", UserRole::Admin)
+        .await
+        .unwrap()
+        .id;
+    let id = repository
+        .save_invited_user("Ann", "ann@example.com", "hash", UserRole::User)
+        .await
+        .unwrap()
+        .id;
+    let now = Utc::now();
+
+    let suspend = StatusChange {
+        to: AccountStatus::Suspended,
+        reason: Some("spam".to_string()),
+        suspended_until: Some(now - Duration::minutes(1)),
+        actor_id: Some(admin),
+    };
+    let user = repository
+        .update_status(id, AccountStatus::Active, suspend.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.status, AccountStatus::Suspended);
+    assert_eq!(user.suspended_reason.as_deref(), Some("spam"));
+    assert!(user.suspended_until.is_some());
+
+    // The user is no longer active, so the same change does not apply again.
+    assert!(
+        repository
+            .update_status(id, AccountStatus::Active, suspend)
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let expired: Vec<Uuid> = repository
+        .get_users_with_expired_suspension(now)
+        .await
+        .unwrap()
+        .iter()
+        .map(|user| user.id)
+        .collect();
+    assert_eq!(expired, vec![id]);
+
+    let reactivate = StatusChange {
+        to: AccountStatus::Active,
+        reason: Some("suspension expired".to_string()),
+        suspended_until: None,
+        actor_id: None,
+    };
+    let user = repository
+        .update_status(id, AccountStatus::Suspended, reactivate)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.status, AccountStatus::Active);
+    assert!(user.suspended_reason.is_none());
+    assert!(user.suspended_until.is_none());
+
+    let transitions = repository.get_status_transitions(id).await.unwrap();
+    let steps: Vec<_> = transitions
+        .iter()
+        .map(|transition| {
+            (
+                transition.from_status,
+                transition.to_status,
+                transition.actor_id,
+            )
+        })
+        .collect();
+    assert_eq!(
+        steps,
+        vec![
+            (AccountStatus::Active, AccountStatus::Suspended, Some(admin)),
+            (AccountStatus::Suspended, AccountStatus::Active, None),
+        ]
+    );
+    assert_eq!(transitions[0].reason.as_deref(), Some("spam"));
+}
+
+async fn deletion_can_be_scheduled_and_cancelled(repository: &dyn UserRepository) {
+    let id = register(repository, "Ann", "token-1").await;
+    let now = Utc::now();
+
+    let user = repository
+        .schedule_deletion(id, now - Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.status, AccountStatus::PendingDeletion);
+    assert!(user.deletion_scheduled_at.is_some());
+
+    let due: Vec<Uuid> = repository
+        .get_users_due_for_deletion(now)
+        .await
+        .unwrap()
+        .iter()
+        .map(|user| user.id)
+        .collect();
+    assert_eq!(due, vec![id]);
+    assert!(
+        repository
+            .get_users_due_for_deletion(now - Duration::hours(1))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    // Cancelling returns the account to where it was before.
+    assert!(repository.cancel_deletion(id).await.unwrap());
+    assert!(!repository.cancel_deletion(id).await.unwrap());
+
+    let user = repository
+        .get_user(Some(id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.status, AccountStatus::PendingVerification);
+    assert!(user.deletion_scheduled_at.is_none());
+
+    let transitions = repository.get_status_transitions(id).await.unwrap();
+    assert_eq!(transitions.len(), 2);
+    assert_eq!(transitions[1].reason.as_deref(), Some("deletion cancelled"));
+}
+
+async fn anonymizing_scrubs_personal_data(repository: &dyn UserRepository) {
+    let id = register(repository, "Ann", "token-1").await;
+    repository.update_user_locale(id, Some("vi")).await.unwrap();
+    repository
+        .schedule_deletion(id, Utc::now() - Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(repository.anonymize_user(id).await.unwrap());
+    assert!(!repository.anonymize_user(id).await.unwrap());
+
+    let user = repository
+        .get_user(Some(id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.name, "Deleted user");
+    assert_eq!(user.email, format!("deleted-{}@deleted.invalid", id));
+    assert_eq!(user.password, "");
+    assert!(user.verification_token.is_none());
+    assert!(user.locale.is_none());
+    assert!(user.deletion_scheduled_at.is_none());
+    assert!(user.deleted_at.is_some());
+    assert_eq!(user.status, AccountStatus::Deleted);
+
+    let transitions = repository.get_status_transitions(id).await.unwrap();
+    let last = transitions.last().unwrap();
+    assert_eq!(last.from_status, AccountStatus::PendingDeletion);
+    assert_eq!(last.to_status, AccountStatus::Deleted);
+    assert_eq!(last.actor_id, None);
+
+    assert!(
+        repository
+            .get_user(None, None, Some("ann@example.com"), None)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(!repository.cancel_deletion(id).await.unwrap());
+    assert!(
+        repository
+            .get_users_due_for_deletion(Utc::now())
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
+async fn failed_logins_lock_an_active_account(repository: &dyn UserRepository) {
+    let id = repository
+        .save_invited_user("Ann", "ann@example.com", "hash", UserRole::User)
+        .await
+        .unwrap()
+        .id;
+
+    let status = |user: Option<User>| user.map(|user| user.status);
+
+    // A successful login in between starts the count over.
+    for _ in 0..2 {
+        let user = repository.record_failed_login(id, 3).await.unwrap();
+        assert_eq!(status(user), Some(AccountStatus::Active));
+    }
+    repository.reset_failed_logins(id).await.unwrap();
+    for _ in 0..2 {
+        let user = repository.record_failed_login(id, 3).await.unwrap();
+        assert_eq!(status(user), Some(AccountStatus::Active));
+    }
+    let user = repository.record_failed_login(id, 3).await.unwrap();
+    assert_eq!(status(user), Some(AccountStatus::Locked));
+
+    // Further failures keep it locked without recording the lock again.
+    let user = repository.record_failed_login(id, 3).await.unwrap();
+    assert_eq!(status(user), Some(AccountStatus::Locked));
+    let transitions = repository.get_status_transitions(id).await.unwrap();
+    assert_eq!(transitions.len(), 1);
+    assert_eq!(transitions[0].from_status, AccountStatus::Active);
+    assert_eq!(transitions[0].to_status, AccountStatus::Locked);
+    assert_eq!(
+        transitions[0].reason.as_deref(),
+        Some("too many failed logins")
+    );
+    assert_eq!(transitions[0].actor_id, None);
+
+    // Unlocking clears the count, so one more failure does not lock again.
+    let unlock = StatusChange {
+        to: AccountStatus::Active,
+        reason: Some("reinstated by admin".to_string()),
+        suspended_until: None,
+        actor_id: None,
+    };
+    repository
+        .update_status(id, AccountStatus::Locked, unlock)
+        .await
+        .unwrap()
+        .unwrap();
+    let user = repository.record_failed_login(id, 3).await.unwrap();
+    assert_eq!(status(user), Some(AccountStatus::Active));
+
+    // A threshold of zero never locks.
+    for _ in 0..10 {
+        let user = repository.record_failed_login(id, 0).await.unwrap();
+        assert_eq!(status(user), Some(AccountStatus::Active));
+    }
+
+    // Only active accounts are locked.
+    let pending = register(repository, "Bob", "token-1").await;
+    let user = repository.record_failed_login(pending, 1).await.unwrap();
+    assert_eq!(status(user), Some(AccountStatus::PendingVerification));
+}
+
+async fn refuses_transitions_outside_the_lifecycle(repository: &dyn UserRepository) {
+    let id = register(repository, "Ann", "token-1").await;
+
+    let lock = StatusChange {
+        to: AccountStatus::Locked,
+        reason: None,
+        suspended_until: None,
+        actor_id: None,
+    };
+    assert!(
+        repository
+            .update_status(id, AccountStatus::PendingVerification, lock)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    // Anonymizing is only reached through a scheduled deletion.
+    assert!(!repository.anonymize_user(id).await.unwrap());
+
+    repository
+        .schedule_deletion(id, Utc::now() - Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(repository.anonymize_user(id).await.unwrap());
+
+    // A deleted account is terminal.
+    let reactivate = StatusChange {
+        to: AccountStatus::Active,
+        reason: None,
+        suspended_until: None,
+        actor_id: None,
+    };
+    assert!(
+        repository
+            .update_status(id, AccountStatus::Deleted, reactivate)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        repository
+            .schedule_deletion(id, Utc::now() + Duration::days(30))
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let user = repository
+        .get_user(Some(id), None, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.status, AccountStatus::Deleted);
+    let steps: Vec<_> = repository
+        .get_status_transitions(id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|transition| (transition.from_status, transition.to_status))
+        .collect();
+    assert_eq!(
+        steps,
+        vec![
+            (
+                AccountStatus::PendingVerification,
+                AccountStatus::PendingDeletion
+            ),
+            (AccountStatus::PendingDeletion, AccountStatus::Deleted),
+        ]
+    );
+}
+
+async fn deleting_removes_the_user_and_its_history(repository: &dyn UserRepository) {
+    let actor = repository
+        .save_invited_user("Root", "root@example.com", "hash", UserRole::Admin)
+        .await
+        .unwrap()
+        .id;
+    let id = register(repository, "Ann", "token-1").await;
+    repository.verified_token("token-1").await.unwrap();
+
+    let lock = StatusChange {
+        to: AccountStatus::Locked,
+        reason: None,
+        suspended_until: None,
+        actor_id: Some(actor),
+    };
+    repository
+        .update_status(id, AccountStatus::Active, lock)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // The actor's own account going away keeps the history it took part in.
+    repository.delete_user(actor).await.unwrap();
+    let transitions = repository.get_status_transitions(id).await.unwrap();
+    assert_eq!(transitions.len(), 2);
+    assert_eq!(transitions[1].actor_id, None);
+
+    repository.delete_user(id).await.unwrap();
+    assert!(
+        repository
+            .get_user(Some(id), None, None, None)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        repository
+            .get_status_transitions(id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(repository.get_user_count().await.unwrap(), 0);
+}
@@ -0,0 +1,388 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    Extension, Router,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+};
+use axum_auth_backend::{
+    AppState,
+    api::admin::handlers::redeliver_webhook::redeliver_webhook,
+    core::webhook::{
+        DELIVERY_HEADER, EVENT_HEADER, MAX_DELIVERY_ATTEMPTS, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+        deliver_due_webhooks, send_webhook, sign_payload,
+    },
+    domains::{
+        user::{User, UserRole},
+        webhook::{WebhookDelivery, WebhookDeliveryStatus},
+    },
+    infrastructure::{
+        middleware::{auth::JWTAuthMiddleware, client_info::ClientInfo},
+        webhook::{webhook_impl::PgWebhookRepository, webhook_trait::WebhookRepository},
+    },
+};
+use chrono::{Duration, Utc};
+use common::TestDatabase;
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+#[derive(Clone, Default)]
+struct Receiver {
+    status: Arc<Mutex<Option<StatusCode>>>,
+    requests: Arc<Mutex<Vec<(HeaderMap, Bytes)>>>,
+}
+
+async fn receive(State(receiver): State<Receiver>, headers: HeaderMap, body: Bytes) -> StatusCode {
+    receiver.requests.lock().unwrap().push((headers, body));
+    receiver.status.lock().unwrap().unwrap_or(StatusCode::OK)
+}
+
+/// Starts a receiver on an ephemeral port and returns its URL.
+async fn start_receiver(status: StatusCode) -> (String, Receiver) {
+    let receiver = Receiver::default();
+    *receiver.status.lock().unwrap() = Some(status);
+
+    let app = Router::new()
+        .route("/hook", post(receive))
+        .with_state(receiver.clone());
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (format!("http://{}/hook", addr), receiver)
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> &'a str {
+    headers.get(name).unwrap().to_str().unwrap()
+}
+
+#[tokio::test]
+async fn delivery_carries_a_verifiable_signature() {
+    let (url, receiver) = start_receiver(StatusCode::NO_CONTENT).await;
+    let client = reqwest::Client::new();
+    let delivery_id = Uuid::new_v4();
+    let payload = json!({ "type": "user.registered", "data": { "user": { "id": "42" } } });
+
+    let status = send_webhook(
+        &client,
+        &url,
+        "receiver-secret",
+        delivery_id,
+        "user.registered",
+        &payload,
+    )
+    .await
+    .unwrap();
+    assert_eq!(status, 204);
+
+    let requests = receiver.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    let (headers, body) = &requests[0];
+
+    assert_eq!(header(headers, EVENT_HEADER), "user.registered");
+    assert_eq!(header(headers, DELIVERY_HEADER), delivery_id.to_string());
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(body).unwrap(),
+        payload
+    );
+
+    // Recompute the signature the way a receiver would.
+    let timestamp = header(headers, TIMESTAMP_HEADER);
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"receiver-secret").unwrap();
+    mac.update(format!("{}.", timestamp).as_bytes());
+    mac.update(body);
+    let expected = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+    assert_eq!(header(headers, SIGNATURE_HEADER), expected);
+}
+
+#[tokio::test]
+async fn signature_depends_on_the_secret() {
+    let (url, receiver) = start_receiver(StatusCode::OK).await;
+    let client = reqwest::Client::new();
+
+    send_webhook(
+        &client,
+        &url,
+        "first-secret",
+        Uuid::new_v4(),
+        "user.deleted",
+        &json!({}),
+    )
+    .await
+    .unwrap();
+
+    let requests = receiver.requests.lock().unwrap();
+    let (headers, body) = &requests[0];
+    let timestamp: i64 = header(headers, TIMESTAMP_HEADER).parse().unwrap();
+
+    assert_eq!(
+        header(headers, SIGNATURE_HEADER),
+        sign_payload("first-secret", timestamp, body)
+    );
+    assert_ne!(
+        header(headers, SIGNATURE_HEADER),
+        sign_payload("other-secret", timestamp, body)
+    );
+}
+
+#[tokio::test]
+async fn non_success_response_is_a_failed_attempt() {
+    let (url, receiver) = start_receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+    let client = reqwest::Client::new();
+
+    let error = send_webhook(
+        &client,
+        &url,
+        "receiver-secret",
+        Uuid::new_v4(),
+        "user.verified",
+        &json!({}),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(error.response_status, Some(500));
+    assert_eq!(receiver.requests.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn unreachable_receiver_is_a_failed_attempt_without_status() {
+    // Bind and drop to get a port nothing listens on.
+    let addr = TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = reqwest::Client::new();
+
+    let error = send_webhook(
+        &client,
+        &format!("http://{}/hook", addr),
+        "receiver-secret",
+        Uuid::new_v4(),
+        "user.verified",
+        &json!({}),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(error.response_status, None);
+}
+
+/// An admin, an endpoint at `url` and one delivery queued for it.
+async fn queue_delivery(app_state: &AppState, url: &str) -> (User, WebhookDelivery) {
+    let admin = app_state
+        .user_repository
+        .save_invited_user("Ann", "ann@example.com", "hash", UserRole::Admin)
+        .await
+        .unwrap();
+    let repository = PgWebhookRepository::new(&app_state.db_client);
+    let endpoint = repository
+        .save_endpoint(url, "receiver-secret", &[], admin.id)
+        .await
+        .unwrap();
+    let delivery = repository
+        .enqueue_delivery(
+            endpoint.id,
+            Uuid::new_v4(),
+            "user.registered",
+            &json!({ "type": "user.registered" }),
+        )
+        .await
+        .unwrap();
+    (admin, delivery)
+}
+
+async fn reload(app_state: &AppState, delivery: &WebhookDelivery) -> WebhookDelivery {
+    PgWebhookRepository::new(&app_state.db_client)
+        .get_delivery(delivery.id)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn a_successful_attempt_marks_the_delivery_delivered() {
+    let Some(db) = TestDatabase::connect("webhook_delivered").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let (url, receiver) = start_receiver(StatusCode::NO_CONTENT).await;
+    let (_, delivery) = queue_delivery(&app_state, &url).await;
+
+    deliver_due_webhooks(&app_state, &reqwest::Client::new()).await;
+
+    let delivery = reload(&app_state, &delivery).await;
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Succeeded);
+    assert_eq!(delivery.attempts, 1);
+    assert_eq!(delivery.response_status, Some(204));
+    assert!(delivery.delivered_at.is_some());
+    assert_eq!(receiver.requests.lock().unwrap().len(), 1);
+
+    // Delivered entries are never claimed again.
+    deliver_due_webhooks(&app_state, &reqwest::Client::new()).await;
+    assert_eq!(receiver.requests.lock().unwrap().len(), 1);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn failed_attempts_are_rescheduled_until_the_last_one() {
+    let Some(db) = TestDatabase::connect("webhook_failed").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let (url, receiver) = start_receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+    let (_, delivery) = queue_delivery(&app_state, &url).await;
+    let client = reqwest::Client::new();
+
+    let before = Utc::now();
+    deliver_due_webhooks(&app_state, &client).await;
+
+    let retried = reload(&app_state, &delivery).await;
+    assert_eq!(retried.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(retried.attempts, 1);
+    assert_eq!(retried.response_status, Some(500));
+    assert!(retried.last_error.is_some());
+    assert!(retried.next_attempt_at >= before + Duration::seconds(30));
+
+    // Not due yet, so a second pass leaves it alone.
+    deliver_due_webhooks(&app_state, &client).await;
+    assert_eq!(receiver.requests.lock().unwrap().len(), 1);
+
+    // Due again with one attempt left.
+    sqlx::query("UPDATE webhook_deliveries SET attempts = $1, next_attempt_at = $2 WHERE id = $3")
+        .bind(MAX_DELIVERY_ATTEMPTS - 1)
+        .bind(Utc::now())
+        .bind(delivery.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    deliver_due_webhooks(&app_state, &client).await;
+
+    let failed = reload(&app_state, &delivery).await;
+    assert_eq!(failed.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(failed.attempts, MAX_DELIVERY_ATTEMPTS);
+    assert_eq!(receiver.requests.lock().unwrap().len(), 2);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn claimed_deliveries_are_leased_until_the_lease_runs_out() {
+    let Some(db) = TestDatabase::connect("webhook_lease").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let (_, delivery) = queue_delivery(&app_state, "http://127.0.0.1:1/hook").await;
+    let repository = PgWebhookRepository::new(&app_state.db_client);
+
+    let now = Utc::now();
+    let lease_until = now + Duration::minutes(2);
+    let claimed = repository
+        .claim_due_deliveries(now, lease_until, 10)
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].id, delivery.id);
+    assert_eq!(claimed[0].secret, "receiver-secret");
+
+    // Another worker finds nothing while the lease holds.
+    let again = repository
+        .claim_due_deliveries(now, now + Duration::minutes(2), 10)
+        .await
+        .unwrap();
+    assert!(again.is_empty());
+
+    // A worker that crashed mid-attempt loses the claim once it lapses.
+    let later = lease_until + Duration::seconds(1);
+    let reclaimed = repository
+        .claim_due_deliveries(later, later + Duration::minutes(2), 10)
+        .await
+        .unwrap();
+    assert_eq!(reclaimed.len(), 1);
+    assert_eq!(reclaimed[0].attempts, 0);
+
+    db.teardown().await;
+}
+
+#[tokio::test]
+async fn redelivery_queues_a_new_attempt_of_the_same_event() {
+    let Some(db) = TestDatabase::connect("webhook_redeliver").await else {
+        return;
+    };
+    let app_state = db.app_state(&[]);
+    let (url, receiver) = start_receiver(StatusCode::OK).await;
+    let (admin, original) = queue_delivery(&app_state, &url).await;
+    sqlx::query("UPDATE webhook_deliveries SET status = 'failed' WHERE id = $1")
+        .bind(original.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let redeliver = |delivery_id: Uuid| {
+        redeliver_webhook(
+            Extension(app_state.clone()),
+            Extension(JWTAuthMiddleware {
+                user: admin.clone(),
+                active_organization: None,
+                authenticated_at: Utc::now(),
+            }),
+            ClientInfo::default(),
+            Path(delivery_id),
+        )
+    };
+
+    let response = redeliver(original.id).await.unwrap().into_response();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let missing = redeliver(Uuid::new_v4())
+        .await
+        .err()
+        .unwrap()
+        .into_response();
+    assert_eq!(missing.status(), StatusCode::BAD_REQUEST);
+
+    deliver_due_webhooks(&app_state, &reqwest::Client::new()).await;
+
+    {
+        let requests = receiver.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let (headers, _) = &requests[0];
+        assert_ne!(header(headers, DELIVERY_HEADER), original.id.to_string());
+    }
+
+    let deliveries = PgWebhookRepository::new(&app_state.db_client)
+        .get_deliveries(original.endpoint_id, 1, 10)
+        .await
+        .unwrap();
+    assert_eq!(deliveries.len(), 2);
+    // Page 0 is read as the first page rather than underflowing.
+    let first_page = PgWebhookRepository::new(&app_state.db_client)
+        .get_deliveries(original.endpoint_id, 0, 10)
+        .await
+        .unwrap();
+    assert_eq!(first_page.len(), 2);
+    assert!(deliveries.iter().all(|d| d.event_id == original.event_id));
+    assert_eq!(
+        reload(&app_state, &original).await.status,
+        WebhookDeliveryStatus::Failed
+    );
+    assert!(
+        deliveries
+            .iter()
+            .any(|d| d.id != original.id && d.status == WebhookDeliveryStatus::Succeeded)
+    );
+
+    db.teardown().await;
+}